@@ -154,6 +154,7 @@ fn run_bindgen(env: &Environment) {
     bindgen::Builder::default()
         .header(env.include_dir.join("acb.h").to_str().unwrap())
         .header(env.include_dir.join("acb_elliptic.h").to_str().unwrap())
+        .header(env.include_dir.join("acb_modular.h").to_str().unwrap())
         .header(env.include_dir.join("arb.h").to_str().unwrap())
         .header(env.include_dir.join("arb_hypgeom.h").to_str().unwrap())
         .header(env.include_dir.join("arf.h").to_str().unwrap())
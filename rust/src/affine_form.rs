@@ -0,0 +1,198 @@
+use inari::{const_interval, interval, DecInterval, Decoration, Interval};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// The number of the noise symbols, which are associated with x, y and n_θ in this order.
+pub const N_NOISE_SYMBOLS: usize = 3;
+
+/// An affine form a₀ + a₁ε₁ + a₂ε₂ + a₃ε₃ + e,
+/// where ε₁, ε₂, ε₃ ∈ [-1, 1] are the noise symbols associated with x, y and n_θ,
+/// respectively, and e is an interval that encloses the errors of linear approximations.
+///
+/// Unlike intervals, affine forms keep track of the linear correlation between quantities,
+/// which reduces overestimation due to the dependency problem, e.g., x - x evaluates to
+/// exactly zero. The coefficients are intervals so that the form remains rigorous
+/// in the presence of rounding errors.
+#[derive(Clone, Copy, Debug)]
+pub struct AffineForm {
+    a0: Interval,
+    a: [Interval; N_NOISE_SYMBOLS],
+    e: Interval,
+}
+
+const ZERO: Interval = const_interval!(0.0, 0.0);
+const UNIT: Interval = const_interval!(-1.0, 1.0);
+
+impl AffineForm {
+    /// Creates an affine form that has no noise symbols and only represents the interval.
+    pub fn constant(x: Interval) -> Self {
+        Self {
+            a0: x,
+            a: [ZERO; N_NOISE_SYMBOLS],
+            e: ZERO,
+        }
+    }
+
+    /// Creates an affine form that represents the interval with the `i`th noise symbol.
+    ///
+    /// If `x` is not a bounded nonempty interval, the result is the same as
+    /// `AffineForm::constant(x)`.
+    pub fn variable(x: Interval, i: usize) -> Self {
+        if !x.is_common_interval() {
+            return Self::constant(x);
+        }
+        let m = Self::point(x.mid());
+        let mut a = [ZERO; N_NOISE_SYMBOLS];
+        a[i] = Self::point((x - m).mag());
+        Self { a0: m, a, e: ZERO }
+    }
+
+    /// Returns an enclosure of the range of the affine form.
+    pub fn range(&self) -> Interval {
+        self.a0 + self.deviation()
+    }
+
+    /// Returns an affine form that encloses `f(self)`.
+    ///
+    /// `f` is the function to apply, `df` returns an enclosure of its derivative over an interval,
+    /// and `x` is an enclosure of the range of `self`.
+    /// The function is linearized by the mean value theorem around the midpoint of
+    /// `x ∩ self.range()`. [`None`] is returned if `f` is not continuous over the interval
+    /// or the derivative is unbounded.
+    pub fn linearize<F, DF>(self, f: F, df: DF, x: Interval) -> Option<Self>
+    where
+        F: Fn(DecInterval) -> DecInterval,
+        DF: Fn(Interval) -> Option<Interval>,
+    {
+        let x = x.intersection(self.range());
+        if !x.is_common_interval() || f(DecInterval::new(x)).decoration() < Decoration::Dac {
+            return None;
+        }
+        let d = df(x).filter(|d| d.is_common_interval())?;
+        let m = Self::point(x.mid());
+        let fm = f(DecInterval::new(m)).interval()?;
+        let alpha = Self::point(d.mid());
+        // f(x) = f(m) + f'(ξ)(x - m) = f(m) + α(x - m) + (f'(ξ) - α)(x - m), where ξ ∈ x.
+        Some(Self {
+            a0: fm + alpha * (self.a0 - m),
+            a: self.a.map(|a| alpha * a),
+            e: alpha * self.e + (d - alpha) * (x - m),
+        })
+    }
+
+    /// Returns the square of the affine form, which is tighter than `self * self`.
+    pub fn sqr(self) -> Self {
+        Self {
+            a0: self.a0.sqr(),
+            a: self.a.map(|a| (self.a0 + self.a0) * a),
+            e: (self.a0 + self.a0) * self.e + self.deviation().sqr(),
+        }
+    }
+
+    /// Returns the range of the affine form minus a₀.
+    fn deviation(&self) -> Interval {
+        self.a.iter().fold(self.e, |acc, &a| acc + a * UNIT)
+    }
+
+    fn point(x: f64) -> Interval {
+        interval!(x, x).unwrap_or(Interval::EMPTY)
+    }
+}
+
+impl Add for AffineForm {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let mut a = self.a;
+        for (a, b) in a.iter_mut().zip(rhs.a.iter()) {
+            *a += *b;
+        }
+        Self {
+            a0: self.a0 + rhs.a0,
+            a,
+            e: self.e + rhs.e,
+        }
+    }
+}
+
+impl Sub for AffineForm {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Neg for AffineForm {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            a0: -self.a0,
+            a: self.a.map(|a| -a),
+            e: -self.e,
+        }
+    }
+}
+
+impl Mul for AffineForm {
+    type Output = Self;
+
+    // Let x = a₀ + x' and y = b₀ + y', then xy = a₀b₀ + a₀y' + b₀x' + x'y',
+    // where the last term is put into the error.
+    fn mul(self, rhs: Self) -> Self {
+        let mut a = [ZERO; N_NOISE_SYMBOLS];
+        for (i, a) in a.iter_mut().enumerate() {
+            *a = self.a0 * rhs.a[i] + rhs.a0 * self.a[i];
+        }
+        Self {
+            a0: self.a0 * rhs.a0,
+            a,
+            e: self.a0 * rhs.e + rhs.a0 * self.e + self.deviation() * rhs.deviation(),
+        }
+    }
+}
+
+impl Mul<Interval> for AffineForm {
+    type Output = Self;
+
+    fn mul(self, rhs: Interval) -> Self {
+        self * Self::constant(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn affine_form() {
+        let x = AffineForm::variable(const_interval!(1.0, 3.0), 0);
+        let y = AffineForm::variable(const_interval!(-1.0, 1.0), 1);
+        assert_eq!(x.range(), const_interval!(1.0, 3.0));
+        assert_eq!((x - x).range(), const_interval!(0.0, 0.0));
+        assert_eq!((x + y - x).range(), const_interval!(-1.0, 1.0));
+        assert_eq!(x.sqr().range(), const_interval!(0.0, 9.0));
+        assert_eq!((x * y).range(), const_interval!(-3.0, 3.0));
+        assert_eq!((x.sqr() - x * x).range(), const_interval!(-1.0, 2.0));
+        assert_eq!((x * const_interval!(2.0, 2.0) - x - x).range(), ZERO);
+        assert_eq!(
+            AffineForm::constant(const_interval!(-1.0, 2.0)).range(),
+            const_interval!(-1.0, 2.0)
+        );
+
+        // exp(x) - x over X = [1, 1.125] is enclosed more tightly than exp(X) - X.
+        let x = AffineForm::variable(const_interval!(1.0, 1.125), 0);
+        let ex = x
+            .linearize(|x| x.exp(), |x| Some(x.exp()), Interval::ENTIRE)
+            .unwrap();
+        let z = (ex - x).range();
+        assert!(z.inf() <= 1.0f64.exp() - 1.0 && z.sup() >= 1.125f64.exp() - 1.125);
+        let x = const_interval!(1.0, 1.125);
+        assert!(z.wid() < (x.exp() - x).wid());
+
+        // Not defined everywhere in the range.
+        assert!(y
+            .linearize(|x| x.sqrt(), |x| Some(x.sqrt().recip()), Interval::ENTIRE)
+            .is_none());
+    }
+}
@@ -65,6 +65,10 @@ unsafe impl Send for Arf {}
 unsafe impl Sync for Arf {}
 
 /// A wrapper for the `arb_t` type.
+///
+/// The type has the same layout as `arb_struct`, so that an array of `Arb` values
+/// can be passed to Arb functions that take a vector of `arb_t`.
+#[repr(transparent)]
 pub struct Arb(arb_struct);
 
 impl Arb {
@@ -128,6 +128,80 @@ macro_rules! impl_arb_op {
             rs
         }
     };
+
+    ($op:ident($x:ident, $y:ident, $z:ident), $result:expr, $def:expr) => {
+        pub fn $op(&self, ys: &Self, zs: &Self) -> Self {
+            let mut rs = Self::new();
+            for x in self {
+                for y in ys {
+                    if let Some(g) = x.g.union(y.g) {
+                        for z in zs {
+                            if let Some(g) = g.union(z.g) {
+                                let $x = x.x;
+                                let $y = y.x;
+                                let $z = z.x;
+                                let def = $def;
+                                if def.possibly() {
+                                    let dec = if def.certainly() {
+                                        // Assuming the restriction of f to x × y × z is continuous.
+                                        Decoration::Dac.min(x.d).min(y.d).min(z.d)
+                                    } else {
+                                        Decoration::Trv
+                                    };
+                                    rs.insert(TupperInterval::new(
+                                        DecInterval::set_dec($result, dec),
+                                        g,
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            rs.normalize(false);
+            rs
+        }
+    };
+
+    ($op:ident($x:ident, $y:ident, $z:ident, $w:ident), $result:expr, $def:expr) => {
+        pub fn $op(&self, ys: &Self, zs: &Self, ws: &Self) -> Self {
+            let mut rs = Self::new();
+            for x in self {
+                for y in ys {
+                    if let Some(g) = x.g.union(y.g) {
+                        for z in zs {
+                            if let Some(g) = g.union(z.g) {
+                                for w in ws {
+                                    if let Some(g) = g.union(w.g) {
+                                        let $x = x.x;
+                                        let $y = y.x;
+                                        let $z = z.x;
+                                        let $w = w.x;
+                                        let def = $def;
+                                        if def.possibly() {
+                                            let dec = if def.certainly() {
+                                                // Assuming the restriction of f to x × y × z × w
+                                                // is continuous.
+                                                Decoration::Dac.min(x.d).min(y.d).min(z.d).min(w.d)
+                                            } else {
+                                                Decoration::Trv
+                                            };
+                                            rs.insert(TupperInterval::new(
+                                                DecInterval::set_dec($result, dec),
+                                                g,
+                                            ));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            rs.normalize(false);
+            rs
+        }
+    };
 }
 
 fn i(x: f64) -> Interval {
@@ -135,12 +209,15 @@ fn i(x: f64) -> Interval {
 }
 
 const M_ONE_TO_ONE: Interval = const_interval!(-1.0, 1.0);
+const N_INF_TO_ONE: Interval = const_interval!(f64::NEG_INFINITY, 1.0);
 const N_INF_TO_ZERO: Interval = const_interval!(f64::NEG_INFINITY, 0.0);
+const ONE: Interval = const_interval!(1.0, 1.0);
 const ONE_HALF: Interval = const_interval!(0.5, 0.5);
 const ONE_TO_INF: Interval = const_interval!(1.0, f64::INFINITY);
 const ZERO: Interval = const_interval!(0.0, 0.0);
 const ZERO_TO_INF: Interval = const_interval!(0.0, f64::INFINITY);
 const ZERO_TO_ONE: Interval = const_interval!(0.0, 1.0);
+const ZERO_TO_TWO: Interval = const_interval!(0.0, 2.0);
 
 impl TupperIntervalSet {
     // Mid-rad IA, which is used by Arb, cannot represent half-bounded intervals.
@@ -511,6 +588,36 @@ impl TupperIntervalSet {
         }
     );
 
+    impl_arb_op!(
+        beta_regularized(x, a, b),
+        {
+            let x = x.intersection(ZERO_TO_ONE);
+            let a = a.intersection(ZERO_TO_INF);
+            let b = b.intersection(ZERO_TO_INF);
+            if x.is_empty() || a.is_empty() || b.is_empty() {
+                Interval::EMPTY
+            } else {
+                // I_x(a, b) is increasing in x and b and decreasing in a.
+                let inf = if x.inf() == 0.0 || a.sup() == f64::INFINITY || b.inf() == 0.0 {
+                    0.0
+                } else if x.inf() == 1.0 {
+                    1.0
+                } else {
+                    arb_beta_regularized(i(x.inf()), i(a.sup()), i(b.inf())).inf()
+                };
+                let sup = if x.sup() == 1.0 || a.inf() == 0.0 || b.sup() == f64::INFINITY {
+                    1.0
+                } else if x.sup() == 0.0 {
+                    0.0
+                } else {
+                    arb_beta_regularized(i(x.sup()), i(a.inf()), i(b.sup())).sup()
+                };
+                interval!(inf, sup).unwrap()
+            }
+        },
+        ge!(x, 0.0) & le!(x, 1.0) & gt!(a, 0.0) & gt!(b, 0.0)
+    );
+
     impl_arb_op!(
         chi(x),
         {
@@ -621,21 +728,30 @@ impl TupperIntervalSet {
     );
 
     impl_arb_op!(
-        elliptic_k(x),
-        {
-            let a = x.inf();
-            let b = x.sup();
-            if a == f64::NEG_INFINITY && b >= 1.0 {
-                const_interval!(0.0, f64::INFINITY)
-            } else if a == f64::NEG_INFINITY {
-                interval!(0.0, arb_elliptic_k(i(b)).sup()).unwrap()
-            } else if b >= 1.0 {
-                interval!(arb_elliptic_k(i(a)).inf(), f64::INFINITY).unwrap()
-            } else {
-                arb_elliptic_k(x)
-            }
-        },
-        lt!(x, 1.0)
+        elliptic_e_inc(phi, m),
+        arb_elliptic_e_inc(phi, m),
+        elliptic_integrand_def(phi, m, true)
+    );
+
+    impl_arb_op!(
+        elliptic_f(phi, m),
+        arb_elliptic_f(phi, m),
+        // F(φ, m) is finite at the endpoint φ = asin(1/√m) if m > 1.
+        BoolInterval::new(
+            elliptic_integrand_def(phi, m, false).certainly(),
+            elliptic_integrand_def(phi, m, true).possibly()
+        )
+    );
+
+    impl_arb_op!(elliptic_k(x), elliptic_k(x), lt!(x, 1.0));
+
+    impl_arb_op!(
+        elliptic_pi(n, phi, m),
+        arb_elliptic_pi(n, phi, m),
+        BoolInterval::new(
+            elliptic_integrand_def(phi, m, false).certainly(),
+            elliptic_integrand_def(phi, m, true).possibly()
+        ) & elliptic_integrand_def(phi, n, false)
     );
 
     impl_arb_op!(
@@ -656,6 +772,31 @@ impl TupperIntervalSet {
         }
     );
 
+    impl_arb_op!(
+        erfcinv(x),
+        {
+            let x = x.intersection(ZERO_TO_TWO);
+            if x.is_empty() {
+                Interval::EMPTY
+            } else {
+                let a = x.inf();
+                let b = x.sup();
+                let inf = if b == 2.0 {
+                    f64::NEG_INFINITY
+                } else {
+                    arb_erfcinv(i(b)).inf()
+                };
+                let sup = if a == 0.0 {
+                    f64::INFINITY
+                } else {
+                    arb_erfcinv(i(a)).sup()
+                };
+                interval!(inf, sup).unwrap()
+            }
+        },
+        BoolInterval::new(x.interior(ZERO_TO_TWO), x.inf() < 2.0 && x.sup() > 0.0)
+    );
+
     impl_arb_op!(erfi(x), {
         let a = x.inf();
         let b = x.sup();
@@ -672,6 +813,65 @@ impl TupperIntervalSet {
         }
     });
 
+    impl_arb_op!(
+        erfinv(x),
+        {
+            let x = x.intersection(M_ONE_TO_ONE);
+            if x.is_empty() {
+                Interval::EMPTY
+            } else {
+                let a = x.inf();
+                let b = x.sup();
+                let inf = if a == -1.0 {
+                    f64::NEG_INFINITY
+                } else {
+                    arb_erfinv(i(a)).inf()
+                };
+                let sup = if b == 1.0 {
+                    f64::INFINITY
+                } else {
+                    arb_erfinv(i(b)).sup()
+                };
+                interval!(inf, sup).unwrap()
+            }
+        },
+        BoolInterval::new(x.interior(M_ONE_TO_ONE), x.inf() < 1.0 && x.sup() > -1.0)
+    );
+
+    impl_arb_op!(
+        exp_integral_e(n, x),
+        {
+            let x = x.intersection(ZERO_TO_INF);
+            if x.is_empty() {
+                Interval::EMPTY
+            } else {
+                // E_n(x) is decreasing in both n and x.
+                let (n0, n1) = (n.inf(), n.sup());
+                let (x0, x1) = (x.inf(), x.sup());
+                let inf = if n1 == f64::INFINITY || x1 == f64::INFINITY {
+                    0.0
+                } else if x1 == 0.0 {
+                    // E_n(0) = 1/(n - 1) for n > 1.
+                    (ONE / (i(n1) - ONE)).inf()
+                } else {
+                    arb_exp_integral_e(i(n1), i(x1)).inf()
+                };
+                let sup = if n0 == f64::NEG_INFINITY || x0 == 0.0 && n0 <= 1.0 {
+                    f64::INFINITY
+                } else if x0 == 0.0 {
+                    (ONE / (i(n0) - ONE)).sup()
+                } else {
+                    arb_exp_integral_e(i(n0), i(x0)).sup()
+                };
+                interval!(inf, sup).unwrap()
+            }
+        },
+        BoolInterval::new(
+            x.inf() > 0.0 || x.inf() == 0.0 && n.inf() > 1.0,
+            x.sup() > 0.0 || x.sup() == 0.0 && n.sup() > 1.0
+        )
+    );
+
     impl_arb_op!(
         exp(x),
         if x.is_common_interval() {
@@ -791,6 +991,114 @@ impl TupperIntervalSet {
         }
     );
 
+    impl_arb_op!(
+        gamma_inc_lower(a, x),
+        {
+            let a = a.intersection(ZERO_TO_INF);
+            let x = x.intersection(ZERO_TO_INF);
+            if a.is_empty() || x.is_empty() {
+                Interval::EMPTY
+            } else {
+                // γ(a, x) is increasing in x and tends to Γ(a) as x → +∞.
+                let inf = if x.inf() == 0.0 {
+                    0.0
+                } else {
+                    arb_gamma_inc_lower(a, i(x.inf())).inf()
+                };
+                let sup = if a.inf() == 0.0 {
+                    f64::INFINITY
+                } else if x.sup() == f64::INFINITY {
+                    arb_gamma(a).sup()
+                } else {
+                    arb_gamma_inc_lower(a, i(x.sup())).sup()
+                };
+                interval!(inf, sup).unwrap()
+            }
+        },
+        gt!(a, 0.0) & ge!(x, 0.0)
+    );
+
+    impl_arb_op!(
+        gamma_regularized(a, x),
+        {
+            let a = a.intersection(ZERO_TO_INF);
+            let x = x.intersection(ZERO_TO_INF);
+            if a.is_empty() || x.is_empty() {
+                Interval::EMPTY
+            } else {
+                // Q(a, x) is increasing in a and decreasing in x.
+                let inf = if a.inf() == 0.0 || x.sup() == f64::INFINITY {
+                    0.0
+                } else {
+                    arb_gamma_regularized(i(a.inf()), i(x.sup())).inf()
+                };
+                let sup = if a.sup() == f64::INFINITY || x.inf() == 0.0 {
+                    1.0
+                } else {
+                    arb_gamma_regularized(i(a.sup()), i(x.inf())).sup()
+                };
+                interval!(inf, sup).unwrap()
+            }
+        },
+        gt!(a, 0.0) & ge!(x, 0.0)
+    );
+
+    impl_arb_op!(
+        gamma_regularized_lower(a, x),
+        {
+            let a = a.intersection(ZERO_TO_INF);
+            let x = x.intersection(ZERO_TO_INF);
+            if a.is_empty() || x.is_empty() {
+                Interval::EMPTY
+            } else {
+                // P(a, x) is decreasing in a and increasing in x.
+                let inf = if a.sup() == f64::INFINITY || x.inf() == 0.0 {
+                    0.0
+                } else {
+                    arb_gamma_regularized_lower(i(a.sup()), i(x.inf())).inf()
+                };
+                let sup = if a.inf() == 0.0 || x.sup() == f64::INFINITY {
+                    1.0
+                } else if x.sup() == 0.0 {
+                    0.0
+                } else {
+                    arb_gamma_regularized_lower(i(a.inf()), i(x.sup())).sup()
+                };
+                interval!(inf, sup).unwrap()
+            }
+        },
+        gt!(a, 0.0) & ge!(x, 0.0)
+    );
+
+    impl_arb_op!(hyp0f1(b, x), arb_hyp0f1(b, x), hypgeom_param_def(b));
+
+    impl_arb_op!(hyp1f1(a, b, x), arb_hyp1f1(a, b, x), hypgeom_param_def(b));
+
+    impl_arb_op!(
+        hyp2f1(a, b, c, x),
+        if is_nonpositive_integer(a) || is_nonpositive_integer(b) {
+            arb_hyp2f1(a, b, c, x)
+        } else {
+            arb_hyp2f1(a, b, c, x.intersection(N_INF_TO_ONE))
+        },
+        hypgeom_param_def(c)
+            & if is_nonpositive_integer(a) || is_nonpositive_integer(b) {
+                // The series terminates.
+                BoolInterval::TRUE
+            } else {
+                // The function is real-valued for x < 1, and for x = 1 if c - a - b > 0.
+                BoolInterval::new(x.sup() < 1.0, x.inf() <= 1.0)
+            }
+    );
+
+    impl_arb_op!(jacobi_am(u, m), jacobi_am(u, m), BoolInterval::TRUE);
+
+    impl_arb_op!(jacobi_cn(u, m), jacobi_sn_cn_dn(u, m).1, BoolInterval::TRUE);
+
+    impl_arb_op!(jacobi_dn(u, m), jacobi_sn_cn_dn(u, m).2, BoolInterval::TRUE);
+
+    impl_arb_op!(jacobi_sn(u, m), jacobi_sn_cn_dn(u, m).0, BoolInterval::TRUE);
+
     impl_arb_op!(
         li(x),
         {
@@ -901,6 +1209,131 @@ impl TupperIntervalSet {
         }
     );
 
+    impl_arb_op!(
+        spherical_bessel_i(n, x),
+        {
+            let n = n.inf() as u32;
+            let f = |x: f64| arb_spherical_bessel_j_or_i(n, i(x), true);
+            if n % 2 == 1 {
+                // i_n is odd and is increasing.
+                let a = x.inf();
+                let b = x.sup();
+                let inf = if a == f64::NEG_INFINITY {
+                    a
+                } else {
+                    f(a).inf()
+                };
+                let sup = if b == f64::INFINITY {
+                    b
+                } else {
+                    f(b).sup()
+                };
+                interval!(inf, sup).unwrap()
+            } else {
+                // i_n is even and is increasing on [0, +∞).
+                let x = x.abs();
+                let a = x.inf();
+                let b = x.sup();
+                let sup = if b == f64::INFINITY {
+                    b
+                } else {
+                    f(b).sup()
+                };
+                interval!(f(a).inf(), sup).unwrap()
+            }
+        },
+        {
+            check_spherical_bessel_order(n, "i");
+            BoolInterval::TRUE
+        }
+    );
+
+    impl_arb_op!(
+        spherical_bessel_j(n, x),
+        {
+            let n = n.inf() as u32;
+            let y = arb_spherical_bessel_j_or_i(n, x, false).intersection(M_ONE_TO_ONE);
+            if x.contains(0.0) {
+                y
+            } else {
+                y.intersection(spherical_bessel_envelope(n, x))
+            }
+        },
+        {
+            check_spherical_bessel_order(n, "j");
+            BoolInterval::TRUE
+        }
+    );
+
+    impl_arb_op!(
+        spherical_bessel_k(n, x),
+        {
+            let n = n.inf() as u32;
+            let x = x.intersection(ZERO_TO_INF);
+            if x.is_empty() || x == ZERO {
+                Interval::EMPTY
+            } else {
+                // k_n is decreasing on (0, +∞).
+                let a = x.inf();
+                let b = x.sup();
+                let inf = if b == f64::INFINITY {
+                    0.0
+                } else {
+                    arb_spherical_bessel_k(n, i(b)).inf()
+                };
+                let sup = if a == 0.0 {
+                    f64::INFINITY
+                } else {
+                    arb_spherical_bessel_k(n, i(a)).sup()
+                };
+                interval!(inf, sup).unwrap()
+            }
+        },
+        {
+            check_spherical_bessel_order(n, "k");
+            gt!(x, 0.0)
+        }
+    );
+
+    impl_arb_op!(
+        spherical_bessel_y(n, x),
+        {
+            let n = n.inf() as u32;
+            // Returns an enclosure of y_n over x ⊆ [0, +∞].
+            let f = |x: Interval| {
+                if x.is_empty() || x == ZERO {
+                    Interval::EMPTY
+                } else if x.inf() == 0.0 {
+                    Interval::ENTIRE
+                } else if x.sup() == f64::INFINITY {
+                    spherical_bessel_envelope(n, x)
+                } else {
+                    arb_spherical_bessel_y(n, x).intersection(spherical_bessel_envelope(n, x))
+                }
+            };
+            // y_n(-x) = (-1)^(n+1) y_n(x).
+            let y0 = f(-x.intersection(N_INF_TO_ZERO));
+            let y0 = if n % 2 == 1 { y0 } else { -y0 };
+            y0.convex_hull(f(x.intersection(ZERO_TO_INF)))
+        },
+        {
+            check_spherical_bessel_order(n, "y");
+            ne!(x, 0.0)
+        }
+    );
+
+    impl_arb_op!(
+        struve_h(nu, x),
+        struve(nu.inf(), x, false),
+        struve_def(nu, x, "StruveH")
+    );
+
+    impl_arb_op!(
+        struve_l(nu, x),
+        struve(nu.inf(), x, true),
+        struve_def(nu, x, "StruveL")
+    );
+
     pub fn tan(&self, site: Option<Site>) -> Self {
         if self.iter().all(|x| {
             let a = x.x.inf();
@@ -1046,6 +1479,11 @@ arb_fn!(
     arb_hypgeom_bessel_y(n, n, x, f64::MANTISSA_DIGITS.into()),
     Interval::ENTIRE
 );
+arb_fn!(
+    arb_beta_regularized(x, a, b),
+    arb_hypgeom_beta_lower(x, a, b, x, 1, f64::MANTISSA_DIGITS.into()),
+    ZERO_TO_ONE
+);
 arb_fn!(
     arb_chi(x),
     arb_hypgeom_chi(x, x, f64::MANTISSA_DIGITS.into()),
@@ -1076,11 +1514,26 @@ acb_fn_reals!(
     acb_elliptic_e(x, x, f64::MANTISSA_DIGITS.into()),
     ONE_TO_INF
 );
+acb_fn_reals!(
+    arb_elliptic_e_inc(phi, m),
+    acb_elliptic_e_inc(phi, phi, m, 0, f64::MANTISSA_DIGITS.into()),
+    Interval::ENTIRE
+);
+acb_fn_reals!(
+    arb_elliptic_f(phi, m),
+    acb_elliptic_f(phi, phi, m, 0, f64::MANTISSA_DIGITS.into()),
+    Interval::ENTIRE
+);
 acb_fn_reals!(
     arb_elliptic_k(x),
     acb_elliptic_k(x, x, f64::MANTISSA_DIGITS.into()),
     ZERO_TO_INF
 );
+acb_fn_reals!(
+    arb_elliptic_pi(n, phi, m),
+    acb_elliptic_pi_inc(n, n, phi, m, 0, f64::MANTISSA_DIGITS.into()),
+    Interval::ENTIRE
+);
 arb_fn!(
     arb_erf(x),
     // `+ 3` completes the graphing of "y = erf(1/x^21)".
@@ -1093,11 +1546,21 @@ arb_fn!(
     arb_hypgeom_erfc(x, x, (f64::MANTISSA_DIGITS + 3).into()),
     const_interval!(0.0, 2.0)
 );
+arb_fn!(
+    arb_erfcinv(x),
+    arb_hypgeom_erfcinv(x, x, f64::MANTISSA_DIGITS.into()),
+    Interval::ENTIRE
+);
 arb_fn!(
     arb_erfi(x),
     arb_hypgeom_erfi(x, x, f64::MANTISSA_DIGITS.into()),
     Interval::ENTIRE
 );
+arb_fn!(
+    arb_erfinv(x),
+    arb_hypgeom_erfinv(x, x, f64::MANTISSA_DIGITS.into()),
+    Interval::ENTIRE
+);
 arb_fn!(
     arb_exp(x),
     arb_exp(x, x, f64::MANTISSA_DIGITS.into()),
@@ -1123,6 +1586,11 @@ arb_fn!(
     ),
     ZERO_TO_INF
 );
+arb_fn!(
+    arb_exp_integral_e(n, x),
+    arb_hypgeom_expint(n, n, x, f64::MANTISSA_DIGITS.into()),
+    ZERO_TO_INF
+);
 arb_fn!(
     arb_fresnel_c(x),
     arb_hypgeom_fresnel(null(), x, x, 1, f64::MANTISSA_DIGITS.into()),
@@ -1133,11 +1601,51 @@ arb_fn!(
     arb_hypgeom_fresnel(x, null(), x, 1, f64::MANTISSA_DIGITS.into()),
     const_interval!(-0.7139722140219397, 0.7139722140219397) // [S(-√2), S(√2)]
 );
+arb_fn!(
+    arb_gamma(x),
+    arb_gamma(x, x, f64::MANTISSA_DIGITS.into()),
+    Interval::ENTIRE
+);
 arb_fn!(
     arb_gamma_inc(a, x),
     arb_hypgeom_gamma_upper(a, a, x, 0, f64::MANTISSA_DIGITS.into()),
     Interval::ENTIRE
 );
+arb_fn!(
+    arb_gamma_inc_lower(a, x),
+    arb_hypgeom_gamma_lower(a, a, x, 0, f64::MANTISSA_DIGITS.into()),
+    ZERO_TO_INF
+);
+arb_fn!(
+    arb_gamma_regularized(a, x),
+    arb_hypgeom_gamma_upper(a, a, x, 1, f64::MANTISSA_DIGITS.into()),
+    ZERO_TO_ONE
+);
+arb_fn!(
+    arb_gamma_regularized_lower(a, x),
+    arb_hypgeom_gamma_lower(a, a, x, 1, f64::MANTISSA_DIGITS.into()),
+    ZERO_TO_ONE
+);
+arb_fn!(
+    arb_hyp0f1(b, z),
+    arb_hypgeom_0f1(b, b, z, 0, f64::MANTISSA_DIGITS.into()),
+    Interval::ENTIRE
+);
+arb_fn!(
+    arb_hyp0f1_regularized(b, z),
+    arb_hypgeom_0f1(b, b, z, 1, f64::MANTISSA_DIGITS.into()),
+    Interval::ENTIRE
+);
+arb_fn!(
+    arb_hyp1f1(a, b, z),
+    arb_hypgeom_1f1(a, a, b, z, 0, f64::MANTISSA_DIGITS.into()),
+    Interval::ENTIRE
+);
+arb_fn!(
+    arb_hyp2f1(a, b, c, z),
+    arb_hypgeom_2f1(a, a, b, c, z, 0, f64::MANTISSA_DIGITS.into()),
+    Interval::ENTIRE
+);
 arb_fn!(
     arb_li(x),
     arb_hypgeom_li(x, x, 0, f64::MANTISSA_DIGITS.into()),
@@ -1194,6 +1702,368 @@ arb_fn!(
     M_ONE_TO_ONE
 );
 
+/// Returns the domain of a hypergeometric function with respect to a lower parameter `b`,
+/// which must not be a nonpositive integer.
+fn hypgeom_param_def(b: Interval) -> BoolInterval {
+    let n = b.inf().ceil();
+    let contains_pole = n <= b.sup() && n <= 0.0;
+    BoolInterval::new(!contains_pole, !is_nonpositive_integer(b))
+}
+
+/// Returns `true` if `x` is a singleton of a nonpositive integer.
+fn is_nonpositive_integer(x: Interval) -> bool {
+    x.is_singleton() && x.inf() <= 0.0 && x.inf() % 1.0 == 0.0
+}
+
+/// Panics if `n` is not a nonnegative integer.
+fn check_spherical_bessel_order(n: Interval, name: &str) {
+    if !(n.is_singleton() && n.inf() >= 0.0 && n.inf() % 1.0 == 0.0) {
+        panic!("`{}(n, x)` only permits nonnegative integers for `n`", name);
+    }
+}
+
+/// Returns an enclosure of
+///
+///   (√π / 2) (x/2)^n ₀F̃₁(; n + 3/2; ∓x^2/4),
+///
+/// which is j_n(x) if `modified` is `false` and i_n(x) otherwise.
+/// ₀F̃₁ is the regularized hypergeometric function.
+fn arb_spherical_bessel_j_or_i(n: u32, x: Interval, modified: bool) -> Interval {
+    let b = i(n as f64) + const_interval!(1.5, 1.5);
+    let z = x.sqr() * const_interval!(0.25, 0.25);
+    let z = if modified { z } else { -z };
+    let c = Interval::PI.sqrt() * ONE_HALF;
+    c * (x * ONE_HALF).pown(n as i32) * arb_hyp0f1_regularized(b, z)
+}
+
+/// Returns an enclosure of k_n(x) = √(π / (2x)) K_{n+1/2}(x), where x > 0.
+fn arb_spherical_bessel_k(n: u32, x: Interval) -> Interval {
+    let nu = i(n as f64) + ONE_HALF;
+    (Interval::FRAC_PI_2 / x).sqrt() * arb_bessel_k(nu, x)
+}
+
+/// Returns an enclosure of y_n(x) = √(π / (2x)) Y_{n+1/2}(x), where x > 0.
+fn arb_spherical_bessel_y(n: u32, x: Interval) -> Interval {
+    let nu = i(n as f64) + ONE_HALF;
+    (Interval::FRAC_PI_2 / x).sqrt() * arb_bessel_y(nu, x)
+}
+
+/// The maximum absolute value of the argument for which the Struve functions are evaluated.
+const MAX_STRUVE_ARG: f64 = 1024.0;
+
+/// Returns an enclosure of H_ν(x) if `modified` is `false` and L_ν(x) otherwise.
+///
+/// `nu` must be an integer or a half-integer.
+fn struve(nu: f64, x: Interval, modified: bool) -> Interval {
+    // Returns an enclosure of the function over x ⊆ [0, +∞].
+    let f = |x: Interval| {
+        if x.is_empty() {
+            Interval::EMPTY
+        } else if x.sup() > MAX_STRUVE_ARG {
+            Interval::ENTIRE
+        } else {
+            // H_ν(x) = (x/2)^(ν+1) ₁F̃₂(1; 3/2, ν + 3/2; -x^2/4),
+            // L_ν(x) = (x/2)^(ν+1) ₁F̃₂(1; 3/2, ν + 3/2; x^2/4).
+            let z = x.sqr() * const_interval!(0.25, 0.25);
+            let z = if modified { z } else { -z };
+            let w = if nu % 1.0 == 0.0 {
+                (x * ONE_HALF).pown(nu as i32 + 1)
+            } else {
+                (x * ONE_HALF).pow(i(nu) + ONE)
+            };
+            w * arb_struve_hypgeom(nu, z, x.sup(), modified)
+        }
+    };
+    if nu % 1.0 == 0.0 {
+        // H_ν(-x) = (-1)^(ν+1) H_ν(x), and the same holds for L_ν.
+        let y0 = f(-x.intersection(N_INF_TO_ZERO));
+        let y0 = if nu % 2.0 == 0.0 { -y0 } else { y0 };
+        y0.convex_hull(f(x.intersection(ZERO_TO_INF)))
+    } else {
+        f(x.intersection(ZERO_TO_INF))
+    }
+}
+
+fn struve_def(nu: Interval, x: Interval, name: &str) -> BoolInterval {
+    if !(nu.is_singleton() && nu.inf() % 0.5 == 0.0) {
+        panic!("`{}(ν, x)` only permits integers and half-integers for `ν`", name);
+    }
+    let nu = nu.inf();
+    if nu % 1.0 == 0.0 {
+        if nu >= -1.0 {
+            BoolInterval::TRUE
+        } else {
+            ne!(x, 0.0)
+        }
+    } else if nu > -1.0 {
+        ge!(x, 0.0)
+    } else {
+        gt!(x, 0.0)
+    }
+}
+
+/// Returns an enclosure of ₁F̃₂(1; 3/2, ν + 3/2; z), where z = ∓x^2/4 and x ≤ `x_max`.
+fn arb_struve_hypgeom(nu: f64, z: Interval, x_max: f64, modified: bool) -> Interval {
+    use crate::{arb::Arb, arb_sys::*};
+    // Arb evaluates the function by direct summation of the series.
+    // When the signs of the terms alternate, their magnitudes grow up to about e^x,
+    // which needs to be compensated by extra precision.
+    let prec = if modified {
+        f64::MANTISSA_DIGITS.into()
+    } else {
+        i64::from(f64::MANTISSA_DIGITS) + (1.5 * x_max).ceil() as i64
+    };
+    let mut a = [Arb::from_f64(1.0)];
+    let mut b = [
+        Arb::from_f64(1.5),
+        Arb::from_interval(i(nu) + const_interval!(1.5, 1.5)),
+    ];
+    let mut z = Arb::from_interval(z);
+    unsafe {
+        let z = z.as_mut_ptr();
+        arb_hypgeom_pfq(
+            z,
+            a.as_mut_ptr() as arb_ptr,
+            1,
+            b.as_mut_ptr() as arb_ptr,
+            2,
+            z,
+            1,
+            prec,
+        );
+    }
+    z.to_interval()
+}
+
+/// Returns an enclosure of K(m).
+fn elliptic_k(x: Interval) -> Interval {
+    let a = x.inf();
+    let b = x.sup();
+    if a == f64::NEG_INFINITY && b >= 1.0 {
+        const_interval!(0.0, f64::INFINITY)
+    } else if a == f64::NEG_INFINITY {
+        interval!(0.0, arb_elliptic_k(i(b)).sup()).unwrap()
+    } else if b >= 1.0 {
+        interval!(arb_elliptic_k(i(a)).inf(), f64::INFINITY).unwrap()
+    } else {
+        arb_elliptic_k(x)
+    }
+}
+
+/// Returns whether 1 - m sin^2(θ) > 0 (or ≥ 0 if `closed` is `true`) holds
+/// for every θ between 0 and φ.
+fn elliptic_integrand_def(phi: Interval, m: Interval, closed: bool) -> BoolInterval {
+    // π/2, rounded down.
+    const FRAC_PI_2_RD: f64 = 1.5707963267948966;
+    // If |φ| ≤ π/2, sin^2(θ) is maximized at θ = φ.
+    let abs_phi = phi.abs();
+    let w = m * phi.sin().sqr();
+    if closed {
+        le!(m, 1.0) | le!(abs_phi, FRAC_PI_2_RD) & le!(w, 1.0)
+    } else {
+        lt!(m, 1.0) | le!(abs_phi, FRAC_PI_2_RD) & lt!(w, 1.0)
+    }
+}
+
+/// Returns an enclosure of am(u | m).
+fn jacobi_am(u: Interval, m: Interval) -> Interval {
+    let mut y = Interval::EMPTY;
+
+    let m0 = m.intersection(const_interval!(f64::NEG_INFINITY, 1.0));
+    if !m0.is_empty() && m0 != ONE {
+        // For m < 1, am(u | m) is increasing in u, and for any integer j,
+        //
+        //   am(jK | m) = jπ/2,
+        //
+        // where K = K(m).  Thus, if u ∈ [(2k - 1)K, (2k + 1)K],
+        //
+        //   am(u | m) = kπ + asin((-1)^k sn(u | m)).
+        let s = u / elliptic_k(m0);
+        let y0 = interval!(s.inf().floor(), s.sup().ceil())
+            .map_or(Interval::ENTIRE, |j| j * Interval::FRAC_PI_2);
+        let k = ((s.inf() + 1.0) / 2.0).floor();
+        let y1 = if k.abs() < 1e15 && s.inf() >= 2.0 * k - 1.0 && s.sup() <= 2.0 * k + 1.0 {
+            let sn = jacobi_sn_cn_dn(u, m0).0;
+            let sn = if k % 2.0 == 0.0 { sn } else { -sn };
+            i(k).mul_add(Interval::PI, sn.asin())
+        } else {
+            Interval::ENTIRE
+        };
+        y = y.convex_hull(y0.intersection(y1));
+    }
+
+    let m1 = m.intersection(ONE_TO_INF);
+    if !m1.is_empty() {
+        // For m ≥ 1, am(u | m) = asin(sn(u | m)).
+        y = y.convex_hull(jacobi_sn_cn_dn(u, m1).0.asin());
+    }
+
+    y
+}
+
+/// Returns enclosures of sn(u | m), cn(u | m) and dn(u | m).
+fn jacobi_sn_cn_dn(u: Interval, m: Interval) -> (Interval, Interval, Interval) {
+    let mut sn = Interval::EMPTY;
+    let mut cn = Interval::EMPTY;
+    let mut dn = Interval::EMPTY;
+
+    let m0 = m.intersection(N_INF_TO_ZERO);
+    if !m0.is_empty() {
+        let (s, c, d) = if m0.inf() == f64::NEG_INFINITY {
+            (M_ONE_TO_ONE, M_ONE_TO_ONE, ONE_TO_INF)
+        } else {
+            // With m' = 1 - m and μ = -m/m' ∈ [0, 1),
+            //
+            //   sn(u | m) = sd(√m' u | μ) / √m',
+            //   cn(u | m) = cd(√m' u | μ),
+            //   dn(u | m) = nd(√m' u | μ).
+            let m1 = ONE - m0;
+            let sqrt_m1 = m1.sqrt();
+            let (s, c, d) = jacobi_sn_cn_dn_unit(sqrt_m1 * u, -m0 / m1);
+            (s / (d * sqrt_m1), c / d, d.recip())
+        };
+        sn = sn.convex_hull(s);
+        cn = cn.convex_hull(c);
+        dn = dn.convex_hull(d);
+    }
+
+    let m1 = m.intersection(ZERO_TO_ONE);
+    if !m1.is_empty() {
+        let (s, c, d) = jacobi_sn_cn_dn_unit(u, m1);
+        sn = sn.convex_hull(s);
+        cn = cn.convex_hull(c);
+        dn = dn.convex_hull(d);
+    }
+
+    let m2 = m.intersection(ONE_TO_INF);
+    if !m2.is_empty() {
+        let (s, c, d) = if m2.sup() == f64::INFINITY {
+            (M_ONE_TO_ONE, ZERO_TO_ONE, M_ONE_TO_ONE)
+        } else {
+            // With μ = 1/m ∈ (0, 1],
+            //
+            //   sn(u | m) = sn(√m u | μ) / √m,
+            //   cn(u | m) = dn(√m u | μ),
+            //   dn(u | m) = cn(√m u | μ).
+            let sqrt_m = m2.sqrt();
+            let (s, c, d) = jacobi_sn_cn_dn_unit(sqrt_m * u, m2.recip());
+            (s / sqrt_m, d, c)
+        };
+        sn = sn.convex_hull(s);
+        cn = cn.convex_hull(c);
+        dn = dn.convex_hull(d);
+    }
+
+    (
+        sn.intersection(M_ONE_TO_ONE),
+        cn.intersection(M_ONE_TO_ONE),
+        dn,
+    )
+}
+
+/// Returns enclosures of sn(u | m), cn(u | m) and dn(u | m) for m ⊆ [0, 1].
+fn jacobi_sn_cn_dn_unit(u: Interval, m: Interval) -> (Interval, Interval, Interval) {
+    if m == ZERO {
+        (u.sin(), u.cos(), ONE)
+    } else if m == ONE {
+        let sech = u.cosh().recip();
+        (u.tanh(), sech, sech)
+    } else if m.inf() > 0.0 && m.sup() < 1.0 && u.is_common_interval() {
+        let (s, c, d) = arb_jacobi_sn_cn_dn(u, m);
+        (
+            s.intersection(M_ONE_TO_ONE),
+            c.intersection(M_ONE_TO_ONE),
+            d.intersection(ZERO_TO_ONE),
+        )
+    } else {
+        // The nome q = exp(-πK(1 - m)/K(m)) is not bounded away from 0 or 1.
+        (M_ONE_TO_ONE, M_ONE_TO_ONE, ZERO_TO_ONE)
+    }
+}
+
+/// Returns enclosures of sn(u | m), cn(u | m) and dn(u | m) for 0 < m < 1.
+///
+/// The functions are evaluated in terms of the Jacobi theta functions:
+///
+///   sn(u | m) = θ_3 θ_1(z) / (θ_2 θ_4(z)),
+///   cn(u | m) = θ_4 θ_2(z) / (θ_2 θ_4(z)),
+///   dn(u | m) = θ_4 θ_3(z) / (θ_3 θ_4(z)),
+///
+/// where θ_j(z) = θ_j(z, τ), θ_j = θ_j(0, τ), z = u / (2K(m)) and τ = i K(1 - m) / K(m).
+fn arb_jacobi_sn_cn_dn(u: Interval, m: Interval) -> (Interval, Interval, Interval) {
+    use crate::{
+        arb::{Acb, Arb},
+        arb_sys::*,
+    };
+
+    // Returns the real part of x y / (z w).
+    fn ratio(x: &mut Acb, y: &mut Acb, z: &mut Acb, w: &mut Acb) -> Interval {
+        let mut num = Acb::new();
+        let mut den = Acb::new();
+        unsafe {
+            let num = num.as_mut_ptr();
+            let den = den.as_mut_ptr();
+            acb_mul(
+                num,
+                x.as_mut_ptr(),
+                y.as_mut_ptr(),
+                f64::MANTISSA_DIGITS.into(),
+            );
+            acb_mul(
+                den,
+                z.as_mut_ptr(),
+                w.as_mut_ptr(),
+                f64::MANTISSA_DIGITS.into(),
+            );
+            acb_div(num, num, den, f64::MANTISSA_DIGITS.into());
+        }
+        num.real().to_interval()
+    }
+
+    let mut m1 = Acb::from(Arb::from_interval(ONE - m));
+    let mut u = Acb::from(Arb::from_interval(u));
+    let mut m = Acb::from(Arb::from_interval(m));
+    let mut k = Acb::new();
+    let mut tau = Acb::new();
+    let mut z = Acb::new();
+    let mut zero = Acb::new();
+    let (mut t1, mut t2, mut t3, mut t4) = (Acb::new(), Acb::new(), Acb::new(), Acb::new());
+    let (mut t01, mut t02, mut t03, mut t04) = (Acb::new(), Acb::new(), Acb::new(), Acb::new());
+    unsafe {
+        let k = k.as_mut_ptr();
+        let tau = tau.as_mut_ptr();
+        let z = z.as_mut_ptr();
+        acb_elliptic_k(k, m.as_mut_ptr(), f64::MANTISSA_DIGITS.into());
+        acb_elliptic_k(tau, m1.as_mut_ptr(), f64::MANTISSA_DIGITS.into());
+        acb_div(tau, tau, k, f64::MANTISSA_DIGITS.into());
+        acb_mul_onei(tau, tau);
+        acb_mul_2exp_si(z, k, 1);
+        acb_div(z, u.as_mut_ptr(), z, f64::MANTISSA_DIGITS.into());
+        acb_modular_theta(
+            t1.as_mut_ptr(),
+            t2.as_mut_ptr(),
+            t3.as_mut_ptr(),
+            t4.as_mut_ptr(),
+            z,
+            tau,
+            f64::MANTISSA_DIGITS.into(),
+        );
+        acb_modular_theta(
+            t01.as_mut_ptr(),
+            t02.as_mut_ptr(),
+            t03.as_mut_ptr(),
+            t04.as_mut_ptr(),
+            zero.as_mut_ptr(),
+            tau,
+            f64::MANTISSA_DIGITS.into(),
+        );
+    }
+    let sn = ratio(&mut t03, &mut t1, &mut t02, &mut t4);
+    let cn = ratio(&mut t04, &mut t2, &mut t02, &mut t4);
+    let dn = ratio(&mut t04, &mut t3, &mut t03, &mut t4);
+    (sn, cn, dn)
+}
+
 // Envelope functions
 fn hypot(x: Interval, y: Interval) -> Interval {
     (x.sqr() + y.sqr()).sqrt()
@@ -1214,6 +2084,14 @@ fn bessel_envelope(n: Interval, x: Interval) -> Interval {
     let env = hypot(arb_bessel_j(n, a), arb_bessel_y(n, a)).sup();
     interval!(-env, env).unwrap()
 }
+fn spherical_bessel_envelope(n: u32, x: Interval) -> Interval {
+    // |j_n(x)|, |y_n(x)| ≤ √(π / (2a)) M_{n+1/2}(a) for |x| ≥ a > 0,
+    // where M_ν(x) = √(J_ν(x)^2 + Y_ν(x)^2) is decreasing for ν ≥ 1/2.
+    let a = x.abs().inf();
+    let a = interval!(a, a).unwrap();
+    let nu = i(n as f64) + ONE_HALF;
+    (Interval::FRAC_PI_2 / a).sqrt() * bessel_envelope(nu, x)
+}
 fn ci_envelope(x: Interval) -> Interval {
     let a = x.inf();
     assert!(a >= 0.0);
@@ -1258,7 +2136,9 @@ mod tests {
             TupperIntervalSet::ei,
             TupperIntervalSet::elliptic_e,
             TupperIntervalSet::elliptic_k,
+            TupperIntervalSet::erfcinv,
             TupperIntervalSet::erfi,
+            TupperIntervalSet::erfinv,
             TupperIntervalSet::fresnel_c,
             TupperIntervalSet::fresnel_s,
             TupperIntervalSet::li,
@@ -1276,7 +2156,11 @@ mod tests {
             TupperIntervalSet::bessel_j,
             TupperIntervalSet::bessel_k,
             TupperIntervalSet::bessel_y,
+            TupperIntervalSet::exp_integral_e,
             TupperIntervalSet::gamma_inc,
+            TupperIntervalSet::hyp0f1,
+            TupperIntervalSet::struve_h,
+            TupperIntervalSet::struve_l,
         ];
         let ns = vec![-2.0, -1.5, -1.0, -0.5, 0.0, 0.5, 1.0, 1.5, 2.0]
             .into_iter()
@@ -1289,5 +2173,74 @@ mod tests {
                 }
             }
         }
+
+        let fs = [
+            TupperIntervalSet::spherical_bessel_i,
+            TupperIntervalSet::spherical_bessel_j,
+            TupperIntervalSet::spherical_bessel_k,
+            TupperIntervalSet::spherical_bessel_y,
+        ];
+        let ns = vec![0.0, 1.0, 2.0]
+            .into_iter()
+            .map(|n| TupperIntervalSet::from(dec_interval!(n, n).unwrap()))
+            .collect::<Vec<_>>();
+        for f in &fs {
+            for n in &ns {
+                for x in &xs {
+                    f(n, x);
+                }
+            }
+        }
+
+        let fs = [
+            TupperIntervalSet::elliptic_e_inc,
+            TupperIntervalSet::elliptic_f,
+            TupperIntervalSet::gamma_inc_lower,
+            TupperIntervalSet::gamma_regularized,
+            TupperIntervalSet::gamma_regularized_lower,
+            TupperIntervalSet::jacobi_am,
+            TupperIntervalSet::jacobi_cn,
+            TupperIntervalSet::jacobi_dn,
+            TupperIntervalSet::jacobi_sn,
+        ];
+        for f in &fs {
+            for x in &xs {
+                for y in &xs {
+                    f(x, y);
+                }
+            }
+        }
+
+        let fs = [
+            TupperIntervalSet::beta_regularized,
+            TupperIntervalSet::elliptic_pi,
+            TupperIntervalSet::hyp1f1,
+        ];
+        for f in &fs {
+            for x in &xs {
+                for y in &xs {
+                    for z in &xs {
+                        f(x, y, z);
+                    }
+                }
+            }
+        }
+
+        let fs = [TupperIntervalSet::hyp2f1];
+        let ns = vec![-1.0, -0.5, 0.5, 1.0]
+            .into_iter()
+            .map(|n| TupperIntervalSet::from(dec_interval!(n, n).unwrap()))
+            .collect::<Vec<_>>();
+        for f in &fs {
+            for a in &ns {
+                for b in &ns {
+                    for c in &ns {
+                        for x in &xs {
+                            f(a, b, c, x);
+                        }
+                    }
+                }
+            }
+        }
     }
 }
@@ -24,6 +24,7 @@ pub enum UnaryOp {
     Asinh,
     Atan,
     Atanh,
+    Cbrt,
     Ceil,
     Chi,
     Ci,
@@ -35,30 +36,49 @@ pub enum UnaryOp {
     EllipticK,
     Erf,
     Erfc,
+    Erfcinv,
     Erfi,
+    Erfinv,
     Exp,
     Exp10,
     Exp2,
+    Expm1,
+    Factorial,
     Floor,
     FresnelC,
     FresnelS,
     Gamma,
+    Heaviside,
+    IsPrime,
     Li,
     Ln,
+    LnGamma,
     Log10,
+    Log1p,
+    Mobius,
     Neg,
     Not,
+    NumDivisors,
     One,
+    Popcount,
+    PrimePi,
     Recip,
+    Rect,
+    SawtoothWave,
     Shi,
     Si,
+    Sign,
     Sin,
     Sinc,
     Sinh,
+    Sinhc,
     Sqr,
     Sqrt,
+    SquareWave,
     Tan,
     Tanh,
+    Totient,
+    Tri,
     UndefAt0,
 }
 
@@ -71,14 +91,37 @@ pub enum BinaryOp {
     BesselJ,
     BesselK,
     BesselY,
+    Beta,
+    Binom,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ChebyshevT,
+    ChebyshevU,
     Div,
+    DivisorSigma,
+    EllipticEInc,
+    EllipticF,
     Eq,
+    ExpIntegralE,
     GammaInc,
+    GammaIncLower,
+    GammaRegularized,
+    GammaRegularizedLower,
     Gcd,
     Ge,
     Gt,
+    HermiteH,
+    Hyp0F1,
+    Hypot,
+    JacobiAm,
+    JacobiCn,
+    JacobiDn,
+    JacobiSn,
+    LaguerreL,
     Lcm,
     Le,
+    LegendreP,
     Log,
     Lt,
     Max,
@@ -91,17 +134,35 @@ pub enum BinaryOp {
     Nle,
     Nlt,
     Or,
+    Poch,
+    Polygamma,
     Pow,
     RankedMax,
     RankedMin,
+    SphericalBesselI,
+    SphericalBesselJ,
+    SphericalBesselK,
+    SphericalBesselY,
+    StruveH,
+    StruveL,
     Sub,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum TernaryOp {
+    AssocLegendreP,
+    BetaRegularized,
+    Clamp,
+    EllipticPi,
+    Hyp1F1,
     MulAdd,
 }
 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum QuaternaryOp {
+    Hyp2F1,
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum NaryOp {
     List,
@@ -116,6 +177,7 @@ pub enum ExprKind {
     Unary(UnaryOp, Box<Expr>),
     Binary(BinaryOp, Box<Expr>, Box<Expr>),
     Ternary(TernaryOp, Box<Expr>, Box<Expr>, Box<Expr>),
+    Quaternary(QuaternaryOp, Box<Expr>, Box<Expr>, Box<Expr>, Box<Expr>),
     Nary(NaryOp, Vec<Expr>),
     Pown(Box<Expr>, i32),
     Rootn(Box<Expr>, u32),
@@ -196,6 +258,17 @@ macro_rules! pown {
     };
 }
 
+/// Makes a pattern that matches an [`Expr`] of kind [`ExprKind::Quaternary`].
+#[macro_export]
+macro_rules! quaternary {
+    ($($op:pat)|*, $x:pat, $y:pat, $z:pat, $w:pat) => {
+        $crate::ast::Expr {
+            kind: $crate::ast::ExprKind::Quaternary($($op)|*, box $x, box $y, box $z, box $w),
+            ..
+        }
+    };
+}
+
 /// Makes a pattern that matches an [`Expr`] of kind [`ExprKind::Rootn`].
 #[macro_export]
 macro_rules! rootn {
@@ -298,6 +371,17 @@ impl Expr {
         Self::new(ExprKind::Pown(x, n))
     }
 
+    /// Creates a new expression of kind [`ExprKind::Quaternary`].
+    pub fn quaternary(
+        op: QuaternaryOp,
+        x: Box<Expr>,
+        y: Box<Expr>,
+        z: Box<Expr>,
+        w: Box<Expr>,
+    ) -> Self {
+        Self::new(ExprKind::Quaternary(op, x, y, z, w))
+    }
+
     /// Creates a new expression of kind [`ExprKind::Rootn`].
     pub fn rootn(x: Box<Expr>, n: u32) -> Self {
         Self::new(ExprKind::Rootn(x, n))
@@ -337,7 +421,7 @@ impl Expr {
     /// Returns [`None`] if the expression cannot be evaluated to a scalar constant
     /// or constant evaluation is not implemented for the operation.
     pub fn eval(&self) -> Option<(TupperIntervalSet, Option<Rational>)> {
-        use {BinaryOp::*, NaryOp::*, TernaryOp::*, UnaryOp::*};
+        use {BinaryOp::*, NaryOp::*, QuaternaryOp::*, TernaryOp::*, UnaryOp::*};
         match self {
             constant!(x) => Some(x.clone()),
             var!(_) => None,
@@ -352,6 +436,7 @@ impl Expr {
             unary!(Asinh, x) => x.eval1(|x| x.asinh()),
             unary!(Atan, x) => x.eval1(|x| x.atan()),
             unary!(Atanh, x) => x.eval1(|x| x.atanh()),
+            unary!(Cbrt, x) => x.eval1(|x| x.cbrt()),
             unary!(Ceil, x) => x.eval1r(|x| x.ceil(None), |x| Some(x.ceil())),
             unary!(Chi, x) => x.eval1(|x| x.chi()),
             unary!(Ci, x) => x.eval1(|x| x.ci()),
@@ -363,26 +448,51 @@ impl Expr {
             unary!(EllipticK, x) => x.eval1(|x| x.elliptic_k()),
             unary!(Erf, x) => x.eval1(|x| x.erf()),
             unary!(Erfc, x) => x.eval1(|x| x.erfc()),
+            unary!(Erfcinv, x) => x.eval1(|x| x.erfcinv()),
             unary!(Erfi, x) => x.eval1(|x| x.erfi()),
+            unary!(Erfinv, x) => x.eval1(|x| x.erfinv()),
             unary!(Exp, x) => x.eval1(|x| x.exp()),
+            unary!(Expm1, x) => x.eval1(|x| x.expm1()),
+            unary!(Factorial, x) => x.eval1r(|x| x.factorial(None), rational_ops::factorial),
             unary!(Floor, x) => x.eval1r(|x| x.floor(None), |x| Some(x.floor())),
             unary!(FresnelC, x) => x.eval1(|x| x.fresnel_c()),
             unary!(FresnelS, x) => x.eval1(|x| x.fresnel_s()),
             unary!(Gamma, x) => x.eval1(|x| x.gamma(None)),
+            unary!(Heaviside, x) => x.eval1r(|x| x.heaviside(None), rational_ops::heaviside),
+            unary!(IsPrime, x) => x.eval1r(|x| x.is_prime(None), rational_ops::is_prime),
             unary!(Li, x) => x.eval1(|x| x.li()),
             unary!(Ln, x) => x.eval1(|x| x.ln()),
+            unary!(LnGamma, x) => x.eval1(|x| x.ln_gamma(None)),
             unary!(Log10, x) => x.eval1(|x| x.log10()),
+            unary!(Log1p, x) => x.eval1(|x| x.log1p()),
+            unary!(Mobius, x) => x.eval1r(|x| x.mobius(None), rational_ops::mobius),
             unary!(Neg, x) => x.eval1r(|x| -&x, |x| Some(-x)),
+            unary!(NumDivisors, x) => {
+                x.eval1r(|x| x.num_divisors(None), rational_ops::num_divisors)
+            }
             unary!(One, x) => x.eval1(|x| x.one()),
+            unary!(Popcount, x) => x.eval1r(|x| x.popcount(None), rational_ops::popcount),
+            unary!(PrimePi, x) => x.eval1r(|x| x.prime_pi(None), rational_ops::prime_pi),
+            unary!(Rect, x) => x.eval1r(|x| x.rect(None), rational_ops::rect),
+            unary!(SawtoothWave, x) => {
+                x.eval1r(|x| x.sawtooth_wave(None), rational_ops::sawtooth_wave)
+            }
             unary!(Shi, x) => x.eval1(|x| x.shi()),
             unary!(Si, x) => x.eval1(|x| x.si()),
+            unary!(Sign, x) => x.eval1r(|x| x.sign(None), |x| Some(x.signum())),
             unary!(Sin, x) => x.eval1(|x| x.sin()),
             unary!(Sinc, x) => x.eval1(|x| x.sinc()),
             unary!(Sinh, x) => x.eval1(|x| x.sinh()),
+            unary!(Sinhc, x) => x.eval1(|x| x.sinhc()),
             unary!(Sqr, x) => x.eval1r(|x| x.sqr(), |x| Some(x.square())),
             unary!(Sqrt, x) => x.eval1(|x| x.sqrt()),
+            unary!(SquareWave, x) => {
+                x.eval1r(|x| x.square_wave(None), rational_ops::square_wave)
+            }
             unary!(Tan, x) => x.eval1(|x| x.tan(None)),
             unary!(Tanh, x) => x.eval1(|x| x.tanh()),
+            unary!(Totient, x) => x.eval1r(|x| x.totient(None), rational_ops::totient),
+            unary!(Tri, x) => x.eval1r(|x| x.tri(), rational_ops::tri),
             unary!(UndefAt0, x) => x.eval1(|x| x.undef_at_0()),
             binary!(Add, x, y) => x.eval2r(y, |x, y| &x + &y, |x, y| Some(x + y)),
             binary!(Atan2, y, x) => y.eval2(x, |y, x| y.atan2(&x, None)),
@@ -390,10 +500,41 @@ impl Expr {
             binary!(BesselJ, n, x) => n.eval2(x, |n, x| n.bessel_j(&x)),
             binary!(BesselK, n, x) => n.eval2(x, |n, x| n.bessel_k(&x)),
             binary!(BesselY, n, x) => n.eval2(x, |n, x| n.bessel_y(&x)),
+            binary!(Beta, x, y) => x.eval2r(y, |x, y| x.beta(&y, None), rational_ops::beta),
+            binary!(Binom, n, k) => n.eval2r(k, |n, k| n.binom(&k, None), rational_ops::binom),
+            binary!(BitAnd, x, y) => {
+                x.eval2r(y, |x, y| x.bit_and(&y, None), rational_ops::bit_and)
+            }
+            binary!(BitOr, x, y) => x.eval2r(y, |x, y| x.bit_or(&y, None), rational_ops::bit_or),
+            binary!(BitXor, x, y) => {
+                x.eval2r(y, |x, y| x.bit_xor(&y, None), rational_ops::bit_xor)
+            }
+            binary!(ChebyshevT, n, x) => n.eval2(x, |n, x| n.chebyshev_t(&x)),
+            binary!(ChebyshevU, n, x) => n.eval2(x, |n, x| n.chebyshev_u(&x)),
             binary!(Div, x, y) => x.eval2r(y, |x, y| x.div(&y, None), rational_ops::div),
+            binary!(DivisorSigma, k, n) => {
+                k.eval2r(n, |k, n| k.divisor_sigma(&n, None), rational_ops::divisor_sigma)
+            }
+            binary!(EllipticEInc, phi, m) => phi.eval2(m, |phi, m| phi.elliptic_e_inc(&m)),
+            binary!(EllipticF, phi, m) => phi.eval2(m, |phi, m| phi.elliptic_f(&m)),
+            binary!(ExpIntegralE, n, x) => n.eval2(x, |n, x| n.exp_integral_e(&x)),
             binary!(GammaInc, a, x) => a.eval2(x, |a, x| a.gamma_inc(&x)),
+            binary!(GammaIncLower, a, x) => a.eval2(x, |a, x| a.gamma_inc_lower(&x)),
+            binary!(GammaRegularized, a, x) => a.eval2(x, |a, x| a.gamma_regularized(&x)),
+            binary!(GammaRegularizedLower, a, x) => {
+                a.eval2(x, |a, x| a.gamma_regularized_lower(&x))
+            }
             binary!(Gcd, x, y) => x.eval2r(y, |x, y| x.gcd(&y, None), rational_ops::gcd),
+            binary!(HermiteH, n, x) => n.eval2(x, |n, x| n.hermite_h(&x)),
+            binary!(Hyp0F1, b, x) => b.eval2(x, |b, x| b.hyp0f1(&x)),
+            binary!(Hypot, x, y) => x.eval2(y, |x, y| x.hypot(&y)),
+            binary!(JacobiAm, u, m) => u.eval2(m, |u, m| u.jacobi_am(&m)),
+            binary!(JacobiCn, u, m) => u.eval2(m, |u, m| u.jacobi_cn(&m)),
+            binary!(JacobiDn, u, m) => u.eval2(m, |u, m| u.jacobi_dn(&m)),
+            binary!(JacobiSn, u, m) => u.eval2(m, |u, m| u.jacobi_sn(&m)),
+            binary!(LaguerreL, n, x) => n.eval2(x, |n, x| n.laguerre_l(&x)),
             binary!(Lcm, x, y) => x.eval2r(y, |x, y| x.lcm(&y, None), rational_ops::lcm),
+            binary!(LegendreP, n, x) => n.eval2(x, |n, x| n.legendre_p(&x)),
             // Beware the order of arguments.
             binary!(Log, b, x) => b.eval2(x, |b, x| x.log(&b, None)),
             binary!(Max, x, y) => x.eval2r(y, |x, y| x.max(&y), rational_ops::max),
@@ -402,6 +543,8 @@ impl Expr {
                 x.eval2r(y, |x, y| x.rem_euclid(&y, None), rational_ops::rem_euclid)
             }
             binary!(Mul, x, y) => x.eval2r(y, |x, y| &x * &y, |x, y| Some(x * y)),
+            binary!(Poch, x, n) => x.eval2r(n, |x, n| x.poch(&n, None), rational_ops::poch),
+            binary!(Polygamma, n, x) => n.eval2(x, |n, x| n.polygamma(&x, None)),
             binary!(Pow, x, y) => x.eval2r(y, |x, y| x.pow(&y, None), rational_ops::pow),
             binary!(RankedMax, xs, n) => Some((
                 if let nary!(List, xs) = xs {
@@ -429,8 +572,28 @@ impl Expr {
                 },
                 None,
             )),
+            binary!(SphericalBesselI, n, x) => n.eval2(x, |n, x| n.spherical_bessel_i(&x)),
+            binary!(SphericalBesselJ, n, x) => n.eval2(x, |n, x| n.spherical_bessel_j(&x)),
+            binary!(SphericalBesselK, n, x) => n.eval2(x, |n, x| n.spherical_bessel_k(&x)),
+            binary!(SphericalBesselY, n, x) => n.eval2(x, |n, x| n.spherical_bessel_y(&x)),
+            binary!(StruveH, nu, x) => nu.eval2(x, |nu, x| nu.struve_h(&x)),
+            binary!(StruveL, nu, x) => nu.eval2(x, |nu, x| nu.struve_l(&x)),
             binary!(Sub, x, y) => x.eval2r(y, |x, y| &x - &y, |x, y| Some(x - y)),
+            ternary!(AssocLegendreP, n, m, x) => {
+                n.eval3(m, x, |n, m, x| n.assoc_legendre_p(&m, &x))
+            }
+            ternary!(BetaRegularized, x, a, b) => {
+                x.eval3(a, b, |x, a, b| x.beta_regularized(&a, &b))
+            }
+            ternary!(EllipticPi, n, phi, m) => {
+                n.eval3(phi, m, |n, phi, m| n.elliptic_pi(&phi, &m))
+            }
+            ternary!(Clamp, x, a, b) => x.eval3(a, b, |x, a, b| x.clamp(&a, &b)),
+            ternary!(Hyp1F1, a, b, x) => a.eval3(b, x, |a, b, x| a.hyp1f1(&b, &x)),
             ternary!(MulAdd, _, _, _) => None,
+            quaternary!(Hyp2F1, a, b, c, x) => {
+                a.eval4(b, c, x, |a, b, c, x| a.hyp2f1(&b, &c, &x))
+            }
             nary!(Plus | Times, _) => None,
             rootn!(x, n) => x.eval1(|x| x.rootn(*n)),
             unary!(Exp10 | Exp2 | Recip, _) | pown!(_, _) => {
@@ -464,6 +627,7 @@ impl Expr {
             unary!(_, x) | pown!(x, _) | rootn!(x, _) => x.vars,
             binary!(_, x, y) => x.vars | y.vars,
             ternary!(_, x, y, z) => x.vars | y.vars | z.vars,
+            quaternary!(_, x, y, z, w) => x.vars | y.vars | z.vars | w.vars,
             nary!(_, xs) => xs.iter().fold(VarSet::EMPTY, |vs, x| vs | x.vars),
             uninit!() => panic!(),
         };
@@ -476,7 +640,7 @@ impl Expr {
     }
 
     pub fn value_type(&self) -> ValueType {
-        use {BinaryOp::*, NaryOp::*, TernaryOp::*, UnaryOp::*, ValueType::*};
+        use {BinaryOp::*, NaryOp::*, QuaternaryOp::*, TernaryOp::*, UnaryOp::*, ValueType::*};
         match self {
             constant!(_) => Scalar,
             var!(x) if x == "x" || x == "y" || x == "<n-theta>" => Scalar,
@@ -491,6 +655,7 @@ impl Expr {
                     | Asinh
                     | Atan
                     | Atanh
+                    | Cbrt
                     | Ceil
                     | Chi
                     | Ci
@@ -502,29 +667,48 @@ impl Expr {
                     | EllipticK
                     | Erf
                     | Erfc
+                    | Erfcinv
                     | Erfi
+                    | Erfinv
                     | Exp
                     | Exp10
                     | Exp2
+                    | Expm1
+                    | Factorial
                     | Floor
                     | FresnelC
                     | FresnelS
                     | Gamma
+                    | Heaviside
+                    | IsPrime
                     | Li
                     | Ln
+                    | LnGamma
                     | Log10
+                    | Log1p
+                    | Mobius
                     | Neg
+                    | NumDivisors
                     | One
+                    | Popcount
+                    | PrimePi
                     | Recip
+                    | Rect
+                    | SawtoothWave
                     | Shi
                     | Si
+                    | Sign
                     | Sin
                     | Sinc
                     | Sinh
+                    | Sinhc
                     | Sqr
                     | Sqrt
+                    | SquareWave
                     | Tan
                     | Tanh
+                    | Totient
+                    | Tri
                     | UndefAt0,
                 x
             ) if x.ty == Scalar => Scalar,
@@ -534,21 +718,64 @@ impl Expr {
                     | BesselJ
                     | BesselK
                     | BesselY
+                    | Beta
+                    | Binom
+                    | BitAnd
+                    | BitOr
+                    | BitXor
+                    | ChebyshevT
+                    | ChebyshevU
                     | Div
+                    | DivisorSigma
+                    | EllipticEInc
+                    | EllipticF
+                    | ExpIntegralE
                     | GammaInc
+                    | GammaIncLower
+                    | GammaRegularized
+                    | GammaRegularizedLower
                     | Gcd
+                    | HermiteH
+                    | Hyp0F1
+                    | Hypot
+                    | JacobiAm
+                    | JacobiCn
+                    | JacobiDn
+                    | JacobiSn
+                    | LaguerreL
                     | Lcm
+                    | LegendreP
                     | Log
                     | Max
                     | Min
                     | Mod
                     | Mul
+                    | Poch
+                    | Polygamma
                     | Pow
+                    | SphericalBesselI
+                    | SphericalBesselJ
+                    | SphericalBesselK
+                    | SphericalBesselY
+                    | StruveH
+                    | StruveL
                     | Sub,
                 x,
                 y
             ) if x.ty == Scalar && y.ty == Scalar => Scalar,
-            ternary!(MulAdd, x, y, z) if x.ty == Scalar && y.ty == Scalar && z.ty == Scalar => {
+            ternary!(
+                AssocLegendreP | BetaRegularized | Clamp | EllipticPi | Hyp1F1 | MulAdd,
+                x,
+                y,
+                z
+            )
+                if x.ty == Scalar && y.ty == Scalar && z.ty == Scalar =>
+            {
+                Scalar
+            }
+            quaternary!(Hyp2F1, x, y, z, w)
+                if x.ty == Scalar && y.ty == Scalar && z.ty == Scalar && w.ty == Scalar =>
+            {
                 Scalar
             }
             binary!(RankedMax | RankedMin, x, y) if x.ty == Vector && y.ty == Scalar => Scalar,
@@ -617,6 +844,42 @@ impl Expr {
         };
         Some((z, zr))
     }
+
+    fn eval3<F>(&self, y: &Self, z: &Self, f: F) -> Option<(TupperIntervalSet, Option<Rational>)>
+    where
+        F: Fn(TupperIntervalSet, TupperIntervalSet, TupperIntervalSet) -> TupperIntervalSet,
+    {
+        let (x, _) = self.eval()?;
+        let (y, _) = y.eval()?;
+        let (z, _) = z.eval()?;
+        let w = f(x, y, z);
+        let wr = w.to_f64().and_then(Rational::from_f64);
+        Some((w, wr))
+    }
+
+    fn eval4<F>(
+        &self,
+        y: &Self,
+        z: &Self,
+        w: &Self,
+        f: F,
+    ) -> Option<(TupperIntervalSet, Option<Rational>)>
+    where
+        F: Fn(
+            TupperIntervalSet,
+            TupperIntervalSet,
+            TupperIntervalSet,
+            TupperIntervalSet,
+        ) -> TupperIntervalSet,
+    {
+        let (x, _) = self.eval()?;
+        let (y, _) = y.eval()?;
+        let (z, _) = z.eval()?;
+        let (w, _) = w.eval()?;
+        let v = f(x, y, z, w);
+        let vr = v.to_f64().and_then(Rational::from_f64);
+        Some((v, vr))
+    }
 }
 
 impl Default for Expr {
@@ -674,6 +937,15 @@ impl<'a> fmt::Display for DumpStructure<'a> {
                 y.dump_structure(),
                 z.dump_structure()
             ),
+            quaternary!(op, x, y, z, w) => write!(
+                f,
+                "({:?} {} {} {} {})",
+                op,
+                x.dump_structure(),
+                y.dump_structure(),
+                z.dump_structure(),
+                w.dump_structure()
+            ),
             nary!(op, xs) => {
                 write!(
                     f,
@@ -1,11 +1,12 @@
 use clap::{App, AppSettings, Arg, ArgSettings};
 use graphest::{
-    graph::{Graph, GraphingStatistics, InexactRegion},
+    graph::{ExactRegion, Graph, GraphingStatistics, InexactRegion, Tightening},
     relation::Relation,
 };
 use image::{GrayAlphaImage, RgbImage};
 use inari::{const_interval, interval, Interval};
-use std::time::Duration;
+use rug::{Integer, Rational};
+use std::{str::FromStr, thread, time::Duration};
 
 fn print_statistics_header() {
     println!(
@@ -41,6 +42,37 @@ fn to_interval(s: &str) -> Interval {
     interval!(&ss).unwrap_or_else(|_| panic!("{} is not a valid number", s))
 }
 
+/// The maximum absolute value of the decimal exponent of a number accepted by [`to_rational`],
+/// which prevents the computation of 10^exp from exhausting memory.
+const MAX_DECIMAL_EXP: u64 = 10000;
+
+/// Parses a decimal number such as "-1.25e-3" exactly.
+fn to_rational(s: &str) -> Rational {
+    let invalid = || -> ! { panic!("{} is not a valid number", s) };
+    let (mant, exp) = match s.find(&['e', 'E'][..]) {
+        Some(i) => (
+            &s[..i],
+            i64::from_str(&s[i + 1..]).unwrap_or_else(|_| invalid()),
+        ),
+        _ => (s, 0),
+    };
+    let (int_part, frac_part) = match mant.find('.') {
+        Some(i) => (&mant[..i], &mant[i + 1..]),
+        _ => (mant, ""),
+    };
+    let i = Integer::from_str(&[int_part, frac_part].concat()).unwrap_or_else(|_| invalid());
+    let exp = exp.saturating_sub(frac_part.len() as i64);
+    if exp.unsigned_abs() > MAX_DECIMAL_EXP {
+        panic!("the exponent of {} is too large", s);
+    }
+    let p = Integer::from(Integer::u_pow_u(10, exp.unsigned_abs() as u32));
+    if exp >= 0 {
+        Rational::from(i * p)
+    } else {
+        Rational::from((i, p))
+    }
+}
+
 fn main() {
     let matches = App::new("graph")
         .setting(AppSettings::AllowLeadingHyphen)
@@ -55,6 +87,11 @@ fn main() {
                 .value_names(&["xmin", "xmax", "ymin", "ymax"])
                 .about("Bounds of the region to plot over."),
         )
+        .arg(
+            Arg::new("contract")
+                .long("contract")
+                .about("Contract blocks by constraint propagation before subdividing them, which can make plotting faster for relations with thin curves."),
+        )
         .arg(
             Arg::new("def")
                 .long("def")
@@ -84,6 +121,17 @@ fn main() {
                 .long("parse")
                 .about("Only parse the relation and exit with 0 iff it is valid."),
         )
+        .arg(
+            Arg::new("prec")
+                .long("prec")
+                .takes_value(true)
+                .value_name("bits")
+                .validator(|s| match u32::from_str(s) {
+                    Ok(prec) if prec >= 2 => Ok(()),
+                    _ => Err("the precision must be an integer ≥ 2"),
+                })
+                .about("Evaluates the relation with the given number of bits of precision instead of that of double. The bounds are read as exact decimal numbers."),
+        )
         .arg(
             Arg::new("size")
                 .short('s')
@@ -93,6 +141,20 @@ fn main() {
                 .value_names(&["width", "height"])
                 .about("Pixel dimensions of the output image."),
         )
+        .arg(
+            Arg::new("threads")
+                .short('j')
+                .long("threads")
+                .default_value("1")
+                .about("Number of threads to use for refinement. If 0, all available CPU cores are used."),
+        )
+        .arg(
+            Arg::new("tighten")
+                .long("tighten")
+                .possible_values(&["none", "derivatives", "affine", "taylor"])
+                .default_value("none")
+                .about("Method for tightening the evaluation of the relation, which can make plotting faster for some relations such as polynomials."),
+        )
         .get_matches();
 
     let rel = matches.value_of_t_or_exit::<Relation>("relation");
@@ -100,24 +162,52 @@ fn main() {
         return;
     }
 
-    let bounds = matches
-        .values_of_lossy("bounds")
-        .unwrap()
-        .iter()
-        .map(|s| to_interval(s))
-        .collect::<Vec<_>>();
+    let bounds = matches.values_of_lossy("bounds").unwrap();
+    let contract = matches.is_present("contract");
     let gray_alpha = matches.is_present("gray-alpha");
     let mem_limit = 1024 * 1024 * matches.value_of_t_or_exit::<usize>("mem-limit");
     let output = matches.value_of_os("output");
     let size = matches.values_of_t_or_exit::<u32>("size");
+    let tightening = match matches.value_of("tighten").unwrap() {
+        "derivatives" => Tightening::Derivatives,
+        "affine" => Tightening::AffineArithmetic,
+        "taylor" => Tightening::TaylorModel,
+        _ => Tightening::None,
+    };
+    let threads = match matches.value_of_t_or_exit::<usize>("threads") {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    };
 
-    let mut g = Graph::new(
-        rel,
-        InexactRegion::new(bounds[0], bounds[1], bounds[2], bounds[3]),
-        size[0],
-        size[1],
-        mem_limit,
-    );
+    let mut g = if matches.is_present("prec") {
+        let prec = matches.value_of_t_or_exit::<u32>("prec");
+        let bounds = bounds.iter().map(|s| to_rational(s)).collect::<Vec<_>>();
+        Graph::with_precision(
+            rel,
+            ExactRegion::new(
+                bounds[0].clone(),
+                bounds[1].clone(),
+                bounds[2].clone(),
+                bounds[3].clone(),
+            ),
+            size[0],
+            size[1],
+            mem_limit,
+            prec,
+        )
+    } else {
+        let bounds = bounds.iter().map(|s| to_interval(s)).collect::<Vec<_>>();
+        Graph::new(
+            rel,
+            InexactRegion::new(bounds[0], bounds[1], bounds[2], bounds[3]),
+            size[0],
+            size[1],
+            mem_limit,
+        )
+    };
+    g.set_threads(threads);
+    g.set_tightening(tightening);
+    g.set_use_contractor(contract);
     let mut gray_alpha_im: Option<GrayAlphaImage> = None;
     let mut rgb_im: Option<RgbImage> = None;
     if gray_alpha {
@@ -156,4 +246,11 @@ fn main() {
             _ => (),
         }
     }
+
+    if prev_stat.pixels_proven_by_escalation > 0 {
+        println!(
+            "  {} pixels were proven by re-evaluation with higher precision.",
+            prev_stat.pixels_proven_by_escalation
+        );
+    }
 }
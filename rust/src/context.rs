@@ -1,5 +1,5 @@
 use crate::{
-    ast::{BinaryOp, Expr, UnaryOp},
+    ast::{BinaryOp, Expr, QuaternaryOp, TernaryOp, UnaryOp},
     parse::parse_expr,
     visit::{Parametrize, Substitute, VisitMut},
 };
@@ -54,6 +54,49 @@ impl Def {
         }
     }
 
+    /// Creates a definition of a ternary function.
+    fn ternary(op: TernaryOp) -> Self {
+        Self::Function {
+            arity: 3,
+            body: Expr::ternary(
+                op,
+                box Expr::var("0"),
+                box Expr::var("1"),
+                box Expr::var("2"),
+            ),
+            left_associative: false,
+        }
+    }
+
+    /// Creates a definition of a quaternary function.
+    fn quaternary(op: QuaternaryOp) -> Self {
+        Self::Function {
+            arity: 4,
+            body: Expr::quaternary(
+                op,
+                box Expr::var("0"),
+                box Expr::var("1"),
+                box Expr::var("2"),
+                box Expr::var("3"),
+            ),
+            left_associative: false,
+        }
+    }
+
+    /// Creates a definition of a function whose body is given by `body`
+    /// in terms of the parameters `params` and the definitions in `ctx`.
+    ///
+    /// Panics if `body` cannot be parsed.
+    fn function(params: &[&str], body: &str, ctx: &Context) -> Self {
+        let mut body = parse_expr(body, ctx).unwrap();
+        Parametrize::new(params.iter().map(|&p| p.into()).collect()).visit_expr_mut(&mut body);
+        Self::Function {
+            arity: params.len(),
+            body,
+            left_associative: false,
+        }
+    }
+
     /// Marks the binary function as left-associative and returns `self`.
     ///
     /// Panics if `self` is not a function of arity 2.
@@ -140,28 +183,49 @@ static BUILTIN_CONTEXT: SyncLazy<Context> = SyncLazy::new(|| {
         .def("ψ", Def::unary(UnaryOp::Digamma))
         .def("Ei", Def::unary(UnaryOp::Ei))
         .def("E", Def::unary(UnaryOp::EllipticE))
+        .def("EllipticE", Def::unary(UnaryOp::EllipticE))
         .def("K", Def::unary(UnaryOp::EllipticK))
         .def("erf", Def::unary(UnaryOp::Erf))
         .def("erfc", Def::unary(UnaryOp::Erfc))
+        .def("erfcinv", Def::unary(UnaryOp::Erfcinv))
         .def("erfi", Def::unary(UnaryOp::Erfi))
+        .def("erfinv", Def::unary(UnaryOp::Erfinv))
         .def("exp", Def::unary(UnaryOp::Exp))
+        .def("factorial", Def::unary(UnaryOp::Factorial))
         .def("floor", Def::unary(UnaryOp::Floor))
         .def("C", Def::unary(UnaryOp::FresnelC))
         .def("S", Def::unary(UnaryOp::FresnelS))
         .def("Gamma", Def::unary(UnaryOp::Gamma))
         .def("Γ", Def::unary(UnaryOp::Gamma))
+        .def("Heaviside", Def::unary(UnaryOp::Heaviside))
+        .def("isprime", Def::unary(UnaryOp::IsPrime))
         .def("li", Def::unary(UnaryOp::Li))
         .def("ln", Def::unary(UnaryOp::Ln))
+        .def("lnGamma", Def::unary(UnaryOp::LnGamma))
+        .def("lnΓ", Def::unary(UnaryOp::LnGamma))
         .def("log", Def::unary(UnaryOp::Log10))
+        .def("mobius", Def::unary(UnaryOp::Mobius))
+        .def("μ", Def::unary(UnaryOp::Mobius))
         .def("-", Def::unary(UnaryOp::Neg))
         .def("!", Def::unary(UnaryOp::Not))
+        .def("numdivisors", Def::unary(UnaryOp::NumDivisors))
+        .def("popcount", Def::unary(UnaryOp::Popcount))
+        .def("primepi", Def::unary(UnaryOp::PrimePi))
+        .def("rect", Def::unary(UnaryOp::Rect))
+        .def("sawtooth", Def::unary(UnaryOp::SawtoothWave))
         .def("Shi", Def::unary(UnaryOp::Shi))
         .def("Si", Def::unary(UnaryOp::Si))
+        .def("sgn", Def::unary(UnaryOp::Sign))
+        .def("sign", Def::unary(UnaryOp::Sign))
         .def("sin", Def::unary(UnaryOp::Sin))
         .def("sinh", Def::unary(UnaryOp::Sinh))
         .def("sqrt", Def::unary(UnaryOp::Sqrt))
+        .def("square", Def::unary(UnaryOp::SquareWave))
         .def("tan", Def::unary(UnaryOp::Tan))
         .def("tanh", Def::unary(UnaryOp::Tanh))
+        .def("totient", Def::unary(UnaryOp::Totient))
+        .def("φ", Def::unary(UnaryOp::Totient))
+        .def("tri", Def::unary(UnaryOp::Tri))
         .def("+", Def::binary(BinaryOp::Add))
         .def("&&", Def::binary(BinaryOp::And))
         .def("atan2", Def::binary(BinaryOp::Atan2))
@@ -169,15 +233,39 @@ static BUILTIN_CONTEXT: SyncLazy<Context> = SyncLazy::new(|| {
         .def("J", Def::binary(BinaryOp::BesselJ))
         .def("K", Def::binary(BinaryOp::BesselK))
         .def("Y", Def::binary(BinaryOp::BesselY))
+        .def("Beta", Def::binary(BinaryOp::Beta))
+        .def("binom", Def::binary(BinaryOp::Binom))
+        .def("bitand", Def::binary(BinaryOp::BitAnd).left_associative())
+        .def("bitor", Def::binary(BinaryOp::BitOr).left_associative())
+        .def("bitxor", Def::binary(BinaryOp::BitXor).left_associative())
+        .def("T", Def::binary(BinaryOp::ChebyshevT))
+        .def("U", Def::binary(BinaryOp::ChebyshevU))
         .def("/", Def::binary(BinaryOp::Div))
+        .def("sigma", Def::binary(BinaryOp::DivisorSigma))
+        .def("σ", Def::binary(BinaryOp::DivisorSigma))
+        .def("EllipticE", Def::binary(BinaryOp::EllipticEInc))
+        .def("F", Def::binary(BinaryOp::EllipticF))
         .def("=", Def::binary(BinaryOp::Eq))
+        .def("E", Def::binary(BinaryOp::ExpIntegralE))
         .def("Gamma", Def::binary(BinaryOp::GammaInc))
         .def("Γ", Def::binary(BinaryOp::GammaInc))
+        .def("gammaLower", Def::binary(BinaryOp::GammaIncLower))
+        .def("Q", Def::binary(BinaryOp::GammaRegularized))
+        .def("gammaQ", Def::binary(BinaryOp::GammaRegularized))
+        .def("gammaP", Def::binary(BinaryOp::GammaRegularizedLower))
         .def("gcd", Def::binary(BinaryOp::Gcd).left_associative())
         .def(">=", Def::binary(BinaryOp::Ge))
         .def(">", Def::binary(BinaryOp::Gt))
+        .def("H", Def::binary(BinaryOp::HermiteH))
+        .def("hyp0f1", Def::binary(BinaryOp::Hyp0F1))
+        .def("am", Def::binary(BinaryOp::JacobiAm))
+        .def("cn", Def::binary(BinaryOp::JacobiCn))
+        .def("dn", Def::binary(BinaryOp::JacobiDn))
+        .def("sn", Def::binary(BinaryOp::JacobiSn))
+        .def("L", Def::binary(BinaryOp::LaguerreL))
         .def("lcm", Def::binary(BinaryOp::Lcm).left_associative())
         .def("<=", Def::binary(BinaryOp::Le))
+        .def("P", Def::binary(BinaryOp::LegendreP))
         .def("log", Def::binary(BinaryOp::Log))
         .def("<", Def::binary(BinaryOp::Lt))
         .def("max", Def::binary(BinaryOp::Max).left_associative())
@@ -185,19 +273,75 @@ static BUILTIN_CONTEXT: SyncLazy<Context> = SyncLazy::new(|| {
         .def("mod", Def::binary(BinaryOp::Mod))
         .def("*", Def::binary(BinaryOp::Mul))
         .def("||", Def::binary(BinaryOp::Or))
+        .def("poch", Def::binary(BinaryOp::Poch))
+        .def("psi", Def::binary(BinaryOp::Polygamma))
+        .def("ψ", Def::binary(BinaryOp::Polygamma))
         .def("^", Def::binary(BinaryOp::Pow))
         .def("ranked_max", Def::binary(BinaryOp::RankedMax))
         .def("ranked_min", Def::binary(BinaryOp::RankedMin))
-        .def("-", Def::binary(BinaryOp::Sub));
-
-    let mut body = parse_expr("⌊min(max(x, -0.5), 0.5)⌋ + ⌈min(max(x, -0.5), 0.5)⌉", &ctx).unwrap();
-    Parametrize::new(vec!["x".into()]).visit_expr_mut(&mut body);
-    let def = Def::Function {
-        arity: 1,
-        body,
-        left_associative: false,
-    };
-    ctx.def("sgn", def.clone()).def("sign", def)
+        .def("i", Def::binary(BinaryOp::SphericalBesselI))
+        .def("j", Def::binary(BinaryOp::SphericalBesselJ))
+        .def("k", Def::binary(BinaryOp::SphericalBesselK))
+        .def("y", Def::binary(BinaryOp::SphericalBesselY))
+        .def("StruveH", Def::binary(BinaryOp::StruveH))
+        .def("StruveL", Def::binary(BinaryOp::StruveL))
+        .def("-", Def::binary(BinaryOp::Sub))
+        .def("P", Def::ternary(TernaryOp::AssocLegendreP))
+        .def("I", Def::ternary(TernaryOp::BetaRegularized))
+        .def("clamp", Def::ternary(TernaryOp::Clamp))
+        .def("Pi", Def::ternary(TernaryOp::EllipticPi))
+        .def("Π", Def::ternary(TernaryOp::EllipticPi))
+        .def("hyp1f1", Def::ternary(TernaryOp::Hyp1F1))
+        .def("hyp2f1", Def::quaternary(QuaternaryOp::Hyp2F1));
+
+    // Helpers for the probability distributions, which are not exposed.
+    // A definition can refer to the ones preceding it.
+    #[rustfmt::skip]
+    let helpers: &[(&str, &[&str], &str)] = &[
+        // 1 if ⌊k⌋ ≥ n and 0 otherwise, where n is an integer.
+        ("at_least", &["k", "n"], "clamp(floor(k) - n + 1, 0, 1)"),
+        // binomcdf(k, n, p) = I(1 - p, n - k, k + 1) holds for 0 ≤ k < n.
+        // Outside that range, k is clamped to it and x is replaced with 0 or 1,
+        // so that the CDF is 0 for k < 0 and 1 for k ≥ n.
+        ("binomcdf_k", &["k", "n"], "clamp(floor(k), 0, n - 1)"),
+        ("binomcdf_x", &["k", "n", "p"], "min(max(1 - p, at_least(k, n)), at_least(k, 0))"),
+    ];
+    let mut helper_ctx = helpers
+        .iter()
+        .fold(ctx.clone(), |ctx, &(name, params, body)| {
+            let def = Def::function(params, body, &ctx);
+            ctx.def(name, def)
+        });
+
+    // Probability distributions. A definition can refer to the helpers and the ones preceding it.
+    #[rustfmt::skip]
+    let dists: &[(&str, &[&str], &str)] = &[
+        ("normpdf", &["x"], "exp(-x^2 / 2) / sqrt(2 pi)"),
+        ("normpdf", &["x", "mu", "sigma"], "normpdf((x - mu) / sigma) / sigma"),
+        ("normcdf", &["x"], "erfc(-x / sqrt(2)) / 2"),
+        ("normcdf", &["x", "mu", "sigma"], "normcdf((x - mu) / sigma)"),
+        ("normquantile", &["p"], "-sqrt(2) erfcinv(2 p)"),
+        ("normquantile", &["p", "mu", "sigma"], "mu + sigma normquantile(p)"),
+        ("tpdf", &["x", "nu"], "(1 + x^2 / nu)^(-(nu + 1) / 2) / (sqrt(nu) Beta(nu / 2, 1 / 2))"),
+        ("tcdf", &["x", "nu"], "Heaviside(x) - sgn(x) I(nu / (x^2 + nu), nu / 2, 1 / 2) / 2"),
+        ("gammapdf", &["x", "k", "theta"], "x^(k - 1) exp(-x / theta) / (Gamma(k) theta^k)"),
+        ("gammacdf", &["x", "k", "theta"], "gammaP(k, max(x, 0) / theta)"),
+        ("chi2pdf", &["x", "k"], "gammapdf(x, k / 2, 2)"),
+        ("chi2cdf", &["x", "k"], "gammacdf(x, k / 2, 2)"),
+        ("betapdf", &["x", "a", "b"], "x^(a - 1) (1 - x)^(b - 1) / Beta(a, b)"),
+        ("betacdf", &["x", "a", "b"], "I(clamp(x, 0, 1), a, b)"),
+        ("binompdf", &["k", "n", "p"], "binom(n, k) p^k (1 - p)^(n - k)"),
+        ("binomcdf", &["k", "n", "p"], "I(binomcdf_x(k, n, p), n - binomcdf_k(k, n), binomcdf_k(k, n) + 1)"),
+        ("poissonpdf", &["k", "lambda"], "lambda^k exp(-lambda) / factorial(k)"),
+        ("poissoncdf", &["k", "lambda"], "at_least(k, 0) Q(max(floor(k), 0) + 1, lambda)"),
+    ];
+    let mut ctx = ctx;
+    for &(name, params, body) in dists {
+        let def = Def::function(params, body, &helper_ctx);
+        helper_ctx = helper_ctx.def(name, def.clone());
+        ctx = ctx.def(name, def);
+    }
+    ctx
 });
 
 impl Context {
@@ -357,3 +501,48 @@ impl<'a> Slice<RangeFull> for InputWithContext<'a> {
 }
 
 impl<'a> UnspecializedInput for InputWithContext<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use inari::Interval;
+
+    fn eval_const(e: &str) -> Interval {
+        let e = parse_expr(e, Context::builtin_context()).unwrap();
+        e.eval().unwrap().0.single().unwrap().x
+    }
+
+    #[test]
+    fn distributions() {
+        let f = eval_const;
+        assert!(f("normpdf(0)").contains(0.3989422804014327));
+        assert!(f("normpdf(3, 1, 2)").contains(0.12098536225957168));
+        assert!(f("normcdf(0)").contains(0.5));
+        assert!(f("normcdf(-10)").contains(7.619853024160527e-24));
+        assert!(f("normcdf(-10)").inf() > 0.0);
+        assert!(f("normcdf(10)").contains(1.0));
+        assert!(f("binompdf(2, 4, 0.5)").contains(0.375));
+        assert!(f("poissonpdf(2, 1)").contains(0.18393972058572117));
+        assert!(parse_expr("at_least(0, 0)", Context::builtin_context()).is_err());
+    }
+
+    #[cfg(feature = "arb")]
+    #[test]
+    fn distributions_arb() {
+        use inari::const_interval;
+
+        let f = eval_const;
+        assert!(f("normquantile(0.975)").contains(1.959963984540054));
+        assert_eq!(f("gammacdf(-1, 2, 1)"), const_interval!(0.0, 0.0));
+        assert_eq!(f("binomcdf(-1, 4, 0.5)"), const_interval!(0.0, 0.0));
+        assert_eq!(f("binomcdf(-0.5, 4, 0.5)"), const_interval!(0.0, 0.0));
+        assert!(f("binomcdf(0, 4, 0.5)").contains(0.0625));
+        assert!(f("binomcdf(2.5, 4, 0.5)").contains(0.6875));
+        assert_eq!(f("binomcdf(4, 4, 0.5)"), const_interval!(1.0, 1.0));
+        assert_eq!(f("binomcdf(10, 4, 0.5)"), const_interval!(1.0, 1.0));
+        assert_eq!(f("poissoncdf(-1, 2)"), const_interval!(0.0, 0.0));
+        assert!(f("poissoncdf(0, 2)").contains(0.1353352832366127));
+        assert!(f("poissoncdf(3, 2)").contains(0.857123460498547));
+        assert!(f("poissoncdf(30, 2)").contains(1.0));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     interval_set::DecSignSet,
-    ops::{StaticForm, StaticFormKind},
+    ops::{FormIndex, StaticForm, StaticFormKind},
 };
 use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
 use smallvec::SmallVec;
@@ -54,16 +54,21 @@ impl EvalResultMask {
 
     /// Returns `true` if the existence of a solution is concluded by the arguments.
     /// See the actual usage for details.
+    ///
+    /// `common_zero_forms` are the indices of the conjunctions of two atomic formulas
+    /// that are known to be true at some common point.
     pub fn solution_certainly_exists(
         &self,
         forms: &[StaticForm],
         locally_zero_mask: &Self,
+        common_zero_forms: &[FormIndex],
     ) -> bool {
         Self::solution_certainly_exists_impl(
             &self.0[..],
             forms,
             forms.len() - 1,
             &locally_zero_mask.0[..],
+            common_zero_forms,
         )
     }
 
@@ -72,28 +77,48 @@ impl EvalResultMask {
         forms: &[StaticForm],
         i: usize,
         locally_zero_mask: &[bool],
+        common_zero_forms: &[FormIndex],
     ) -> bool {
         use StaticFormKind::*;
         match &forms[i].kind {
             Atomic(_, _) => slf[i],
+            And(_, _) if common_zero_forms.contains(&(i as FormIndex)) => true,
             And(x, y) => {
                 if Self::eval_impl(locally_zero_mask, forms, *x as usize) {
-                    Self::solution_certainly_exists_impl(slf, forms, *y as usize, locally_zero_mask)
+                    Self::solution_certainly_exists_impl(
+                        slf,
+                        forms,
+                        *y as usize,
+                        locally_zero_mask,
+                        common_zero_forms,
+                    )
                 } else if Self::eval_impl(locally_zero_mask, forms, *y as usize) {
-                    Self::solution_certainly_exists_impl(slf, forms, *x as usize, locally_zero_mask)
+                    Self::solution_certainly_exists_impl(
+                        slf,
+                        forms,
+                        *x as usize,
+                        locally_zero_mask,
+                        common_zero_forms,
+                    )
                 } else {
                     // Cannot tell the existence of a solution by a normal conjunction.
                     false
                 }
             }
             Or(x, y) => {
-                Self::solution_certainly_exists_impl(slf, forms, *x as usize, locally_zero_mask)
-                    || Self::solution_certainly_exists_impl(
-                        slf,
-                        forms,
-                        *y as usize,
-                        locally_zero_mask,
-                    )
+                Self::solution_certainly_exists_impl(
+                    slf,
+                    forms,
+                    *x as usize,
+                    locally_zero_mask,
+                    common_zero_forms,
+                ) || Self::solution_certainly_exists_impl(
+                    slf,
+                    forms,
+                    *y as usize,
+                    locally_zero_mask,
+                    common_zero_forms,
+                )
             }
         }
     }
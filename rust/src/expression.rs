@@ -0,0 +1,176 @@
+use crate::{
+    ast::{BinaryOp, Expr, ValueType, VarSet},
+    context::Context,
+    interval_set::TupperIntervalSet,
+    ops::{Program, StaticTerm, StoreIndex, ValueStore},
+    parse::parse_expr,
+    relation::simplify,
+    var,
+    visit::*,
+};
+use inari::{const_interval, interval, DecInterval, Interval};
+use std::str::FromStr;
+
+/// A real-valued expression of x and y that can be evaluated with interval arithmetic.
+///
+/// An expression is parsed from the same syntax as a [`Relation`](crate::relation::Relation),
+/// e.g., "sin(x) + y^2". The polar coordinates r and θ can also be used, which stand for
+/// √(x² + y²) and atan2(y, x) ∈ (-π, π], respectively.
+#[derive(Clone, Debug)]
+pub struct Expression {
+    terms: Vec<StaticTerm>,
+    program: Program,
+    ts: ValueStore<TupperIntervalSet>,
+    root: StoreIndex,
+}
+
+impl Expression {
+    /// Evaluates the expression over the box X × Y.
+    ///
+    /// Returns a set of decorated intervals whose union encloses the values of the expression
+    /// at the points in the box where it is defined. The set is empty if the expression
+    /// is nowhere defined in the box. Each interval is decorated with
+    /// [`Decoration::Trv`](inari::Decoration::Trv) if the expression can be undefined
+    /// at some point in the box.
+    pub fn eval(&mut self, x: Interval, y: Interval) -> Vec<DecInterval> {
+        self.program
+            .eval(&self.terms, &mut self.ts, x, y, const_interval!(0.0, 0.0));
+        let mut xs = self.ts[self.root].clone();
+        xs.normalize(true);
+        xs.iter().map(|x| x.dec_interval()).collect()
+    }
+
+    /// Evaluates the expression at the point (x, y).
+    ///
+    /// See [`Self::eval`] for the result.
+    ///
+    /// Panics if either of the coordinates is NaN.
+    pub fn eval_point(&mut self, x: f64, y: f64) -> Vec<DecInterval> {
+        let point = |x: f64| interval!(x, x).unwrap();
+        self.eval(point(x), point(y))
+    }
+}
+
+impl FromStr for Expression {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut e = parse_expr(s, Context::builtin_context())?;
+        #[cfg(not(feature = "arb"))]
+        {
+            let mut v = FindUnavailableFunction::default();
+            v.visit_expr(&e);
+            if let Some(error) = v.error {
+                return Err(error);
+            }
+        }
+        UpdateMetadata.visit_expr_mut(&mut e);
+        PreTransform.visit_expr_mut(&mut e);
+        simplify(&mut e);
+        let mut v = ReplaceAll::new(|e| match e {
+            var!(x) if x == "r" => Some(Expr::binary(
+                BinaryOp::Hypot,
+                box Expr::var("x"),
+                box Expr::var("y"),
+            )),
+            var!(x) if x == "theta" || x == "θ" => Some(Expr::binary(
+                BinaryOp::Atan2,
+                box Expr::var("y"),
+                box Expr::var("x"),
+            )),
+            _ => None,
+        });
+        v.visit_expr_mut(&mut e);
+        UpdateMetadata.visit_expr_mut(&mut e);
+        simplify(&mut e);
+        SubDivTransform.visit_expr_mut(&mut e);
+        simplify(&mut e);
+        PostTransform.visit_expr_mut(&mut e);
+        FuseMulAdd.visit_expr_mut(&mut e);
+        UpdateMetadata.visit_expr_mut(&mut e);
+        if e.ty != ValueType::Scalar {
+            return Err("the expression must be a real-valued expression".into());
+        }
+        let mut v = AssignId::new();
+        v.visit_expr_mut(&mut e);
+        let collector = CollectStatic::new(v);
+        let root = collector.store_index(&e);
+        let terms = collector.terms;
+        let n_terms = terms.len();
+
+        let program = Program::new(&terms, |t| t.vars != VarSet::EMPTY);
+        let mut ts = ValueStore::new(TupperIntervalSet::new(), n_terms);
+        for t in &terms {
+            if t.vars == VarSet::EMPTY {
+                t.put_eval(&mut ts);
+            }
+        }
+
+        Ok(Self {
+            terms,
+            program,
+            ts,
+            root,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use inari::{const_dec_interval, Decoration};
+
+    #[test]
+    fn expression() {
+        fn f(e: &str, x: Interval, y: Interval) -> Vec<DecInterval> {
+            e.parse::<Expression>().unwrap().eval(x, y)
+        }
+
+        assert_eq!(
+            f(
+                "x^2 + y",
+                const_interval!(-1.0, 2.0),
+                const_interval!(1.0, 1.0)
+            ),
+            vec![const_dec_interval!(1.0, 5.0)]
+        );
+        assert_eq!(
+            "2x - y".parse::<Expression>().unwrap().eval_point(3.0, 1.5),
+            vec![const_dec_interval!(4.5, 4.5)]
+        );
+        assert_eq!(
+            "r".parse::<Expression>().unwrap().eval_point(3.0, 4.0),
+            vec![const_dec_interval!(5.0, 5.0)]
+        );
+        assert_eq!(
+            f("1 + 2", Interval::ENTIRE, Interval::ENTIRE),
+            vec![const_dec_interval!(3.0, 3.0)]
+        );
+
+        // Partially defined.
+        let xs = f(
+            "sqrt(x)",
+            const_interval!(-1.0, 4.0),
+            const_interval!(0.0, 0.0),
+        );
+        assert_eq!(xs.len(), 1);
+        assert_eq!(xs[0].interval(), Some(const_interval!(0.0, 2.0)));
+        assert_eq!(xs[0].decoration(), Decoration::Trv);
+
+        // Nowhere defined.
+        assert!(f(
+            "sqrt(x)",
+            const_interval!(-2.0, -1.0),
+            const_interval!(0.0, 0.0)
+        )
+        .is_empty());
+
+        // Two branches.
+        assert_eq!(
+            f("1/x", const_interval!(-1.0, 1.0), const_interval!(0.0, 0.0)).len(),
+            2
+        );
+
+        assert!("x = y".parse::<Expression>().is_err());
+    }
+}
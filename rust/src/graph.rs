@@ -3,18 +3,29 @@ use crate::{
     eval_result::EvalResult,
     image::{Image, PixelIndex},
     interval_set::{DecSignSet, SignSet},
+    mp_interval::MpInterval,
     ops::StaticForm,
     relation::{EvalCache, EvalCacheLevel, Relation, RelationType},
 };
 use image::{imageops, GrayAlphaImage, LumaA, Rgb, RgbImage};
-use inari::{interval, Decoration, Interval};
-use itertools::Itertools;
+use inari::{interval, DecInterval, Decoration, Interval};
+use itertools::{Either, Itertools};
+use rug::{Float, Rational};
 use std::{
     convert::TryFrom,
     error, fmt,
+    mem::{size_of, swap, take},
+    thread,
     time::{Duration, Instant},
 };
 
+/// The number of blocks per thread that are subdivided at once in parallel refinement.
+const BLOCKS_PER_THREAD_IN_BATCH: usize = 256;
+
+/// The maximum precision in bits with which blocks that cannot be subdivided further
+/// are re-evaluated.
+const MAX_ESCALATION_PREC: u32 = 1024;
+
 /// The graphing status of a pixel.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum PixelState {
@@ -151,6 +162,256 @@ impl InexactRegion {
     }
 }
 
+/// A rectangular region of the Cartesian plane with exact bounds.
+#[derive(Clone, Debug)]
+pub struct ExactRegion {
+    l: Rational,
+    r: Rational,
+    b: Rational,
+    t: Rational,
+}
+
+impl ExactRegion {
+    /// Creates a new [`ExactRegion`] with the given bounds.
+    pub fn new(l: Rational, r: Rational, b: Rational, t: Rational) -> Self {
+        assert!(l <= r && b <= t);
+        Self { l, r, b, t }
+    }
+
+    /// Returns the smallest [`InexactRegion`] that contains the region.
+    fn to_inexact_region(&self) -> InexactRegion {
+        let f = |x: &Rational| {
+            MpInterval::from_rational(x, f64::MANTISSA_DIGITS)
+                .to_dec_interval()
+                .interval()
+                .unwrap()
+        };
+        InexactRegion::new(f(&self.l), f(&self.r), f(&self.b), f(&self.t))
+    }
+}
+
+/// A possibly empty rectangular region of the Cartesian plane with multiple-precision bounds.
+#[derive(Clone, Debug)]
+struct MpRegion(MpInterval, MpInterval);
+
+impl MpRegion {
+    /// Creates an [`MpRegion`] with the precision `prec` that contains the given [`Region`].
+    fn from_region(r: &Region, prec: u32) -> Self {
+        Self(
+            MpInterval::from_dec_interval(DecInterval::new(r.0), prec),
+            MpInterval::from_dec_interval(DecInterval::new(r.1), prec),
+        )
+    }
+
+    /// Returns the intersection of the regions.
+    fn intersection(&self, rhs: &Self) -> Self {
+        Self(self.0.intersection(&rhs.0), self.1.intersection(&rhs.1))
+    }
+
+    /// Returns `true` if the region is empty.
+    fn is_empty(&self) -> bool {
+        self.0.is_empty() || self.1.is_empty()
+    }
+
+    /// Returns the smallest region with the precision `prec` that contains the region.
+    fn with_prec(&self, prec: u32) -> Self {
+        Self(self.0.with_prec(prec), self.1.with_prec(prec))
+    }
+}
+
+/// A rectangular region of the Cartesian plane with inexact multiple-precision bounds.
+///
+/// See [`InexactRegion`] for details.
+#[derive(Clone, Debug)]
+struct MpInexactRegion {
+    l: MpInterval,
+    r: MpInterval,
+    b: MpInterval,
+    t: MpInterval,
+}
+
+impl MpInexactRegion {
+    /// Returns the inner region.
+    fn inner(&self) -> MpRegion {
+        MpRegion(
+            Self::interval(self.l.sup(), self.r.inf()),
+            Self::interval(self.b.sup(), self.t.inf()),
+        )
+    }
+
+    /// Returns the outer region.
+    fn outer(&self) -> MpRegion {
+        MpRegion(
+            Self::interval(self.l.inf(), self.r.sup()),
+            Self::interval(self.b.inf(), self.t.sup()),
+        )
+    }
+
+    /// Returns a subset of the outer region.
+    ///
+    /// See [`InexactRegion::subpixel_outer`] for details.
+    ///
+    /// Precondition: the block is a subpixel.
+    fn subpixel_outer(&self, blk: Block) -> MpRegion {
+        let mask_x = blk.pixel_align_x() - 1;
+        let mask_y = blk.pixel_align_y() - 1;
+
+        let l = if blk.x & mask_x == 0 {
+            self.l.inf().clone()
+        } else {
+            self.l.mid()
+        };
+        let r = if (blk.x + 1) & mask_x == 0 {
+            self.r.sup().clone()
+        } else {
+            self.r.mid()
+        };
+        let b = if blk.y & mask_y == 0 {
+            self.b.inf().clone()
+        } else {
+            self.b.mid()
+        };
+        let t = if (blk.y + 1) & mask_y == 0 {
+            self.t.sup().clone()
+        } else {
+            self.t.mid()
+        };
+        MpRegion(Self::interval(&l, &r), Self::interval(&b, &t))
+    }
+
+    /// Returns the interval `[a, b]` if `a ≤ b`; otherwise, an empty interval.
+    fn interval(a: &Float, b: &Float) -> MpInterval {
+        if a <= b {
+            MpInterval::new(a.clone(), b.clone(), Decoration::Com)
+        } else {
+            MpInterval::empty(a.prec())
+        }
+    }
+}
+
+/// The affine transformation from pixel coordinates to real coordinates in exact arithmetic.
+///
+/// The results are rounded to intervals with the precision `prec`.
+struct MpTransform {
+    sx: Rational,
+    sy: Rational,
+    tx: Rational,
+    ty: Rational,
+    prec: u32,
+}
+
+impl MpTransform {
+    /// Returns the x coordinate that corresponds to the pixel coordinate `px`.
+    fn x(&self, px: f64) -> MpInterval {
+        let px = Rational::from_f64(px).unwrap();
+        MpInterval::from_rational(&(px * &self.sx + &self.tx), self.prec)
+    }
+
+    /// Returns the y coordinate that corresponds to the pixel coordinate `py`.
+    fn y(&self, py: f64) -> MpInterval {
+        let py = Rational::from_f64(py).unwrap();
+        MpInterval::from_rational(&(py * &self.sy + &self.ty), self.prec)
+    }
+}
+
+/// The pixels that cannot be proven with subdivision and are refined again with higher precision.
+struct PrecisionEscalation {
+    /// The precision with which the blocks are evaluated.
+    prec: u32,
+    /// The stack of the blocks that are yet to be evaluated with `prec`.
+    ///
+    /// Initially, it contains the pixel-level blocks that contain the blocks
+    /// that cannot be subdivided further.
+    bs: Vec<Block>,
+    /// The pixel-level blocks that contain the blocks that have remained uncertain
+    /// after evaluation with `prec`.
+    next_bs: Vec<Block>,
+}
+
+impl PrecisionEscalation {
+    /// Appends the pixel-level block that contains `b` to `bs`
+    /// unless it is the same as the last one.
+    fn push_pixel_block(bs: &mut Vec<Block>, b: Block) {
+        let pixel = b.pixel_index();
+        let b = Block::new(pixel.x, pixel.y, 0, 0, b.n_theta);
+        if bs.last() != Some(&b) {
+            bs.push(b);
+        }
+    }
+
+    /// Returns the size allocated by the blocks in bytes.
+    fn size_in_heap(&self) -> usize {
+        (self.bs.capacity() + self.next_bs.capacity()) * size_of::<Block>()
+    }
+}
+
+/// Points at which a relation is evaluated.
+enum SamplePoints {
+    F64(Vec<(f64, f64)>),
+    Mp(Vec<(MpInterval, MpInterval)>),
+}
+
+impl SamplePoints {
+    fn is_empty(&self) -> bool {
+        match self {
+            SamplePoints::F64(ps) => ps.is_empty(),
+            SamplePoints::Mp(ps) => ps.is_empty(),
+        }
+    }
+}
+
+/// The result of evaluating a relation over a block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum BlockResult {
+    /// Each pixel that the block overlaps contains a solution.
+    True,
+    /// There are no solutions in the block.
+    False,
+    /// Neither of the above has been proven.
+    Unknown,
+}
+
+/// A copy of the relation and the caches for evaluating it, which are owned by a thread.
+struct Worker {
+    rel: Relation,
+    cache_eval_on_region: EvalCache,
+    cache_eval_on_point: EvalCache,
+}
+
+impl Worker {
+    fn new(rel: Relation) -> Self {
+        let (cache_eval_on_region, cache_eval_on_point) = Self::new_caches(rel.relation_type());
+        Self {
+            rel,
+            cache_eval_on_region,
+            cache_eval_on_point,
+        }
+    }
+
+    fn clear_caches(&mut self) {
+        let (cache_eval_on_region, cache_eval_on_point) =
+            Self::new_caches(self.rel.relation_type());
+        self.cache_eval_on_region = cache_eval_on_region;
+        self.cache_eval_on_point = cache_eval_on_point;
+    }
+
+    /// Returns the size allocated by the caches in bytes.
+    fn size_in_heap(&self) -> usize {
+        self.cache_eval_on_region.size_in_heap() + self.cache_eval_on_point.size_in_heap()
+    }
+
+    fn new_caches(relation_type: RelationType) -> (EvalCache, EvalCache) {
+        // Blocks are queued in the Morton order. Thanks to that, the caches should work efficiently.
+        let cache_eval_on_region = EvalCache::new(EvalCacheLevel::PerAxis);
+        let cache_eval_on_point = if relation_type == RelationType::Polar {
+            EvalCache::new(EvalCacheLevel::PerAxis)
+        } else {
+            EvalCache::new(EvalCacheLevel::Full)
+        };
+        (cache_eval_on_region, cache_eval_on_point)
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum GraphingErrorKind {
     BlockIndexOverflow,
@@ -182,9 +443,29 @@ pub struct GraphingStatistics {
     pub pixels: usize,
     pub pixels_proven: usize,
     pub eval_count: usize,
+    /// The number of pixels that have been proven by re-evaluation with higher precision
+    /// after reaching the subdivision limit.
+    pub pixels_proven_by_escalation: usize,
     pub time_elapsed: Duration,
 }
 
+/// The method for tightening the evaluation of the relation over a block
+/// when the plain interval evaluation proves neither that the relation is true
+/// nor that it is false.
+///
+/// Tightening only applies to evaluation in [`f64`] precision.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tightening {
+    /// No tightening.
+    None,
+    /// Use the derivatives of the terms (see [`Relation::eval_tightened`]).
+    Derivatives,
+    /// Use affine arithmetic (see [`Relation::eval_affine`]).
+    AffineArithmetic,
+    /// Use Taylor models (see [`Relation::eval_taylor`]).
+    TaylorModel,
+}
+
 pub struct Graph {
     rel: Relation,
     forms: Vec<StaticForm>,
@@ -202,8 +483,15 @@ pub struct Graph {
     sy: Interval,
     tx: Interval,
     ty: Interval,
+    // The exact counterpart of the transformation, which is used instead of the above
+    // when the relation is evaluated in multiple precision.
+    mp_transform: Option<MpTransform>,
+    escalation: PrecisionEscalation,
     stats: GraphingStatistics,
     mem_limit: usize,
+    n_threads: usize,
+    tightening: Tightening,
+    use_contractor: bool,
 }
 
 impl Graph {
@@ -227,13 +515,23 @@ impl Graph {
             sy: region.height() / Self::point_interval(im_height as f64),
             tx: region.l,
             ty: region.b,
+            mp_transform: None,
+            escalation: PrecisionEscalation {
+                prec: 2 * f64::MANTISSA_DIGITS,
+                bs: vec![],
+                next_bs: vec![],
+            },
             stats: GraphingStatistics {
                 pixels: im_width as usize * im_height as usize,
                 pixels_proven: 0,
                 eval_count: 0,
+                pixels_proven_by_escalation: 0,
                 time_elapsed: Duration::ZERO,
             },
             mem_limit,
+            n_threads: 1,
+            tightening: Tightening::None,
+            use_contractor: false,
         };
         let k = (im_width.max(im_height) as f64).log2().ceil() as i8;
         if relation_type == RelationType::Polar {
@@ -269,6 +567,66 @@ impl Graph {
         g
     }
 
+    /// Creates a graph whose relation is evaluated in multiple precision with `prec` bits
+    /// rather than in [`f64`], which allows plotting over a region that is too small
+    /// to be distinguished by [`f64`] numbers.
+    ///
+    /// Panics if `prec` is less than 2.
+    pub fn with_precision(
+        rel: Relation,
+        region: ExactRegion,
+        im_width: u32,
+        im_height: u32,
+        mem_limit: usize,
+        prec: u32,
+    ) -> Self {
+        assert!(prec >= 2);
+        let mut g = Self::new(
+            rel,
+            region.to_inexact_region(),
+            im_width,
+            im_height,
+            mem_limit,
+        );
+        g.mp_transform = Some(MpTransform {
+            sx: Rational::from(&region.r - &region.l) / im_width,
+            sy: Rational::from(&region.t - &region.b) / im_height,
+            tx: region.l,
+            ty: region.b,
+            prec,
+        });
+        g.escalation.prec = 2 * prec;
+        g
+    }
+
+    /// Sets the number of threads that evaluate blocks in parallel during refinement.
+    ///
+    /// Panics if `n_threads` is zero.
+    pub fn set_threads(&mut self, n_threads: usize) {
+        assert!(n_threads > 0);
+        self.n_threads = n_threads;
+    }
+
+    /// Sets the method for tightening the evaluation of the relation over blocks.
+    ///
+    /// Tightening reduces subdivision of blocks for relations whose interval evaluation
+    /// tends to overestimate, such as polynomials with many terms, but is slower for others.
+    /// The default is [`Tightening::None`].
+    pub fn set_tightening(&mut self, tightening: Tightening) {
+        self.tightening = tightening;
+    }
+
+    /// Sets whether to contract each block with [`Relation::contract`] before its sub-blocks
+    /// are evaluated, so that the sub-blocks outside the contracted box are proven to be false
+    /// without being evaluated.
+    ///
+    /// Contraction saves evaluation of the blocks that are near thin curves, but costs
+    /// an extra evaluation per subdivided block. It is only done in [`f64`] precision.
+    /// The default is `false`.
+    pub fn set_use_contractor(&mut self, use_contractor: bool) {
+        self.use_contractor = use_contractor;
+    }
+
     pub fn get_gray_alpha_image(&self, im: &mut GrayAlphaImage) {
         assert!(im.width() == self.im.width() && im.height() == self.im.height());
         for (src, dst) in self.im.iter().copied().zip(im.pixels_mut()) {
@@ -301,7 +659,6 @@ impl Graph {
                 .copied()
                 .filter(|&s| s == PixelState::False || s == PixelState::True)
                 .count(),
-            eval_count: self.rel.eval_count(),
             ..self.stats
         }
     }
@@ -311,121 +668,136 @@ impl Graph {
     /// Returns `Ok(true)`/`Ok(false)` if graphing is complete/incomplete after refinement.
     pub fn refine(&mut self, timeout: Duration) -> Result<bool, GraphingError> {
         let now = Instant::now();
-        let result = self.refine_impl(timeout, &now);
+        let mut workers = (0..self.n_threads)
+            .map(|_| Worker::new(self.rel.clone()))
+            .collect::<Vec<_>>();
+        let result = self.refine_impl(timeout, &now, &mut workers);
+        // `self.rel` is never evaluated, thus the counts are only those of the workers.
+        self.stats.eval_count += workers.iter().map(|w| w.rel.eval_count()).sum::<usize>();
         self.stats.time_elapsed += now.elapsed();
         result
     }
 
-    fn refine_impl(&mut self, timeout: Duration, now: &Instant) -> Result<bool, GraphingError> {
-        let mut sub_bs = vec![];
-        let mut incomplete_sub_bs = vec![];
-        // Blocks are queued in the Morton order. Thanks to that, the caches should work efficiently.
-        let mut cache_eval_on_region = EvalCache::new(EvalCacheLevel::PerAxis);
-        let mut cache_eval_on_point = if self.relation_type == RelationType::Polar {
-            EvalCache::new(EvalCacheLevel::PerAxis)
+    fn refine_impl(
+        &mut self,
+        timeout: Duration,
+        now: &Instant,
+        workers: &mut [Worker],
+    ) -> Result<bool, GraphingError> {
+        // The blocks are subdivided in batches so that the sub-blocks can be evaluated in parallel.
+        // With a single thread, the blocks are processed one by one, as the sub-blocks can be
+        // skipped if their pixels have been proven to be true by the previous ones.
+        let batch_size = if workers.len() == 1 {
+            1
         } else {
-            EvalCache::new(EvalCacheLevel::Full)
+            BLOCKS_PER_THREAD_IN_BATCH * workers.len()
         };
-        while let Some((bi, b)) = self.bs_to_subdivide.pop_front() {
-            match b.next_dir {
-                SubdivisionDir::NTheta => Self::subdivide_on_n_theta(&mut sub_bs, b),
-                SubdivisionDir::XY => self.subdivide_on_xy(&mut sub_bs, b),
-            }
-
-            let n_sub_bs = sub_bs.len();
-            for (sub_b, is_last_sibling) in sub_bs.drain(..) {
-                let complete = if !sub_b.is_subpixel() {
-                    self.refine_pixel(
-                        sub_b,
-                        is_last_sibling,
-                        QueuedBlockIndex::try_from(bi).unwrap(),
-                        &mut cache_eval_on_region,
-                    )
-                } else {
-                    if self.relation_type == RelationType::Polar && !sub_b.n_theta.is_singleton() {
-                        // Try finding a solution earlier.
-                        let n = Self::point_interval(Self::simple_number(sub_b.n_theta));
-                        self.refine_subpixel(
-                            Block::new(sub_b.x, sub_b.y, sub_b.kx, sub_b.ky, n),
-                            false,
-                            0,
-                            &mut cache_eval_on_region,
-                            &mut cache_eval_on_point,
-                        );
+        let mut bs = vec![];
+        let mut sub_bs = vec![];
+        let mut batch_sub_bs = vec![];
+        let mut batch_groups = vec![];
+        let mut results = vec![];
+        let mut incomplete_sub_bs = vec![];
+        loop {
+            while bs.len() < batch_size {
+                match self.bs_to_subdivide.pop_front() {
+                    Some((bi, b)) => {
+                        match b.next_dir {
+                            SubdivisionDir::NTheta => Self::subdivide_on_n_theta(&mut sub_bs, b),
+                            SubdivisionDir::XY => self.subdivide_on_xy(&mut sub_bs, b),
+                        }
+                        let contract = self.use_contractor
+                            && self.mp_transform.is_none()
+                            && b.next_dir == SubdivisionDir::XY;
+                        results.resize(results.len() + sub_bs.len(), BlockResult::Unknown);
+                        batch_groups.push((sub_bs.len(), contract));
+                        bs.push((bi, b, sub_bs.len()));
+                        batch_sub_bs.append(&mut sub_bs);
                     }
-                    self.refine_subpixel(
-                        sub_b,
-                        is_last_sibling,
-                        QueuedBlockIndex::try_from(bi).unwrap(),
-                        &mut cache_eval_on_region,
-                        &mut cache_eval_on_point,
-                    )
-                };
-                if !complete {
-                    // We can't queue the block yet because we need to modify `sub_b.next_dir`
-                    // after all sub-blocks are processed.
-                    self.set_last_queued_block(
-                        &sub_b,
-                        self.bs_to_subdivide.next_back_index() + incomplete_sub_bs.len(),
-                    )?;
-                    incomplete_sub_bs.push(sub_b);
+                    _ => break,
                 }
             }
+            if bs.is_empty() {
+                break;
+            }
 
-            let preferred_next_dir = if self.relation_type == RelationType::Polar {
-                let n_max = match b.next_dir {
-                    SubdivisionDir::NTheta => 3,
-                    SubdivisionDir::XY => 4,
-                };
-                if n_max * incomplete_sub_bs.len() <= n_sub_bs {
-                    // Subdivide in the same direction again.
-                    b.next_dir
-                } else {
-                    // Subdivide in other direction.
-                    match b.next_dir {
-                        SubdivisionDir::NTheta => SubdivisionDir::XY,
-                        SubdivisionDir::XY => SubdivisionDir::NTheta,
+            self.eval_blocks(workers, &batch_sub_bs, &batch_groups, &mut results);
+            batch_groups.clear();
+
+            // The pixel states are updated in the same order as the blocks are queued
+            // so that `last_queued_blocks` remains consistent.
+            let mut sub_bs_and_results = batch_sub_bs.drain(..).zip(results.drain(..));
+            for (bi, b, n_sub_bs) in bs.drain(..) {
+                let parent_block_index = QueuedBlockIndex::try_from(bi).unwrap();
+                for ((sub_b, is_last_sibling), result) in sub_bs_and_results.by_ref().take(n_sub_bs)
+                {
+                    let complete =
+                        self.set_pixel_states(sub_b, result, is_last_sibling, parent_block_index);
+                    if !complete {
+                        // We can't queue the block yet because we need to modify `sub_b.next_dir`
+                        // after all sub-blocks are processed.
+                        self.set_last_queued_block(
+                            &sub_b,
+                            self.bs_to_subdivide.next_back_index() + incomplete_sub_bs.len(),
+                        )?;
+                        incomplete_sub_bs.push(sub_b);
                     }
                 }
-            } else {
-                SubdivisionDir::XY
-            };
 
-            for mut sub_b in incomplete_sub_bs.drain(..) {
-                sub_b.next_dir = if preferred_next_dir == SubdivisionDir::NTheta
-                    && sub_b.is_subdivisible_on_n_theta()
-                {
-                    SubdivisionDir::NTheta
-                } else if sub_b.is_subdivisible_on_xy() {
-                    SubdivisionDir::XY
-                } else if self.relation_type == RelationType::Polar
-                    && sub_b.is_subdivisible_on_n_theta()
-                {
-                    SubdivisionDir::NTheta
+                let preferred_next_dir = if self.relation_type == RelationType::Polar {
+                    let n_max = match b.next_dir {
+                        SubdivisionDir::NTheta => 3,
+                        SubdivisionDir::XY => 4,
+                    };
+                    if n_max * incomplete_sub_bs.len() <= n_sub_bs {
+                        // Subdivide in the same direction again.
+                        b.next_dir
+                    } else {
+                        // Subdivide in other direction.
+                        match b.next_dir {
+                            SubdivisionDir::NTheta => SubdivisionDir::XY,
+                            SubdivisionDir::XY => SubdivisionDir::NTheta,
+                        }
+                    }
                 } else {
-                    assert!(sub_b.is_subpixel());
-                    let pixel = b.pixel_index();
-                    *self.im.get_mut(pixel) = PixelState::UncertainNeverFalse;
-                    continue;
+                    SubdivisionDir::XY
                 };
-                self.bs_to_subdivide.push_back(sub_b);
+
+                for mut sub_b in incomplete_sub_bs.drain(..) {
+                    sub_b.next_dir = if preferred_next_dir == SubdivisionDir::NTheta
+                        && sub_b.is_subdivisible_on_n_theta()
+                    {
+                        SubdivisionDir::NTheta
+                    } else if sub_b.is_subdivisible_on_xy() {
+                        SubdivisionDir::XY
+                    } else if self.relation_type == RelationType::Polar
+                        && sub_b.is_subdivisible_on_n_theta()
+                    {
+                        SubdivisionDir::NTheta
+                    } else {
+                        assert!(sub_b.is_subpixel());
+                        let pixel = b.pixel_index();
+                        *self.im.get_mut(pixel) = PixelState::UncertainNeverFalse;
+                        PrecisionEscalation::push_pixel_block(&mut self.escalation.bs, sub_b);
+                        continue;
+                    };
+                    self.bs_to_subdivide.push_back(sub_b);
+                }
             }
+            drop(sub_bs_and_results);
 
             let mut clear_cache_and_retry = true;
             while self.im.size_in_heap()
                 + self.last_queued_blocks.size_in_heap()
                 + self.bs_to_subdivide.size_in_heap()
-                + cache_eval_on_region.size_in_heap()
-                + cache_eval_on_point.size_in_heap()
+                + self.escalation.size_in_heap()
+                + workers.iter().map(|w| w.size_in_heap()).sum::<usize>()
                 > self.mem_limit
             {
                 if clear_cache_and_retry {
-                    cache_eval_on_region = EvalCache::new(EvalCacheLevel::PerAxis);
-                    cache_eval_on_point = if self.relation_type == RelationType::Polar {
-                        EvalCache::new(EvalCacheLevel::PerAxis)
-                    } else {
-                        EvalCache::new(EvalCacheLevel::Full)
-                    };
+                    for w in workers.iter_mut() {
+                        w.clear_caches();
+                    }
                     clear_cache_and_retry = false;
                 } else {
                     return Err(GraphingError {
@@ -440,6 +812,10 @@ impl Graph {
         }
 
         if self.bs_to_subdivide.is_empty() {
+            if !self.escalate_precision(timeout, now, &mut workers[0]) {
+                return Ok(false);
+            }
+
             if self
                 .im
                 .iter()
@@ -456,6 +832,68 @@ impl Graph {
         }
     }
 
+    /// Refines the pixels that cannot be proven with subdivision again in multiple precision,
+    /// doubling the precision up to [`MAX_ESCALATION_PREC`] bits, and then marks the pixels
+    /// whose blocks are all proven to be false as [`PixelState::False`].
+    ///
+    /// Returns `true` if the refinement is complete.
+    fn escalate_precision(&mut self, timeout: Duration, now: &Instant, w: &mut Worker) -> bool {
+        let mut sub_bs = vec![];
+        while self.escalation.prec <= MAX_ESCALATION_PREC {
+            // The blocks are refined in the depth-first order, so that the sub-blocks
+            // of each pixel-level block are pushed to `next_bs` consecutively.
+            while let Some(b) = self.escalation.bs.pop() {
+                let pixel = b.pixel_index();
+                if self.im.get(pixel) == PixelState::True {
+                    continue;
+                }
+
+                let result = self.eval_subpixel(w, b, Some(self.escalation.prec));
+                let complete = self.set_pixel_states(b, result, false, 0);
+                if self.im.get(pixel) == PixelState::True {
+                    self.stats.pixels_proven_by_escalation += 1;
+                } else if !complete {
+                    if b.is_subdivisible_on_xy() {
+                        self.subdivide_on_xy(&mut sub_bs, b);
+                        self.escalation
+                            .bs
+                            .extend(sub_bs.drain(..).map(|(sub_b, _)| sub_b));
+                    } else {
+                        PrecisionEscalation::push_pixel_block(&mut self.escalation.next_bs, b);
+                    }
+                }
+
+                if now.elapsed() > timeout {
+                    return false;
+                }
+            }
+
+            if self.escalation.next_bs.is_empty() {
+                break;
+            }
+            swap(&mut self.escalation.bs, &mut self.escalation.next_bs);
+            self.escalation.prec *= 2;
+        }
+
+        let mut has_uncertain_block = Image::<bool>::new(self.im.width(), self.im.height());
+        for b in self.escalation.bs.iter().chain(&self.escalation.next_bs) {
+            *has_uncertain_block.get_mut(b.pixel_index()) = true;
+        }
+        for y in 0..self.im.height() {
+            for x in 0..self.im.width() {
+                let pixel = PixelIndex::new(x, y);
+                if self.im.get(pixel) == PixelState::UncertainNeverFalse
+                    && !has_uncertain_block.get(pixel)
+                {
+                    // All blocks of the pixel have been proven to be false.
+                    *self.im.get_mut(pixel) = PixelState::False;
+                    self.stats.pixels_proven_by_escalation += 1;
+                }
+            }
+        }
+        true
+    }
+
     fn set_last_queued_block(
         &mut self,
         b: &Block,
@@ -487,21 +925,156 @@ impl Graph {
         }
     }
 
-    /// Refine the block and returns `true` if refinement is complete.
+    /// Evaluates the relation over each block in `bs` whose result in `results` is
+    /// [`BlockResult::Unknown`] and stores the result in the corresponding element.
+    /// The elements for the blocks that need not be evaluated are left unchanged.
     ///
-    /// Precondition: the block must be a pixel or a superpixel.
-    fn refine_pixel(
+    /// `groups` are the numbers of the consecutive blocks in `bs` that are obtained by
+    /// subdividing the same block, along with whether they should be contracted
+    /// with [`Self::contract_sub_blocks`] before the evaluation.
+    ///
+    /// The blocks are split into contiguous chunks of whole groups, each of which is processed
+    /// by one of `workers` on its own thread. Since the blocks are in the Morton order,
+    /// the caches of each worker should still work efficiently.
+    fn eval_blocks(
+        &self,
+        workers: &mut [Worker],
+        bs: &[(Block, bool)],
+        groups: &[(usize, bool)],
+        results: &mut [BlockResult],
+    ) {
+        let eval = |w: &mut Worker,
+                    bs: &[(Block, bool)],
+                    groups: &[(usize, bool)],
+                    results: &mut [BlockResult]| {
+            let mut i = 0;
+            for &(n, contract) in groups {
+                if contract {
+                    self.contract_sub_blocks(w, &bs[i..i + n], &mut results[i..i + n]);
+                }
+                i += n;
+            }
+
+            // The pixel that has been proven to be true by the last block.
+            let mut true_pixel = None;
+            for (&(b, _), result) in bs.iter().zip(results) {
+                if *result != BlockResult::Unknown {
+                    continue;
+                }
+                if !b.is_superpixel() && true_pixel == Some(b.pixel_index()) {
+                    // The pixel will have been proven to be true by the time
+                    // the pixel states are updated with the result of this block.
+                    continue;
+                }
+                *result = self.eval_block(w, b);
+                if *result == BlockResult::True && !b.is_superpixel() {
+                    true_pixel = Some(b.pixel_index());
+                }
+            }
+        };
+
+        if let [w] = workers {
+            eval(w, bs, groups, results);
+            return;
+        }
+
+        let n = workers.len();
+        let chunk_size = ((bs.len() + n - 1) / n).max(1);
+        thread::scope(|s| {
+            let mut workers = workers.iter_mut();
+            let (mut bs, mut groups, mut results) = (bs, groups, results);
+            while !groups.is_empty() {
+                // Every chunk but the last one has at least `chunk_size` blocks,
+                // so there are at most `n` chunks.
+                let mut n_groups = 0;
+                let mut n_bs = 0;
+                while n_groups < groups.len() && n_bs < chunk_size {
+                    n_bs += groups[n_groups].0;
+                    n_groups += 1;
+                }
+                let (chunk_bs, rest_bs) = bs.split_at(n_bs);
+                let (chunk_groups, rest_groups) = groups.split_at(n_groups);
+                let (chunk_results, rest_results) = take(&mut results).split_at_mut(n_bs);
+                let w = workers.next().unwrap();
+                s.spawn(move || eval(w, chunk_bs, chunk_groups, chunk_results));
+                bs = rest_bs;
+                groups = rest_groups;
+                results = rest_results;
+            }
+        });
+    }
+
+    /// Contracts the hull of the regions of the sub-blocks with [`Relation::contract`]
+    /// and sets the results of the sub-blocks whose regions do not intersect the contracted box
+    /// to [`BlockResult::False`]. The other results are left unchanged.
+    ///
+    /// Precondition: the sub-blocks are obtained by subdividing a block on x and y.
+    fn contract_sub_blocks(
+        &self,
+        w: &mut Worker,
+        sub_bs: &[(Block, bool)],
+        results: &mut [BlockResult],
+    ) {
+        let regions = sub_bs
+            .iter()
+            .map(|&(b, _)| {
+                if b.is_subpixel() {
+                    self.block_to_region(b).subpixel_outer(b)
+                } else {
+                    self.block_to_region_clipped(b).outer()
+                }
+            })
+            .collect::<Vec<_>>();
+        let x = regions
+            .iter()
+            .fold(Interval::EMPTY, |acc, r| acc.convex_hull(r.0));
+        let y = regions
+            .iter()
+            .fold(Interval::EMPTY, |acc, r| acc.convex_hull(r.1));
+        let (x, y) = w.rel.contract(x, y, sub_bs[0].0.n_theta);
+        let contracted = Region(x, y);
+        for (r, result) in regions.iter().zip(results) {
+            if r.intersection(&contracted).is_empty() {
+                *result = BlockResult::False;
+            }
+        }
+    }
+
+    /// Evaluates the relation over the block.
+    fn eval_block(&self, w: &mut Worker, b: Block) -> BlockResult {
+        if !b.is_subpixel() {
+            self.eval_pixel(w, b)
+        } else {
+            if self.relation_type == RelationType::Polar && !b.n_theta.is_singleton() {
+                // Try finding a solution earlier.
+                let n = Self::point_interval(Self::simple_number(b.n_theta));
+                let result = self.eval_subpixel(w, Block::new(b.x, b.y, b.kx, b.ky, n), None);
+                if result == BlockResult::True {
+                    return result;
+                }
+            }
+            self.eval_subpixel(w, b, None)
+        }
+    }
+
+    /// Updates the states of the pixels that the block overlaps with the result of evaluation
+    /// and returns `true` if refinement of the block is complete.
+    fn set_pixel_states(
         &mut self,
         b: Block,
+        result: BlockResult,
         b_is_last_sibling: bool,
         parent_block_index: QueuedBlockIndex,
-        cache: &mut EvalCache,
     ) -> bool {
         let pixel_begin = b.pixel_index();
-        let pixel_end = PixelIndex::new(
-            (pixel_begin.x + b.width()).min(self.im.width()),
-            (pixel_begin.y + b.height()).min(self.im.height()),
-        );
+        let pixel_end = if b.is_subpixel() {
+            PixelIndex::new(pixel_begin.x + 1, pixel_begin.y + 1)
+        } else {
+            PixelIndex::new(
+                (pixel_begin.x + b.width()).min(self.im.width()),
+                (pixel_begin.y + b.height()).min(self.im.height()),
+            )
+        };
 
         let mut all_true = true;
         'outer: for y in pixel_begin.y..pixel_end.y {
@@ -519,16 +1092,7 @@ impl Graph {
             return true;
         }
 
-        let u_up = self.block_to_region_clipped(b).outer();
-        let r_u_up = Self::eval_on_region(&mut self.rel, &u_up, b.n_theta, Some(cache));
-        let is_true = r_u_up
-            .map(|DecSignSet(ss, d)| ss == SignSet::ZERO && d >= Decoration::Def)
-            .eval(&self.forms[..]);
-        let is_false = !r_u_up
-            .map(|DecSignSet(ss, _)| ss.contains(SignSet::ZERO))
-            .eval(&self.forms[..]);
-
-        if !(is_true || is_false) {
+        if result == BlockResult::Unknown {
             return false;
         }
 
@@ -542,10 +1106,9 @@ impl Graph {
                     continue;
                 }
 
-                if is_true {
+                if result == BlockResult::True {
                     *self.im.get_mut(pixel) = PixelState::True;
-                } else if is_false
-                    && b_is_last_sibling
+                } else if b_is_last_sibling
                     && self.last_queued_blocks.get(pixel) == parent_block_index
                     && state != PixelState::UncertainNeverFalse
                 {
@@ -556,58 +1119,177 @@ impl Graph {
         true
     }
 
-    /// Refine the block and returns `true` if refinement is complete.
+    /// Evaluates the relation over the block.
     ///
-    /// Precondition: the block must be a subpixel.
-    fn refine_subpixel(
-        &mut self,
-        b: Block,
-        b_is_last_sibling: bool,
-        parent_block_index: QueuedBlockIndex,
-        cache_eval_on_region: &mut EvalCache,
-        cache_eval_on_point: &mut EvalCache,
-    ) -> bool {
+    /// Precondition: the block must be a pixel or a superpixel.
+    fn eval_pixel(&self, w: &mut Worker, b: Block) -> BlockResult {
+        let pixel_begin = b.pixel_index();
+        let pixel_end = PixelIndex::new(
+            (pixel_begin.x + b.width()).min(self.im.width()),
+            (pixel_begin.y + b.height()).min(self.im.height()),
+        );
+
+        let mut all_true = true;
+        'outer: for y in pixel_begin.y..pixel_end.y {
+            for x in pixel_begin.x..pixel_end.x {
+                let pixel = PixelIndex::new(x, y);
+                let state = self.im.get(pixel);
+                if state != PixelState::True {
+                    all_true = false;
+                    break 'outer;
+                }
+            }
+        }
+        if all_true {
+            // All pixels have already been proven to be true, which is checked again
+            // when the pixel states are updated.
+            return BlockResult::Unknown;
+        }
+
+        let r_u_up = match &self.mp_transform {
+            Some(mp) => {
+                let u_up = self.block_to_mp_region_clipped(mp, b).outer();
+                w.rel.eval_mp(&u_up.0, &u_up.1, b.n_theta)
+            }
+            _ => {
+                let u_up = self.block_to_region_clipped(b).outer();
+                self.eval_on_region(
+                    &mut w.rel,
+                    &u_up,
+                    b.n_theta,
+                    Some(&mut w.cache_eval_on_region),
+                )
+            }
+        };
+        let is_true = r_u_up
+            .map(|DecSignSet(ss, d)| ss == SignSet::ZERO && d >= Decoration::Def)
+            .eval(&self.forms[..]);
+        let is_false = !r_u_up
+            .map(|DecSignSet(ss, _)| ss.contains(SignSet::ZERO))
+            .eval(&self.forms[..]);
+
+        if is_true {
+            BlockResult::True
+        } else if is_false {
+            BlockResult::False
+        } else {
+            BlockResult::Unknown
+        }
+    }
+
+    /// Evaluates the relation over the block.
+    ///
+    /// If `prec` is [`Some`], the relation is evaluated in multiple precision with `prec` bits
+    /// regardless of the mode of the graph.
+    ///
+    /// Precondition: the block must be a subpixel, or a pixel if `prec` is [`Some`].
+    fn eval_subpixel(&self, w: &mut Worker, b: Block, prec: Option<u32>) -> BlockResult {
         let pixel = b.pixel_index();
         let state = self.im.get(pixel);
         assert_ne!(state, PixelState::False);
         if state == PixelState::True {
-            // This pixel has already been proven to be true.
-            return true;
+            // This pixel has already been proven to be true, which is checked again
+            // when the pixel state is updated.
+            return BlockResult::Unknown;
         }
 
-        let u_up = self.block_to_region(b).subpixel_outer(b);
-        let r_u_up =
-            Self::eval_on_region(&mut self.rel, &u_up, b.n_theta, Some(cache_eval_on_region));
+        // `inter` is the part of the subpixel that is certainly inside the pixel.
+        // `points` are the sample points taken from `inter`, which are used later,
+        // and are empty iff `inter` is empty.
+        // `newton_region` is `inter` if it is nonempty and the relation is evaluated
+        // in [`f64`] precision; otherwise, [`None`].
+        let (r_u_up, points, newton_region) = match (&self.mp_transform, prec) {
+            (None, None) => {
+                let u_up = self.block_to_region(b).subpixel_outer(b);
+                let r_u_up = self.eval_on_region(
+                    &mut w.rel,
+                    &u_up,
+                    b.n_theta,
+                    Some(&mut w.cache_eval_on_region),
+                );
+
+                let p_dn = self.block_to_region(b.pixel_block()).inner();
+                let inter = u_up.intersection(&p_dn);
+                if inter.is_empty() {
+                    (r_u_up, SamplePoints::F64(vec![]), None)
+                } else {
+                    let points = SamplePoints::F64(vec![
+                        (Self::simple_number(inter.0), Self::simple_number(inter.1)),
+                        (inter.0.inf(), inter.1.inf()), // bottom left
+                        (inter.0.sup(), inter.1.inf()), // bottom right
+                        (inter.0.inf(), inter.1.sup()), // top left
+                        (inter.0.sup(), inter.1.sup()), // top right
+                    ]);
+                    (r_u_up, points, Some(inter))
+                }
+            }
+            _ => {
+                // The regions are computed in the same way as without `prec`
+                // so that they are consistent with those of the other blocks.
+                let (u_up, p_dn) = match &self.mp_transform {
+                    Some(mp) => {
+                        let prec = prec.unwrap_or(mp.prec);
+                        (
+                            self.block_to_mp_region(mp, b)
+                                .subpixel_outer(b)
+                                .with_prec(prec),
+                            self.block_to_mp_region(mp, b.pixel_block())
+                                .inner()
+                                .with_prec(prec),
+                        )
+                    }
+                    _ => {
+                        let prec = prec.unwrap();
+                        (
+                            MpRegion::from_region(&self.block_to_region(b).subpixel_outer(b), prec),
+                            MpRegion::from_region(
+                                &self.block_to_region(b.pixel_block()).inner(),
+                                prec,
+                            ),
+                        )
+                    }
+                };
+                let r_u_up = w.rel.eval_mp(&u_up.0, &u_up.1, b.n_theta);
 
-        let p_dn = self.block_to_region(b.pixel_block()).inner();
-        let inter = u_up.intersection(&p_dn);
+                let inter = u_up.intersection(&p_dn);
+                let points = SamplePoints::Mp(if inter.is_empty() {
+                    vec![]
+                } else {
+                    let (x, y) = (&inter.0, &inter.1);
+                    vec![
+                        (x.mid(), y.mid()),
+                        (x.inf().clone(), y.inf().clone()), // bottom left
+                        (x.sup().clone(), y.inf().clone()), // bottom right
+                        (x.inf().clone(), y.sup().clone()), // top left
+                        (x.sup().clone(), y.sup().clone()), // top right
+                    ]
+                    .into_iter()
+                    .map(|(x, y)| (MpInterval::point(x), MpInterval::point(y)))
+                    .collect()
+                });
+                (r_u_up, points, None)
+            }
+        };
 
         // Save `locally_zero_mask` for later use (see the comment below).
         let locally_zero_mask =
             r_u_up.map(|DecSignSet(ss, d)| ss == SignSet::ZERO && d >= Decoration::Def);
-        if locally_zero_mask.eval(&self.forms[..]) && !inter.is_empty() {
+        if locally_zero_mask.eval(&self.forms[..]) && !points.is_empty() {
             // The relation is true everywhere in the subpixel, and the subpixel certainly overlaps
             // with the pixel. Therefore, the pixel contains a solution.
-            *self.im.get_mut(pixel) = PixelState::True;
-            return true;
+            return BlockResult::True;
         }
         if !r_u_up
             .map(|DecSignSet(ss, _)| ss.contains(SignSet::ZERO))
             .eval(&self.forms[..])
         {
             // The relation is false everywhere in the subpixel.
-            if b_is_last_sibling
-                && self.last_queued_blocks.get(pixel) == parent_block_index
-                && state != PixelState::UncertainNeverFalse
-            {
-                *self.im.get_mut(pixel) = PixelState::False;
-            }
-            return true;
+            return BlockResult::False;
         }
 
-        if inter.is_empty() {
+        if points.is_empty() {
             // We still need to refine the subpixel to show absence of solutions.
-            return false;
+            return BlockResult::Unknown;
         }
 
         // Evaluate the relation for some sample points within the inner bounds of the subpixel
@@ -626,25 +1308,21 @@ impl Graph {
         //    "|y - sin(x)| + |x ≥ 0 ? 0 : 1| = 0".
         let dac_mask = r_u_up.map(|DecSignSet(_, d)| d >= Decoration::Dac);
 
-        let points = [
-            (Self::simple_number(inter.0), Self::simple_number(inter.1)),
-            (inter.0.inf(), inter.1.inf()), // bottom left
-            (inter.0.sup(), inter.1.inf()), // bottom right
-            (inter.0.inf(), inter.1.sup()), // top left
-            (inter.0.sup(), inter.1.sup()), // top right
-        ];
-
         let mut neg_mask = r_u_up.map(|_| false);
         let mut pos_mask = neg_mask.clone();
-        for point in &points {
-            let r = Self::eval_on_point(
-                &mut self.rel,
-                point.0,
-                point.1,
-                b.n_theta,
-                Some(cache_eval_on_point),
-            );
-
+        let rs = match &points {
+            SamplePoints::F64(ps) => Either::Left(
+                w.rel
+                    .eval_points(ps, b.n_theta, Some(&mut w.cache_eval_on_point))
+                    .into_iter(),
+            ),
+            // Evaluation in multiple precision is much more expensive,
+            // so the points are evaluated one by one until a solution is found.
+            SamplePoints::Mp(ps) => {
+                Either::Right(ps.iter().map(|(x, y)| w.rel.eval_mp(x, y, b.n_theta)))
+            }
+        };
+        for r in rs {
             // `ss` is nonempty if the decoration is ≥ `Def`, which will be ensured
             // by taking bitand with `dac_mask`.
             neg_mask |= r.map(|DecSignSet(ss, _)| (SignSet::NEG | SignSet::ZERO).contains(ss));
@@ -652,40 +1330,65 @@ impl Graph {
 
             if r.map(|DecSignSet(ss, d)| ss == SignSet::ZERO && d >= Decoration::Def)
                 .eval(&self.forms[..])
-                || (&(&neg_mask & &pos_mask) & &dac_mask)
-                    .solution_certainly_exists(&self.forms[..], &locally_zero_mask)
+                || (&(&neg_mask & &pos_mask) & &dac_mask).solution_certainly_exists(
+                    &self.forms[..],
+                    &locally_zero_mask,
+                    &[],
+                )
             {
                 // Found a solution.
-                *self.im.get_mut(pixel) = PixelState::True;
-                return true;
+                return BlockResult::True;
             }
         }
 
-        false
-    }
+        // c. Use the interval Newton method, which can prove existence of a solution
+        //    where the sample points do not tell it, such as a curve that only passes through
+        //    a small part of the pixel or an intersection point of two curves "f = 0 && g = 0".
+        //    The values of the terms over the subpixel, which contains `inter`, are reused.
+        if let Some(inter) = newton_region {
+            let (zero_mask, common_zero_forms) = w.rel.prove_solutions(inter.0, inter.1, b.n_theta);
+            if (&(&(&neg_mask & &pos_mask) | &zero_mask) & &dac_mask).solution_certainly_exists(
+                &self.forms[..],
+                &locally_zero_mask,
+                &common_zero_forms,
+            ) {
+                return BlockResult::True;
+            }
+        }
 
-    fn eval_on_point(
-        rel: &mut Relation,
-        x: f64,
-        y: f64,
-        n_theta: Interval,
-        cache: Option<&mut EvalCache>,
-    ) -> EvalResult {
-        rel.eval(
-            Self::point_interval(x),
-            Self::point_interval(y),
-            n_theta,
-            cache,
-        )
+        BlockResult::Unknown
     }
 
+    /// Evaluates the relation over the region, and then tightens the results by `self.tightening`
+    /// if the relation is neither proven to be true nor false.
+    ///
+    /// `cache` must be of [`EvalCacheLevel::PerAxis`] so that the tightening can reuse
+    /// the values of the terms computed by the evaluation.
     fn eval_on_region(
+        &self,
         rel: &mut Relation,
         r: &Region,
         n_theta: Interval,
         cache: Option<&mut EvalCache>,
     ) -> EvalResult {
-        rel.eval(r.0, r.1, n_theta, cache)
+        let result = rel.eval(r.0, r.1, n_theta, cache);
+        if self.tightening == Tightening::None
+            || result
+                .map(|DecSignSet(ss, d)| ss == SignSet::ZERO && d >= Decoration::Def)
+                .eval(&self.forms[..])
+            || !result
+                .map(|DecSignSet(ss, _)| ss.contains(SignSet::ZERO))
+                .eval(&self.forms[..])
+        {
+            return result;
+        }
+
+        match self.tightening {
+            Tightening::None => unreachable!(),
+            Tightening::Derivatives => rel.eval_tightened(r.0, r.1, n_theta, result),
+            Tightening::AffineArithmetic => rel.eval_affine(r.0, r.1, n_theta, result),
+            Tightening::TaylorModel => rel.eval_taylor(r.0, r.1, n_theta, result),
+        }
     }
 
     /// Returns the region that corresponds to a subpixel block `b`.
@@ -716,6 +1419,34 @@ impl Graph {
         )
     }
 
+    /// Returns the region that corresponds to a subpixel block `b` in multiple precision.
+    fn block_to_mp_region(&self, mp: &MpTransform, b: Block) -> MpInexactRegion {
+        let pw = b.widthf();
+        let ph = b.heightf();
+        let px = b.x as f64 * pw;
+        let py = b.y as f64 * ph;
+        MpInexactRegion {
+            l: mp.x(px),
+            r: mp.x(px + pw),
+            b: mp.y(py),
+            t: mp.y(py + ph),
+        }
+    }
+
+    /// Returns the region that corresponds to a pixel or superpixel block `b` in multiple precision.
+    fn block_to_mp_region_clipped(&self, mp: &MpTransform, b: Block) -> MpInexactRegion {
+        let pw = b.widthf();
+        let ph = b.heightf();
+        let px = b.x as f64 * pw;
+        let py = b.y as f64 * ph;
+        MpInexactRegion {
+            l: mp.x(px),
+            r: mp.x((px + pw).min(self.im.width() as f64)),
+            b: mp.y(py),
+            t: mp.y((py + ph).min(self.im.height() as f64)),
+        }
+    }
+
     fn point_interval(x: f64) -> Interval {
         interval!(x, x).unwrap()
     }
@@ -895,4 +1626,69 @@ mod tests {
             Region(const_interval!(0.33, 0.67), const_interval!(1.33, 1.67))
         );
     }
+
+    /// Plots the relation over [-10, 10] × [-10, 10] to a 64 × 64 image with the graph
+    /// configured by `configure`, and returns the pixel states.
+    fn plot<F: FnOnce(&mut Graph)>(rel: &str, configure: F) -> Vec<PixelState> {
+        let mut g = Graph::new(
+            rel.parse::<Relation>().unwrap(),
+            InexactRegion::new(
+                const_interval!(-10.0, -10.0),
+                const_interval!(10.0, 10.0),
+                const_interval!(-10.0, -10.0),
+                const_interval!(10.0, 10.0),
+            ),
+            64,
+            64,
+            1 << 28,
+        );
+        configure(&mut g);
+        while !g.refine(Duration::from_secs(10)).unwrap() {}
+        g.im.iter().copied().collect()
+    }
+
+    #[test]
+    fn refine_in_parallel() {
+        for rel in &["y = sin(x)", "x^2 + y^2 < 50", "r = 1 + 2 sin(3 theta)"] {
+            assert_eq!(plot(rel, |g| g.set_threads(4)), plot(rel, |_| {}));
+        }
+    }
+
+    #[test]
+    fn refine_with_contractor() {
+        for rel in &[
+            "y = sin(x)",
+            "x^2 + y^2 = 50 && y >= x",
+            "y = floor(x) || x^2 + y^2 < 50",
+            "y = sqrt(x) || y = 1 / x",
+        ] {
+            let expected = plot(rel, |_| {});
+            assert_eq!(plot(rel, |g| g.set_use_contractor(true)), expected);
+            assert_eq!(
+                plot(rel, |g| {
+                    g.set_use_contractor(true);
+                    g.set_threads(4);
+                }),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn refine_with_tightening() {
+        for rel in &[
+            "y = sin(x)",
+            "x^4 - 10x^2 y + y^3 - x y = 1",
+            "y = floor(x) || x^2 + y^2 < 50",
+        ] {
+            let expected = plot(rel, |_| {});
+            for &tightening in &[
+                Tightening::Derivatives,
+                Tightening::AffineArithmetic,
+                Tightening::TaylorModel,
+            ] {
+                assert_eq!(plot(rel, |g| g.set_tightening(tightening)), expected);
+            }
+        }
+    }
 }
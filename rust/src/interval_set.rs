@@ -193,6 +193,17 @@ impl TupperIntervalSet {
         }
     }
 
+    /// Replaces the intervals in the set with the given one.
+    ///
+    /// This is equivalent to `*self = x.into()`, but reuses the storage of the set.
+    pub fn set_single(&mut self, x: TupperInterval) {
+        self.xs.clear();
+        if !x.x.is_empty() {
+            self.xs.push(x);
+        }
+        self.d = x.d;
+    }
+
     /// Returns `true` if the set is empty.
     pub fn is_empty(&self) -> bool {
         self.xs.is_empty()
@@ -208,6 +219,16 @@ impl TupperIntervalSet {
         self.xs.len()
     }
 
+    /// Returns the only interval in the set if the set contains exactly one interval;
+    /// otherwise, [`None`].
+    pub fn single(&self) -> Option<TupperInterval> {
+        if self.len() == 1 {
+            Some(self.xs[0])
+        } else {
+            None
+        }
+    }
+
     /// Sorts intervals in a consistent order and merges overlapping intervals
     /// with the same branch map.
     ///
@@ -369,6 +390,14 @@ bitflags! {
     }
 }
 
+impl SignSet {
+    /// Returns `true` if the set contains zero and a nonzero sign, i.e., it is not decided
+    /// whether an atomic formula with the set of signs holds.
+    pub fn is_undecided(self) -> bool {
+        self.contains(SignSet::ZERO) && self != SignSet::ZERO
+    }
+}
+
 /// A pair of [`SignSet`] and [`Decoration`].
 ///
 /// It is used as a compact version of [`DecInterval`] when only the sign of the interval
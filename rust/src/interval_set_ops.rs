@@ -1,5 +1,10 @@
-use crate::interval_set::{
-    Branch, BranchMap, DecSignSet, SignSet, Site, TupperInterval, TupperIntervalSet,
+#[cfg(not(feature = "arb"))]
+use crate::special_functions::{airy, bessel_j, bessel_y, ci, si};
+use crate::{
+    interval_set::{
+        Branch, BranchMap, DecSignSet, SignSet, Site, TupperInterval, TupperIntervalSet,
+    },
+    number_theory,
 };
 use gmp_mpfr_sys::mpfr;
 use inari::{const_dec_interval, const_interval, interval, DecInterval, Decoration, Interval};
@@ -8,7 +13,7 @@ use rug::Float;
 use smallvec::{smallvec, SmallVec};
 use std::{
     convert::From,
-    ops::{Add, Mul, Neg, Sub},
+    ops::{Add, Mul, Neg, RangeInclusive, Sub},
 };
 
 impl Neg for &TupperIntervalSet {
@@ -170,12 +175,54 @@ impl TupperIntervalSet {
     #[cfg(not(feature = "arb"))]
     impl_op!(acosh(x), x.acosh());
 
+    #[cfg(not(feature = "arb"))]
+    impl_op!(airy_ai(x), {
+        DecInterval::set_dec(airy(x.interval().unwrap())[0], x.decoration())
+    });
+
+    #[cfg(not(feature = "arb"))]
+    impl_op!(airy_ai_prime(x), {
+        DecInterval::set_dec(airy(x.interval().unwrap())[1], x.decoration())
+    });
+
+    #[cfg(not(feature = "arb"))]
+    impl_op!(airy_bi(x), {
+        DecInterval::set_dec(airy(x.interval().unwrap())[2], x.decoration())
+    });
+
+    #[cfg(not(feature = "arb"))]
+    impl_op!(airy_bi_prime(x), {
+        DecInterval::set_dec(airy(x.interval().unwrap())[3], x.decoration())
+    });
+
     #[cfg(not(feature = "arb"))]
     impl_op!(asin(x), x.asin());
 
     #[cfg(not(feature = "arb"))]
     impl_op!(asinh(x), x.asinh());
 
+    pub fn assoc_legendre_p(&self, m: &Self, x: &Self) -> Self {
+        let mut rs = Self::new();
+        for n in self {
+            for m in m {
+                if let Some(g) = n.g.union(m.g) {
+                    for x in x {
+                        if let Some(g) = g.union(x.g) {
+                            let y = assoc_legendre_p(
+                                n.dec_interval(),
+                                m.dec_interval(),
+                                x.dec_interval(),
+                            );
+                            rs.insert(TupperInterval::new(y, g));
+                        }
+                    }
+                }
+            }
+        }
+        rs.normalize(false);
+        rs
+    }
+
     #[cfg(not(feature = "arb"))]
     impl_op!(atan(x), x.atan());
 
@@ -233,37 +280,154 @@ impl TupperIntervalSet {
     impl_op!(atanh(x), x.atanh());
 
     #[cfg(not(feature = "arb"))]
-    impl_op!(cos(x), x.cos());
+    impl_op!(bessel_j(n, x), {
+        let dec = n.decoration().min(x.decoration());
+        let n = match integer_singleton(n.interval().unwrap()) {
+            Some(n) => n,
+            _ => panic!(
+                "`J(n, x)` only permits integers for `n` unless the feature `arb` is enabled"
+            ),
+        };
+        DecInterval::set_dec(bessel_j(n, x.interval().unwrap()), dec)
+    });
 
     #[cfg(not(feature = "arb"))]
-    impl_op!(cosh(x), x.cosh());
+    impl_op!(bessel_y(n, x), {
+        let dec = n.decoration().min(x.decoration());
+        let n = match integer_singleton(n.interval().unwrap()) {
+            Some(n) => n,
+            _ => panic!(
+                "`Y(n, x)` only permits integers for `n` unless the feature `arb` is enabled"
+            ),
+        };
+        let x = x.interval().unwrap();
+        let dec = if x.inf() > 0.0 { dec } else { Decoration::Trv };
+        DecInterval::set_dec(bessel_y(n, x), dec)
+    });
 
-    impl_op_cut!(digamma(x), {
-        let a = x.inf();
-        let b = x.sup();
-        let ia = a.ceil();
-        let ib = b.floor();
-        if ia == ib && a <= 0.0 {
-            // ∃i ∈ S : x ∩ S = {i}, where S = {0, -1, …}.
-            let dec = Decoration::Trv;
-            let x0 = interval!(a, ia).unwrap();
-            let x1 = interval!(ia, b).unwrap();
-            (
-                DecInterval::set_dec(digamma(x0), dec),
-                Some(DecInterval::set_dec(digamma(x1), dec)),
-            )
-        } else {
-            let dec = if ia < ib && a <= 0.0 {
-                // x ∩ S ≠ ∅.
-                Decoration::Trv
-            } else {
-                Decoration::Com.min(x.decoration())
-            };
-            let x = x.interval().unwrap();
-            (DecInterval::set_dec(digamma(x), dec), None)
+    // B(x, y) := Γ(x) Γ(y) / Γ(x + y).
+    pub fn beta(&self, rhs: &Self, site: Option<Site>) -> Self {
+        let mut rs = Self::new();
+        for x in self {
+            for y in rhs {
+                if let Some(g) = x.g.union(y.g) {
+                    let xs = &Self::from(TupperInterval::new(x.dec_interval(), g));
+                    let ys = &Self::from(TupperInterval::new(y.dec_interval(), g));
+                    let num = &xs.gamma(site) * &ys.gamma(None);
+                    rs.extend(num.div(&(xs + ys).gamma(None), None));
+                }
+            }
+        }
+        rs.normalize(false);
+        rs
+    }
+
+    // The binomial coefficient is defined as:
+    //
+    //   binom(n, k) := Γ(n + 1) / (Γ(k + 1) Γ(n - k + 1)),
+    //
+    // which is extended by continuity in n when k ∈ ℤ:
+    //
+    //   binom(n, k) = | n (n - 1) … (n - k + 1) / k!  if k ≥ 0,
+    //                 | 0                             otherwise.
+    pub fn binom(&self, rhs: &Self, site: Option<Site>) -> Self {
+        const ONE: Interval = const_interval!(1.0, 1.0);
+        let mut rs = Self::new();
+        for n in self {
+            for k in rhs {
+                if let Some(g) = n.g.union(k.g) {
+                    let dec = Decoration::Dac.min(n.d).min(k.d);
+                    match integer_singleton(k.x) {
+                        Some(k) if k < 0.0 => {
+                            let y = const_interval!(0.0, 0.0);
+                            rs.insert(TupperInterval::new(DecInterval::set_dec(y, dec), g));
+                        }
+                        Some(k) if k <= MAX_PRODUCT_LEN as f64 => {
+                            let k1 = interval!(k - 1.0, k - 1.0).unwrap();
+                            let len = k as u32;
+                            let y = rising_factorial(n.x - k1, len) / rising_factorial(ONE, len);
+                            rs.insert(TupperInterval::new(DecInterval::set_dec(y, dec), g));
+                        }
+                        _ if !k.x.is_singleton()
+                            && integer_singleton(n.x).filter(|&n| n < 0.0).is_some() =>
+                        {
+                            // Γ(n + 1) has a pole, but binom(n, k) is defined for k ∈ ℤ.
+                            let y = DecInterval::set_dec(Interval::ENTIRE, Decoration::Trv);
+                            rs.insert(TupperInterval::new(y, g));
+                        }
+                        _ => {
+                            let one = &Self::from(const_dec_interval!(1.0, 1.0));
+                            let ns = &Self::from(TupperInterval::new(n.dec_interval(), g));
+                            let ks = &Self::from(TupperInterval::new(k.dec_interval(), g));
+                            let num = (ns + one).gamma(site);
+                            let den = &(ks + one).gamma(None) * &(&(ns - ks) + one).gamma(None);
+                            rs.extend(num.div(&den, None));
+                        }
+                    }
+                }
+            }
+        }
+        rs.normalize(false);
+        rs
+    }
+
+    impl_op_cut!(bit_and(x, y), bitwise(BitwiseOp::And, x, y));
+
+    impl_op_cut!(bit_or(x, y), bitwise(BitwiseOp::Or, x, y));
+
+    impl_op_cut!(bit_xor(x, y), bitwise(BitwiseOp::Xor, x, y));
+
+    impl_op!(cbrt(x), {
+        DecInterval::set_dec(cbrt(x.interval().unwrap()), x.decoration())
+    });
+
+    impl_op!(chebyshev_t(n, x), {
+        orthogonal_polynomial(OrthogonalPolynomial::ChebyshevT, n, x)
+    });
+
+    impl_op!(chebyshev_u(n, x), {
+        orthogonal_polynomial(OrthogonalPolynomial::ChebyshevU, n, x)
+    });
+
+    // f(x, a, b) = min(max(x, a), b).
+    pub fn clamp(&self, a: &Self, b: &Self) -> Self {
+        let mut rs = Self::new();
+        for x in self {
+            for a in a {
+                if let Some(g) = x.g.union(a.g) {
+                    for b in b {
+                        if let Some(g) = g.union(b.g) {
+                            rs.insert(TupperInterval::new(
+                                x.dec_interval().max(a.dec_interval()).min(b.dec_interval()),
+                                g,
+                            ));
+                        }
+                    }
+                }
+            }
         }
+        rs.normalize(false);
+        rs
+    }
+
+    #[cfg(not(feature = "arb"))]
+    impl_op!(ci(x), {
+        let dec = if x.inf() > 0.0 {
+            x.decoration()
+        } else {
+            Decoration::Trv
+        };
+        DecInterval::set_dec(ci(x.interval().unwrap()), dec)
     });
 
+    #[cfg(not(feature = "arb"))]
+    impl_op!(cos(x), x.cos());
+
+    #[cfg(not(feature = "arb"))]
+    impl_op!(cosh(x), x.cosh());
+
+    impl_op_cut!(digamma(x), eval_gamma_like(x, Decoration::Com, digamma));
+
     impl_op_cut!(div(x, y), {
         let c = y.inf();
         let d = y.sup();
@@ -276,6 +440,24 @@ impl TupperIntervalSet {
         }
     });
 
+    // σ_k(n) = ∑_{d ∣ n} d^k.
+    impl_op_cut!(divisor_sigma(k, n), {
+        let dec = k.decoration();
+        let k = k.interval().unwrap();
+        number_theoretic(
+            n,
+            1.0,
+            dec,
+            |n| divisor_sigma(k, n as u64),
+            |_, b| {
+                // σ_k(n) ≤ d(n) max(1, n^k).
+                let d = interval!(1.0, num_divisors_bound(b)).unwrap();
+                let p = interval!(b, b).map_or(f64::INFINITY, |b| b.pow(k).sup());
+                d * interval!(1.0, p.max(1.0)).unwrap()
+            },
+        )
+    });
+
     #[cfg(not(feature = "arb"))]
     impl_op!(erf(x), {
         DecInterval::set_dec(erf(x.interval().unwrap()), x.decoration())
@@ -295,6 +477,16 @@ impl TupperIntervalSet {
     #[cfg(not(feature = "arb"))]
     impl_op!(exp2(x), x.exp2());
 
+    impl_op!(expm1(x), {
+        DecInterval::set_dec(expm1(x.interval().unwrap()), x.decoration())
+    });
+
+    // n! := Γ(n + 1).
+    pub fn factorial(&self, site: Option<Site>) -> Self {
+        let one = Self::from(const_dec_interval!(1.0, 1.0));
+        (self + &one).gamma(site)
+    }
+
     pub fn gamma(&self, site: Option<Site>) -> Self {
         // argmin_{x > 0} Γ(x), rounded down/up.
         const ARGMIN_RD: f64 = 1.4616321449683622;
@@ -535,6 +727,50 @@ impl TupperIntervalSet {
         rs
     }
 
+    // f(x) = | 0    if x < 0,
+    //        | 1/2  if x = 0,
+    //        | 1    otherwise.
+    impl_op_cut!(heaviside(x), step(x, 0.0, 0.5, 1.0));
+
+    impl_op!(hermite_h(n, x), {
+        orthogonal_polynomial(OrthogonalPolynomial::HermiteH, n, x)
+    });
+
+    impl_op!(hypot(x, y), {
+        DecInterval::set_dec(
+            hypot(x.interval().unwrap(), y.interval().unwrap()),
+            x.decoration().min(y.decoration()),
+        )
+    });
+
+    impl_op_cut!(is_prime(n), {
+        number_theoretic(
+            n,
+            f64::NEG_INFINITY,
+            Decoration::Com,
+            |n| {
+                if n >= 2.0 && number_theory::is_prime(n as u64) {
+                    const_interval!(1.0, 1.0)
+                } else {
+                    const_interval!(0.0, 0.0)
+                }
+            },
+            |a, b| {
+                if b < 2.0 || a >= MAX_EXACT_INTEGER {
+                    // All integers that are greater than or equal to 2^53
+                    // and representable in f64 are even.
+                    const_interval!(0.0, 0.0)
+                } else {
+                    const_interval!(0.0, 1.0)
+                }
+            },
+        )
+    });
+
+    impl_op!(laguerre_l(n, x), {
+        orthogonal_polynomial(OrthogonalPolynomial::LaguerreL, n, x)
+    });
+
     // For x, y ∈ ℚ, the LCM (least common multiple) of x and y is defined as:
     //
     //   lcm(x, y) = | 0                  if x = y = 0,
@@ -588,9 +824,15 @@ impl TupperIntervalSet {
         rs
     }
 
+    impl_op!(legendre_p(n, x), {
+        orthogonal_polynomial(OrthogonalPolynomial::LegendreP, n, x)
+    });
+
     #[cfg(not(feature = "arb"))]
     impl_op!(ln(x), x.ln());
 
+    impl_op_cut!(ln_gamma(x), eval_gamma_like(x, Decoration::Com, ln_gamma));
+
     pub fn log(&self, rhs: &Self, site: Option<Site>) -> Self {
         self.log2().div(&rhs.log2(), site)
     }
@@ -598,6 +840,21 @@ impl TupperIntervalSet {
     #[cfg(not(feature = "arb"))]
     impl_op!(log10(x), x.log10());
 
+    impl_op!(log1p(x), {
+        const DOM: Interval = const_interval!(-1.0, f64::INFINITY);
+        let dec = if x.inf() > -1.0 {
+            x.decoration()
+        } else {
+            Decoration::Trv
+        };
+        let x = x.interval().unwrap().intersection(DOM);
+        if x.is_empty() || x.sup() == -1.0 {
+            DecInterval::EMPTY
+        } else {
+            DecInterval::set_dec(log1p(x), dec)
+        }
+    });
+
     #[cfg(not(feature = "arb"))]
     impl_op!(log2(x), x.log2());
 
@@ -605,6 +862,19 @@ impl TupperIntervalSet {
 
     impl_op!(min(x, y), x.min(y));
 
+    impl_op_cut!(mobius(n), {
+        number_theoretic(
+            n,
+            1.0,
+            Decoration::Com,
+            |n| {
+                let y = number_theory::mobius(n as u64) as f64;
+                interval!(y, y).unwrap()
+            },
+            |_, _| const_interval!(-1.0, 1.0),
+        )
+    });
+
     pub fn mul_add(&self, rhs: &Self, addend: &Self) -> Self {
         let mut rs = Self::new();
         for x in self {
@@ -625,11 +895,70 @@ impl TupperIntervalSet {
         rs
     }
 
+    impl_op_cut!(num_divisors(n), {
+        number_theoretic(
+            n,
+            1.0,
+            Decoration::Com,
+            |n| {
+                let y = number_theory::num_divisors(n as u64) as f64;
+                interval!(y, y).unwrap()
+            },
+            |_, b| interval!(1.0, num_divisors_bound(b)).unwrap(),
+        )
+    });
+
     // f(x) = 1.
     impl_op!(one(x), {
         DecInterval::set_dec(const_interval!(1.0, 1.0), x.decoration())
     });
 
+    // The Pochhammer symbol (the rising factorial) is defined as:
+    //
+    //   poch(x, n) := Γ(x + n) / Γ(x),
+    //
+    // which is extended by continuity in x when n ∈ ℤ:
+    //
+    //   poch(x, n) = | x (x + 1) … (x + n - 1)          if n ≥ 0,
+    //                | 1 / ((x - 1) (x - 2) … (x + n))  otherwise.
+    pub fn poch(&self, rhs: &Self, site: Option<Site>) -> Self {
+        let mut rs = Self::new();
+        for x in self {
+            for n in rhs {
+                if let Some(g) = x.g.union(n.g) {
+                    let dec = Decoration::Dac.min(x.d).min(n.d);
+                    match integer_singleton(n.x) {
+                        Some(n) if n >= 0.0 && n <= MAX_PRODUCT_LEN as f64 => {
+                            let y = rising_factorial(x.x, n as u32);
+                            rs.insert(TupperInterval::new(DecInterval::set_dec(y, dec), g));
+                        }
+                        Some(n) if n < 0.0 && n >= -(MAX_PRODUCT_LEN as f64) => {
+                            let n0 = interval!(n, n).unwrap();
+                            let y = rising_factorial(x.x + n0, -n as u32);
+                            let ys =
+                                Self::from(TupperInterval::new(DecInterval::set_dec(y, dec), g));
+                            rs.extend(ys.recip(site));
+                        }
+                        _ if !n.x.is_singleton()
+                            && integer_singleton(x.x).filter(|&x| x <= 0.0).is_some() =>
+                        {
+                            // Γ(x) has a pole, but poch(x, n) is defined for n ∈ ℤ.
+                            let y = DecInterval::set_dec(Interval::ENTIRE, Decoration::Trv);
+                            rs.insert(TupperInterval::new(y, g));
+                        }
+                        _ => {
+                            let xs = &Self::from(TupperInterval::new(x.dec_interval(), g));
+                            let ns = &Self::from(TupperInterval::new(n.dec_interval(), g));
+                            rs.extend((xs + ns).gamma(site).div(&xs.gamma(None), None));
+                        }
+                    }
+                }
+            }
+        }
+        rs.normalize(false);
+        rs
+    }
+
     /// Returns the parity of the function f(x) = x^y.
     ///
     /// Precondition: y is neither ±∞ nor NaN.
@@ -783,6 +1112,33 @@ impl TupperIntervalSet {
         }
     );
 
+    impl_op_cut!(polygamma(n, x), {
+        let dec = if n.is_singleton() {
+            n.decoration()
+        } else {
+            Decoration::Trv
+        };
+        let n = n.interval().unwrap();
+        eval_gamma_like(x, dec, |x| polygamma(n, x))
+    });
+
+    impl_op_cut!(popcount(n), {
+        number_theoretic(
+            n,
+            f64::NEG_INFINITY,
+            Decoration::Com,
+            |n| {
+                let y = (n.abs() as u64).count_ones() as f64;
+                interval!(y, y).unwrap()
+            },
+            |a, b| {
+                // popcount(n) ≤ the bit length of |n|.
+                let lo = if a > 0.0 || b < 0.0 { 1.0 } else { 0.0 };
+                interval!(lo, bit_length(a.abs().max(b.abs()))).unwrap()
+            },
+        )
+    });
+
     impl_op_cut!(pown(x, n: i32), {
         let a = x.inf();
         let b = x.sup();
@@ -795,6 +1151,8 @@ impl TupperIntervalSet {
         }
     });
 
+    impl_op_cut!(prime_pi(x), prime_pi(x));
+
     pub fn ranked_max(xs: Vec<&Self>, n: &Self, site: Option<Site>) -> Self {
         Self::ranked_min_max(xs, n, site, true)
     }
@@ -859,6 +1217,11 @@ impl TupperIntervalSet {
         }
     });
 
+    // f(x) = H(1/2 - |x|), where H is the Heaviside step function.
+    impl_op_cut!(rect(x), {
+        step(const_dec_interval!(0.5, 0.5) - x.abs(), 0.0, 0.5, 1.0)
+    });
+
     impl_op_cut!(rem_euclid(x, y), {
         // Compute x - |y| ⌊x / |y|⌋.
         let y = y.abs(); // Take abs, normalize, then iterate over could be better.
@@ -899,6 +1262,26 @@ impl TupperIntervalSet {
         }
     });
 
+    // f(x) = | 2(x - ⌊x⌋) - 1  if x ∉ ℤ,
+    //        | 0               otherwise.
+    impl_op_cut!(sawtooth_wave(x), {
+        periodic_with_jumps(x, M_ONE_TO_ONE, |n, x| {
+            let n = interval!(n, n).unwrap();
+            (const_interval!(2.0, 2.0) * (x - n) - const_interval!(1.0, 1.0))
+                .intersection(M_ONE_TO_ONE)
+        })
+    });
+
+    // f(x) = | -1  if x < 0,
+    //        | 0   if x = 0,
+    //        | 1   otherwise.
+    impl_op_cut!(sign(x), step(x, -1.0, 0.0, 1.0));
+
+    #[cfg(not(feature = "arb"))]
+    impl_op!(si(x), {
+        DecInterval::set_dec(si(x.interval().unwrap()), x.decoration())
+    });
+
     #[cfg(not(feature = "arb"))]
     impl_op!(sin(x), x.sin());
 
@@ -912,10 +1295,30 @@ impl TupperIntervalSet {
     #[cfg(not(feature = "arb"))]
     impl_op!(sinh(x), x.sinh());
 
+    // f(x) = | sinh(x)/x  if x ≠ 0,
+    //        | 1          otherwise.
+    impl_op!(sinhc(x), {
+        DecInterval::set_dec(sinhc(x.interval().unwrap()), x.decoration())
+    });
+
     impl_op!(sqr(x), x.sqr());
 
     impl_op!(sqrt(x), x.sqrt());
 
+    // f(x) = | 1   if 2x ∉ ℤ and ⌊2x⌋ is even,
+    //        | -1  if 2x ∉ ℤ and ⌊2x⌋ is odd,
+    //        | 0   otherwise.
+    impl_op_cut!(square_wave(x), {
+        let t = const_dec_interval!(2.0, 2.0) * x;
+        periodic_with_jumps(t, M_ONE_TO_ONE, |n, _| {
+            if n % 2.0 == 0.0 {
+                const_interval!(1.0, 1.0)
+            } else {
+                const_interval!(-1.0, -1.0)
+            }
+        })
+    });
+
     #[cfg(not(feature = "arb"))]
     pub fn tan(&self, site: Option<Site>) -> Self {
         self.tan_impl(site)
@@ -952,6 +1355,37 @@ impl TupperIntervalSet {
     #[cfg(not(feature = "arb"))]
     impl_op!(tanh(x), x.tanh());
 
+    impl_op_cut!(totient(n), {
+        number_theoretic(
+            n,
+            1.0,
+            Decoration::Com,
+            |n| {
+                let y = number_theory::totient(n as u64) as f64;
+                interval!(y, y).unwrap()
+            },
+            |a, b| {
+                // √(n/2) ≤ φ(n) ≤ max(1, n - 1).
+                let a = (interval!(a, a).unwrap() / const_interval!(2.0, 2.0))
+                    .sqrt()
+                    .inf()
+                    .ceil()
+                    .max(1.0);
+                let b = if b <= MAX_EXACT_INTEGER {
+                    (b - 1.0).max(1.0)
+                } else {
+                    b
+                };
+                interval!(a, b).unwrap()
+            },
+        )
+    });
+
+    // f(x) = max(1 - |x|, 0).
+    impl_op!(tri(x), {
+        (const_dec_interval!(1.0, 1.0) - x.abs()).max(const_dec_interval!(0.0, 0.0))
+    });
+
     // f(x) = | x          if x ≠ 0,
     //        | undefined  otherwise.
     impl_op!(undef_at_0(x), {
@@ -1008,26 +1442,41 @@ macro_rules! requires_arb {
 }
 
 impl TupperIntervalSet {
-    requires_arb!(airy_ai(x));
-    requires_arb!(airy_ai_prime(x));
-    requires_arb!(airy_bi(x));
-    requires_arb!(airy_bi_prime(x));
     requires_arb!(bessel_i(n, x));
-    requires_arb!(bessel_j(n, x));
     requires_arb!(bessel_k(n, x));
-    requires_arb!(bessel_y(n, x));
+    requires_arb!(beta_regularized(x, a, b));
     requires_arb!(chi(x));
-    requires_arb!(ci(x));
     requires_arb!(ei(x));
     requires_arb!(elliptic_e(x));
+    requires_arb!(elliptic_e_inc(phi, m));
+    requires_arb!(elliptic_f(phi, m));
     requires_arb!(elliptic_k(x));
+    requires_arb!(elliptic_pi(n, phi, m));
+    requires_arb!(erfcinv(x));
     requires_arb!(erfi(x));
+    requires_arb!(erfinv(x));
     requires_arb!(fresnel_c(x));
     requires_arb!(fresnel_s(x));
+    requires_arb!(exp_integral_e(n, x));
     requires_arb!(gamma_inc(a, x));
+    requires_arb!(gamma_inc_lower(a, x));
+    requires_arb!(gamma_regularized(a, x));
+    requires_arb!(gamma_regularized_lower(a, x));
+    requires_arb!(hyp0f1(b, x));
+    requires_arb!(hyp1f1(a, b, x));
+    requires_arb!(hyp2f1(a, b, c, x));
+    requires_arb!(jacobi_am(u, m));
+    requires_arb!(jacobi_cn(u, m));
+    requires_arb!(jacobi_dn(u, m));
+    requires_arb!(jacobi_sn(u, m));
     requires_arb!(li(x));
     requires_arb!(shi(x));
-    requires_arb!(si(x));
+    requires_arb!(spherical_bessel_i(n, x));
+    requires_arb!(spherical_bessel_j(n, x));
+    requires_arb!(spherical_bessel_k(n, x));
+    requires_arb!(spherical_bessel_y(n, x));
+    requires_arb!(struve_h(nu, x));
+    requires_arb!(struve_l(nu, x));
 }
 
 impl TupperIntervalSet {
@@ -1141,79 +1590,1063 @@ macro_rules! mpfr_fn_ui {
     }};
 }
 
+macro_rules! mpfr_fn2 {
+    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident) => {
+        fn $f_rd(x: f64, y: f64) -> f64 {
+            mpfr_fn2!($mpfr_f(x, y, RNDD))
+        }
+
+        fn $f_ru(x: f64, y: f64) -> f64 {
+            mpfr_fn2!($mpfr_f(x, y, RNDU))
+        }
+    };
+
+    ($mpfr_f:ident($x:ident, $y:ident, $rnd:ident)) => {{
+        let mut x = Float::with_val(f64::MANTISSA_DIGITS, $x);
+        let y = Float::with_val(f64::MANTISSA_DIGITS, $y);
+        let rnd = mpfr::rnd_t::$rnd;
+        unsafe {
+            mpfr::$mpfr_f(x.as_raw_mut(), x.as_raw(), y.as_raw(), rnd);
+            mpfr::get_d(x.as_raw(), rnd)
+        }
+    }};
+}
+
+mpfr_fn!(cbrt, cbrt_rd, cbrt_ru);
 mpfr_fn!(digamma, digamma_rd, digamma_ru);
 mpfr_fn!(erf, erf_rd, erf_ru);
 mpfr_fn!(erfc, erfc_rd, erfc_ru);
+mpfr_fn!(expm1, expm1_rd, expm1_ru);
 mpfr_fn!(gamma, gamma_rd, gamma_ru);
+mpfr_fn2!(hypot, hypot_rd, hypot_ru);
+mpfr_fn!(log1p, log1p_rd, log1p_ru);
 mpfr_fn_ui!(rootn_ui, rootn_rd, rootn_ru);
 mpfr_fn!(tan, tan_rd, tan_ru);
 
-/// `x` must be nonempty.
-pub(crate) fn digamma(x: Interval) -> Interval {
+fn lgamma_rd(x: f64) -> f64 {
+    lgamma(x, mpfr::rnd_t::RNDD)
+}
+
+fn lgamma_ru(x: f64) -> f64 {
+    lgamma(x, mpfr::rnd_t::RNDU)
+}
+
+/// Returns ln |Γ(x)| rounded in the direction `rnd`.
+fn lgamma(x: f64, rnd: mpfr::rnd_t) -> f64 {
+    let mut x = Float::with_val(f64::MANTISSA_DIGITS, x);
+    let mut sign = 0;
+    unsafe {
+        mpfr::lgamma(x.as_raw_mut(), &mut sign, x.as_raw(), rnd);
+        mpfr::get_d(x.as_raw(), rnd)
+    }
+}
+
+/// The maximum number of factors for which [`rising_factorial`] is used
+/// instead of the ratio of gamma functions.
+const MAX_PRODUCT_LEN: u32 = 64;
+
+/// Returns `Some(n)` if `x = {n}` and n ∈ ℤ; otherwise, [`None`].
+fn integer_singleton(x: Interval) -> Option<f64> {
     let a = x.inf();
-    let b = x.sup();
-    let ia = a.ceil();
-    let ib = b.floor();
-    if x.is_singleton() && a == ia && a <= 0.0 {
-        // ∃i ∈ S : x = {i}, where S = {0, -1, …}.
-        Interval::EMPTY
-    } else if a < 0.0 && (a < ia && ia <= ib && ib < b || x.wid() >= 1.0) {
-        // (∃i ∈ S : a < i < b) ∨ (a < 0 ∧ b - a ≥ 1).
-        Interval::ENTIRE
+    if x.is_singleton() && a == a.trunc() {
+        Some(a)
     } else {
-        let inf = if a == ia && a <= 0.0 {
-            f64::NEG_INFINITY
-        } else {
-            digamma_rd(a)
-        };
-        let sup = if b == ib && b <= 0.0 {
-            f64::INFINITY
-        } else {
-            digamma_ru(b)
-        };
-        interval!(inf, sup).unwrap()
+        None
     }
 }
 
-/// `x` must be nonempty.
-pub(crate) fn erf(x: Interval) -> Interval {
-    interval!(erf_rd(x.inf()), erf_ru(x.sup())).unwrap()
+/// Returns an enclosure of x (x + 1) … (x + n - 1).
+fn rising_factorial(x: Interval, n: u32) -> Interval {
+    (0..n).fold(const_interval!(1.0, 1.0), |y, i| {
+        let i = interval!(i as f64, i as f64).unwrap();
+        y * (x + i)
+    })
 }
 
-/// `x` must be nonempty.
-pub(crate) fn erfc(x: Interval) -> Interval {
-    interval!(erfc_rd(x.sup()), erfc_ru(x.inf())).unwrap()
+/// The maximum degree of orthogonal polynomials for which enclosures are computed
+/// by [`eval_recurrence`].
+const MAX_POLYNOMIAL_DEGREE: i32 = 1024;
+
+/// Returns the integers in `x` if there are at most [`MAX_PRODUCT_LEN`] of them
+/// and their absolute values do not exceed [`MAX_POLYNOMIAL_DEGREE`]; otherwise, [`None`].
+fn polynomial_degrees(x: Interval) -> Option<RangeInclusive<i32>> {
+    let a = x.inf().ceil();
+    let b = x.sup().floor();
+    if a > b {
+        Some(RangeInclusive::new(1, 0))
+    } else if b - a < MAX_PRODUCT_LEN as f64
+        && a >= -MAX_POLYNOMIAL_DEGREE as f64
+        && b <= MAX_POLYNOMIAL_DEGREE as f64
+    {
+        Some(a as i32..=b as i32)
+    } else {
+        None
+    }
 }
 
-/// `x` must be nonempty.
-pub(crate) fn rootn(x: Interval, n: u32) -> Interval {
-    if n == 0 {
-        Interval::EMPTY
-    } else if n % 2 == 0 {
-        const DOM: Interval = const_interval!(0.0, f64::INFINITY);
-        let x = x.intersection(DOM);
-        if x.is_empty() {
-            return Interval::EMPTY;
-        }
-        let a = x.inf();
-        let b = x.sup();
-        interval!(rootn_rd(a, n), rootn_ru(b, n)).unwrap()
-    } else {
-        let a = x.inf();
-        let b = x.sup();
-        interval!(rootn_rd(a, n), rootn_ru(b, n)).unwrap()
+/// Returns enclosures of p_n(x) and p_n'(x), where the sequence of polynomials is defined by:
+///
+///   p_{-1}(x) = 0,
+///   p_0(x) = 1,
+///   c_k p_{k+1}(x) = (a_k x + b_k) p_k(x) - d_k p_{k-1}(x),
+///
+/// and `coeffs(k)` returns (a_k, b_k, c_k, d_k).
+fn eval_recurrence<F>(x: Interval, n: u32, coeffs: &F) -> (Interval, Interval)
+where
+    F: Fn(u32) -> (f64, f64, f64, f64),
+{
+    const ZERO: Interval = const_interval!(0.0, 0.0);
+    const ONE: Interval = const_interval!(1.0, 1.0);
+    let (mut p0, mut p1) = (ZERO, ONE);
+    let (mut dp0, mut dp1) = (ZERO, ZERO);
+    for k in 0..n {
+        let (a, b, c, d) = coeffs(k);
+        let a = interval!(a, a).unwrap();
+        let b = interval!(b, b).unwrap();
+        let c = interval!(c, c).unwrap();
+        let d = interval!(d, d).unwrap();
+        let t = a.mul_add(x, b);
+        let p2 = (t * p1 - d * p0) / c;
+        let dp2 = (a * p1 + t * dp1 - d * dp0) / c;
+        p0 = p1;
+        p1 = p2;
+        dp0 = dp1;
+        dp1 = dp2;
     }
+    (p1, dp1)
 }
 
-/// `x` must be nonempty.
-pub(crate) fn sinc(x: Interval) -> Interval {
-    // argmin_{x > 0} sinc(x), rounded down.
-    const ARGMIN_RD: f64 = 4.493409457909063;
-    // min_{x > 0} sinc(x), rounded down.
-    const MIN_RD: f64 = -0.21723362821122166;
+/// Returns an enclosure of the range of p_n over `x`, where p_n is defined as
+/// in [`eval_recurrence`].
+///
+/// The naïve evaluation of the recurrence suffers from the dependency problem,
+/// so the result is tightened by the monotonicity test and the mean value form.
+fn polynomial_range<F>(x: Interval, n: u32, coeffs: F) -> Interval
+where
+    F: Fn(u32) -> (f64, f64, f64, f64),
+{
+    let (y, dy) = eval_recurrence(x, n, &coeffs);
+    if x.is_singleton() || !x.is_common_interval() {
+        return y;
+    }
+
     let a = x.inf();
     let b = x.sup();
-    if a <= 0.0 && b >= 0.0 {
+    if dy.inf() >= 0.0 || dy.sup() <= 0.0 {
+        // p_n is monotonic on `x`.
+        let ya = eval_recurrence(interval!(a, a).unwrap(), n, &coeffs).0;
+        let yb = eval_recurrence(interval!(b, b).unwrap(), n, &coeffs).0;
+        y.intersection(ya.convex_hull(yb))
+    } else {
+        let c = interval!(x.mid(), x.mid()).unwrap();
+        let yc = eval_recurrence(c, n, &coeffs).0;
+        y.intersection(dy.mul_add(x - c, yc))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum OrthogonalPolynomial {
+    ChebyshevT,
+    ChebyshevU,
+    HermiteH,
+    LaguerreL,
+    LegendreP,
+}
+
+impl OrthogonalPolynomial {
+    /// Returns the coefficients of the recurrence in the form of [`eval_recurrence`].
+    fn coefficients(self, k: u32) -> (f64, f64, f64, f64) {
+        use OrthogonalPolynomial::*;
+        let k = k as f64;
+        match self {
+            ChebyshevT if k == 0.0 => (1.0, 0.0, 1.0, 0.0),
+            ChebyshevT | ChebyshevU => (2.0, 0.0, 1.0, 1.0),
+            HermiteH => (2.0, 0.0, 1.0, 2.0 * k),
+            LaguerreL => (-1.0, 2.0 * k + 1.0, k + 1.0, k),
+            LegendreP => (2.0 * k + 1.0, 0.0, k + 1.0, k),
+        }
+    }
+
+    /// Returns an enclosure of the range of the polynomial of degree `n` over `x`,
+    /// or [`None`] if the polynomial is not defined.
+    ///
+    /// The polynomials of negative degrees are defined by:
+    ///
+    ///   P_{-n}(x) = P_{n-1}(x),
+    ///   T_{-n}(x) = T_n(x),
+    ///   U_{-n}(x) = -U_{n-2}(x),
+    ///
+    /// where U_{-1}(x) = 0.
+    fn range(self, n: i32, x: Interval) -> Option<Interval> {
+        use OrthogonalPolynomial::*;
+        const ZERO: Interval = const_interval!(0.0, 0.0);
+        const UNIT: Interval = const_interval!(-1.0, 1.0);
+        let (n, negate) = match self {
+            ChebyshevT => (n.abs(), false),
+            ChebyshevU if n == -1 => return Some(ZERO),
+            ChebyshevU if n < -1 => (-n - 2, true),
+            LegendreP if n < 0 => (-n - 1, false),
+            HermiteH | LaguerreL if n < 0 => return None,
+            _ => (n, false),
+        };
+        let mut y = polynomial_range(x, n as u32, |k| self.coefficients(k));
+        if x.subset(UNIT) {
+            // Use the bounds |P_n(x)|, |T_n(x)| ≤ 1 and |U_n(x)| ≤ n + 1 for |x| ≤ 1.
+            y = match self {
+                ChebyshevT | LegendreP => y.intersection(UNIT),
+                ChebyshevU => {
+                    let n1 = n as f64 + 1.0;
+                    y.intersection(interval!(-n1, n1).unwrap())
+                }
+                _ => y,
+            };
+        }
+        Some(if negate { -y } else { y })
+    }
+}
+
+/// Evaluates the orthogonal polynomial `f` of degree `n` at `x`.
+///
+/// The result is decorated with [`Decoration::Trv`] unless `n` is a singleton integer.
+fn orthogonal_polynomial(f: OrthogonalPolynomial, n: DecInterval, x: DecInterval) -> DecInterval {
+    let mut dec = if n.interval().unwrap().is_singleton() {
+        Decoration::Dac.min(n.decoration()).min(x.decoration())
+    } else {
+        Decoration::Trv
+    };
+    let x = x.interval().unwrap();
+    let y = match polynomial_degrees(n.interval().unwrap()) {
+        Some(ns) => {
+            if ns.is_empty() {
+                dec = Decoration::Trv;
+            }
+            ns.fold(Interval::EMPTY, |y, n| match f.range(n, x) {
+                Some(yn) => y.convex_hull(yn),
+                _ => {
+                    dec = Decoration::Trv;
+                    y
+                }
+            })
+        }
+        _ => Interval::ENTIRE,
+    };
+    DecInterval::set_dec(y, dec)
+}
+
+/// Returns an enclosure of the range of the associated Legendre function P_n^m over `x`
+/// and `true` if the function is defined everywhere on `x`.
+///
+/// The function is defined by:
+///
+///   P_n^m(x) = (-1)^m (1 - x^2)^(m/2) d^m/dx^m P_n(x),
+///
+/// for 0 ≤ m ≤ n, and it is extended to other integers by:
+///
+///   P_n^m(x) = 0 if |m| > n,
+///   P_n^{-m}(x) = (-1)^m (n - m)! / (n + m)! P_n^m(x),
+///   P_{-n}^m(x) = P_{n-1}^m(x).
+///
+/// (1 - x^2)^(m/2) is defined only for |x| ≤ 1 if m is odd.
+fn assoc_legendre_p_range(n: i32, m: i32, x: Interval) -> (Interval, bool) {
+    const ONE: Interval = const_interval!(1.0, 1.0);
+    let n = if n < 0 { -n - 1 } else { n };
+    let mu = m.abs();
+    if mu > n {
+        return (const_interval!(0.0, 0.0), true);
+    }
+
+    let (x, total) = if mu % 2 == 1 {
+        let x_dom = x.intersection(const_interval!(-1.0, 1.0));
+        (x_dom, x_dom == x)
+    } else {
+        (x, true)
+    };
+    if x.is_empty() {
+        return (Interval::EMPTY, false);
+    }
+
+    // P_n^μ(x) = (-1)^μ (2μ - 1)!! (1 - x^2)^(μ/2) q_{n-μ}(x), where q_k satisfies:
+    //
+    //   (k + 1) q_{k+1}(x) = (2(μ + k) + 1) x q_k(x) - (k + 2μ) q_{k-1}(x).
+    let w = ONE - x.sqr();
+    let w = if mu % 2 == 0 {
+        w.pown(mu / 2)
+    } else {
+        w.sqrt().pown(mu)
+    };
+    let double_factorial = (1..mu).fold(ONE, |y, i| {
+        let k = (2 * i + 1) as f64;
+        y * interval!(k, k).unwrap()
+    });
+    let q = polynomial_range(x, (n - mu) as u32, |k| {
+        let k = k as f64;
+        let mu = mu as f64;
+        (2.0 * (mu + k) + 1.0, 0.0, k + 1.0, k + 2.0 * mu)
+    });
+    let mut y = double_factorial * w * q;
+    if m < 0 {
+        let n_mu1 = (n - mu + 1) as f64;
+        y /= rising_factorial(interval!(n_mu1, n_mu1).unwrap(), 2 * mu as u32);
+    } else if mu % 2 == 1 {
+        y = -y;
+    }
+    (y, total)
+}
+
+/// Evaluates the associated Legendre function P_n^m at `x`.
+///
+/// The result is decorated with [`Decoration::Trv`] unless both `n` and `m` are singleton integers.
+fn assoc_legendre_p(n: DecInterval, m: DecInterval, x: DecInterval) -> DecInterval {
+    let mut dec = if n.interval().unwrap().is_singleton() && m.interval().unwrap().is_singleton() {
+        Decoration::Dac
+            .min(n.decoration())
+            .min(m.decoration())
+            .min(x.decoration())
+    } else {
+        Decoration::Trv
+    };
+    let x = x.interval().unwrap();
+    let ns = polynomial_degrees(n.interval().unwrap());
+    let ms = polynomial_degrees(m.interval().unwrap());
+    let y = match (ns, ms) {
+        (Some(ns), Some(ms))
+            if ns.clone().count() * ms.clone().count() <= MAX_PRODUCT_LEN as usize =>
+        {
+            if ns.is_empty() || ms.is_empty() {
+                dec = Decoration::Trv;
+            }
+            ns.cartesian_product(ms).fold(Interval::EMPTY, |y, (n, m)| {
+                let (ynm, total) = assoc_legendre_p_range(n, m, x);
+                if !total {
+                    dec = Decoration::Trv;
+                }
+                y.convex_hull(ynm)
+            })
+        }
+        _ => Interval::ENTIRE,
+    };
+    DecInterval::set_dec(y, dec)
+}
+
+const M_ONE_TO_ONE: Interval = const_interval!(-1.0, 1.0);
+
+/// Evaluates a step function that takes the value `y_neg` for x < 0, `y_zero` for x = 0
+/// and `y_pos` for x > 0.
+fn step(x: DecInterval, y_neg: f64, y_zero: f64, y_pos: f64) -> (DecInterval, Option<DecInterval>) {
+    if x.is_empty() {
+        return (DecInterval::EMPTY, None);
+    }
+    let dec = x.decoration();
+    let y = |y: f64, d: Decoration| DecInterval::set_dec(interval!(y, y).unwrap(), d.min(dec));
+    let a = x.inf();
+    let b = x.sup();
+    if a > 0.0 {
+        (y(y_pos, Decoration::Com), None)
+    } else if b < 0.0 {
+        (y(y_neg, Decoration::Com), None)
+    } else if a == 0.0 && b == 0.0 {
+        (y(y_zero, Decoration::Dac), None)
+    } else if a == 0.0 {
+        (y(y_zero, Decoration::Def), Some(y(y_pos, Decoration::Def)))
+    } else if b == 0.0 {
+        (y(y_neg, Decoration::Def), Some(y(y_zero, Decoration::Def)))
+    } else {
+        let y1 = interval!(y_zero, y_zero)
+            .unwrap()
+            .convex_hull(interval!(y_pos, y_pos).unwrap());
+        (
+            y(y_neg, Decoration::Def),
+            Some(DecInterval::set_dec(y1, Decoration::Def.min(dec))),
+        )
+    }
+}
+
+/// Evaluates a function with period 1 that is continuous except at the integers,
+/// where it takes the value 0.
+///
+/// `f(n, x)` must return an enclosure of the continuous extension of the function
+/// from (n, n + 1) to `x` ⊆ [n, n + 1], and `range` must enclose the range of the function.
+fn periodic_with_jumps<F>(
+    x: DecInterval,
+    range: Interval,
+    f: F,
+) -> (DecInterval, Option<DecInterval>)
+where
+    F: Fn(f64, Interval) -> Interval,
+{
+    const ZERO: Interval = const_interval!(0.0, 0.0);
+    if x.is_empty() {
+        return (DecInterval::EMPTY, None);
+    }
+    let dec = x.decoration();
+    let a = x.inf();
+    let b = x.sup();
+    let na = a.floor();
+    let nb = b.floor();
+    if !x.is_common_interval() || nb - na > 1.0 {
+        return (DecInterval::set_dec(range, Decoration::Def.min(dec)), None);
+    }
+    if a == b {
+        return if a == na {
+            (DecInterval::set_dec(ZERO, Decoration::Dac.min(dec)), None)
+        } else {
+            let y = f(na, x.interval().unwrap());
+            (DecInterval::set_dec(y, Decoration::Com.min(dec)), None)
+        };
+    }
+    let dec = Decoration::Def.min(dec);
+    // `na + 1.0` is exact, since |`na`| < 2^53 holds by the above conditions.
+    let y0 = f(na, interval!(a, b.min(na + 1.0)).unwrap());
+    if na == nb {
+        if a == na {
+            (
+                DecInterval::set_dec(ZERO, dec),
+                Some(DecInterval::set_dec(y0, dec)),
+            )
+        } else {
+            (
+                DecInterval::set_dec(y0, Decoration::Com.min(x.decoration())),
+                None,
+            )
+        }
+    } else {
+        let y0 = if a == na { y0.convex_hull(ZERO) } else { y0 };
+        let y1 = if b > nb {
+            f(nb, interval!(nb, b).unwrap()).convex_hull(ZERO)
+        } else {
+            ZERO
+        };
+        (
+            DecInterval::set_dec(y0, dec),
+            Some(DecInterval::set_dec(y1, dec)),
+        )
+    }
+}
+
+/// The largest integer n such that all integers in [0, n] are representable in f64.
+const MAX_EXACT_INTEGER: f64 = 9007199254740992.0; // 2^53
+
+/// The maximum number of integers at which number-theoretic functions are evaluated individually.
+const MAX_NUMBER_THEORETIC_ARGS: f64 = 16.0;
+
+/// Evaluates a number-theoretic function over the integers in `n` that are not less than `min`.
+///
+/// If there are at most [`MAX_NUMBER_THEORETIC_ARGS`] of them and none of them exceeds
+/// [`MAX_EXACT_INTEGER`], `f` is evaluated at each of them.
+/// Otherwise, `bounds(a, b)` is used, which must return an enclosure of the values
+/// of the function at the integers in [a, b].
+///
+/// The result is decorated with [`Decoration::Trv`] unless `n` is a singleton,
+/// in which case the decoration does not exceed `dec`.
+fn number_theoretic<F, G>(
+    n: DecInterval,
+    min: f64,
+    dec: Decoration,
+    f: F,
+    bounds: G,
+) -> (DecInterval, Option<DecInterval>)
+where
+    F: Fn(f64) -> Interval,
+    G: Fn(f64, f64) -> Interval,
+{
+    let dec = if n.is_singleton() {
+        Decoration::Dac.min(n.decoration()).min(dec)
+    } else {
+        Decoration::Trv
+    };
+    let a = n.inf().ceil().max(min);
+    let b = n.sup().floor();
+    if a > b {
+        return (DecInterval::EMPTY, None);
+    }
+    let y = if b <= MAX_EXACT_INTEGER && b - a < MAX_NUMBER_THEORETIC_ARGS {
+        let mut ys = (a as i64..=b as i64)
+            .map(|n| f(n as f64))
+            .collect::<SmallVec<[_; 16]>>();
+        ys.sort_by(|x, y| x.inf().partial_cmp(&y.inf()).unwrap());
+        ys.dedup();
+        if let [y0, y1] = ys[..] {
+            if y0.disjoint(y1) {
+                return (
+                    DecInterval::set_dec(y0, dec),
+                    Some(DecInterval::set_dec(y1, dec)),
+                );
+            }
+        }
+        ys.into_iter()
+            .fold(Interval::EMPTY, |y, yi| y.convex_hull(yi))
+    } else {
+        bounds(a, b)
+    };
+    (DecInterval::set_dec(y, dec), None)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum BitwiseOp {
+    And,
+    Or,
+    Xor,
+}
+
+impl BitwiseOp {
+    fn apply(self, x: i64, y: i64) -> i64 {
+        match self {
+            BitwiseOp::And => x & y,
+            BitwiseOp::Or => x | y,
+            BitwiseOp::Xor => x ^ y,
+        }
+    }
+}
+
+/// Evaluates a bitwise operation over the integers in `x` and `y`,
+/// where negative integers are represented in two's complement with infinitely many bits.
+///
+/// The operation is performed on each pair of the integers if there are at most
+/// [`MAX_NUMBER_THEORETIC_ARGS`] of them, and [`bitwise_bounds`] is used otherwise.
+fn bitwise(op: BitwiseOp, x: DecInterval, y: DecInterval) -> (DecInterval, Option<DecInterval>) {
+    // The integers in [-2^62, 2^62) are closed under the operations.
+    const MAX: f64 = 4611686018427387904.0; // 2^62
+    let dec = if x.is_singleton() && y.is_singleton() {
+        Decoration::Dac.min(x.decoration()).min(y.decoration())
+    } else {
+        Decoration::Trv
+    };
+    let (xa, xb) = (x.inf().ceil(), x.sup().floor());
+    let (ya, yb) = (y.inf().ceil(), y.sup().floor());
+    if xa > xb || ya > yb {
+        return (DecInterval::EMPTY, None);
+    }
+    if xa >= -MAX
+        && xb < MAX
+        && ya >= -MAX
+        && yb < MAX
+        && (xb - xa + 1.0) * (yb - ya + 1.0) <= MAX_NUMBER_THEORETIC_ARGS
+    {
+        let zs = (xa as i64..=xb as i64)
+            .cartesian_product(ya as i64..=yb as i64)
+            .map(|(x, y)| op.apply(x, y))
+            .collect::<SmallVec<[_; 16]>>();
+        // Give up if any of the results is not representable in f64.
+        if zs.iter().all(|&z| z as f64 as i64 == z) {
+            let mut zs = zs
+                .into_iter()
+                .map(|z| z as f64)
+                .collect::<SmallVec<[_; 16]>>();
+            zs.sort_by(|x, y| x.partial_cmp(y).unwrap());
+            zs.dedup();
+            let z0 = interval!(zs[0], zs[0]).unwrap();
+            let z1 = interval!(zs[zs.len() - 1], zs[zs.len() - 1]).unwrap();
+            return if zs.len() == 2 {
+                (
+                    DecInterval::set_dec(z0, dec),
+                    Some(DecInterval::set_dec(z1, dec)),
+                )
+            } else {
+                (DecInterval::set_dec(z0.convex_hull(z1), dec), None)
+            };
+        }
+    }
+    // Split the ranges into the negative and the nonnegative parts.
+    let split = |a: f64, b: f64| {
+        [(a, b.min(-1.0)), (a.max(0.0), b)]
+            .iter()
+            .copied()
+            .filter(|&(a, b)| a <= b)
+            .collect::<SmallVec<[_; 2]>>()
+    };
+    let mut z = Interval::EMPTY;
+    for &xr in &split(xa, xb) {
+        for &yr in &split(ya, yb) {
+            z = z.convex_hull(bitwise_bounds(op, xr, yr));
+        }
+    }
+    (DecInterval::set_dec(z, dec), None)
+}
+
+/// Returns an enclosure of the results of a bitwise operation over the integers
+/// in [a1, b1] and [a2, b2], each of which must consist only of either negative
+/// or nonnegative integers.
+///
+/// The bounds for negative integers are derived from those for nonnegative integers
+/// by the identities x & y = ¬(¬x | ¬y), x | y = ¬(¬x & ¬y) and x ^ y = ¬(¬x ^ y),
+/// where ¬x = -1 - x.
+fn bitwise_bounds(op: BitwiseOp, (a1, b1): (f64, f64), (a2, b2): (f64, f64)) -> Interval {
+    use BitwiseOp::*;
+    // Returns the smallest power of two that is greater than `b`, where `b` ≥ 0.
+    let pow2 = |b: f64| {
+        let n = bit_length(b);
+        if n == f64::INFINITY {
+            f64::INFINITY
+        } else {
+            2.0f64.powi(n as i32)
+        }
+    };
+    // Returns an upper bound of ¬x for x ≥ `a`, where `a` < 0.
+    let not_ru = |a: f64| {
+        if a >= -MAX_EXACT_INTEGER {
+            -1.0 - a
+        } else {
+            -a
+        }
+    };
+    let (z0, z1) = match (op, b1 < 0.0, b2 < 0.0) {
+        (And, false, false) => (0.0, b1.min(b2)),
+        (And, true, false) => (0.0, b2),
+        (And, false, true) => (0.0, b1),
+        (And, true, true) => (-pow2(not_ru(a1).max(not_ru(a2))), b1.min(b2)),
+        (Or, false, false) => (a1.max(a2), pow2(b1.max(b2)) - 1.0),
+        (Or, true, false) => (a1, -1.0),
+        (Or, false, true) => (a2, -1.0),
+        (Or, true, true) => (a1.max(a2), -1.0),
+        (Xor, false, false) => (0.0, pow2(b1.max(b2)) - 1.0),
+        (Xor, true, false) => (-pow2(not_ru(a1).max(b2)), -1.0),
+        (Xor, false, true) => (-pow2(b1.max(not_ru(a2))), -1.0),
+        (Xor, true, true) => (0.0, pow2(not_ru(a1).max(not_ru(a2))) - 1.0),
+    };
+    interval!(z0, z1).unwrap()
+}
+
+/// Returns the number of bits needed to represent the integer part of `x`,
+/// where `x` ≥ 0.
+fn bit_length(x: f64) -> f64 {
+    if x < MAX_EXACT_INTEGER {
+        (64 - (x as u64).leading_zeros()) as f64
+    } else if x == f64::INFINITY {
+        f64::INFINITY
+    } else {
+        // x ∈ [2^e, 2^(e+1)).
+        let e = ((x.to_bits() >> 52) & 0x7ff) as i64 - 1023;
+        (e + 1) as f64
+    }
+}
+
+/// Returns an enclosure of σ_k(n) = ∏_{p^e ∥ n} ∑_{j=0}^e p^{jk},
+/// where `n` must be positive and must not exceed [`MAX_EXACT_INTEGER`].
+fn divisor_sigma(k: Interval, n: u64) -> Interval {
+    number_theory::factorize(n)
+        .iter()
+        .fold(const_interval!(1.0, 1.0), |y, &(p, e)| {
+            let mut q = 1;
+            let mut s = const_interval!(1.0, 1.0);
+            for _ in 0..e {
+                q *= p;
+                let q = q as f64;
+                s += interval!(q, q).unwrap().pow(k);
+            }
+            y * s
+        })
+}
+
+/// Returns an upper bound of the number of positive divisors of the integers in [1, `b`].
+fn num_divisors_bound(b: f64) -> f64 {
+    // d(n) ≤ 2√n.
+    interval!(b, b)
+        .map_or(f64::INFINITY, |b| {
+            (const_interval!(2.0, 2.0) * b.sqrt()).sup().floor()
+        })
+        .min(b)
+}
+
+/// Returns an enclosure of π(x), the number of primes less than or equal to x.
+///
+/// The exact values are used for x < [`number_theory::SIEVE_LIMIT`],
+/// and the following bounds by Rosser and Schoenfeld (1962) are used elsewhere:
+///
+///   x / ln(x) < π(x)  for x ≥ 17,
+///   π(x) < 1.25506 x / ln(x)  for x > 1.
+fn prime_pi(x: DecInterval) -> (DecInterval, Option<DecInterval>) {
+    // 1.25506, rounded up.
+    const C_RU: f64 = 1.25506;
+    let bound = |x: f64, upper: bool| {
+        if x < 2.0 {
+            0.0
+        } else if let Some(y) = number_theory::prime_pi(x as u64) {
+            y as f64
+        } else if x == f64::INFINITY {
+            x
+        } else {
+            let x = interval!(x, x).unwrap();
+            let y = x / x.ln();
+            if upper {
+                (interval!(C_RU, C_RU).unwrap() * y).sup().floor()
+            } else {
+                let y0 = number_theory::prime_pi(number_theory::SIEVE_LIMIT - 1).unwrap();
+                y.inf().ceil().max(y0 as f64)
+            }
+        }
+    };
+    let a = x.inf();
+    let b = x.sup();
+    let ya = bound(a, false);
+    let yb = bound(b, true);
+    let dec = if b < number_theory::SIEVE_LIMIT as f64 && ya == yb {
+        // π is constant on x.
+        if a == a.floor() && a >= 2.0 && number_theory::is_prime(a as u64) {
+            // π is not left-continuous at a.
+            Decoration::Dac
+        } else {
+            Decoration::Com
+        }
+    } else {
+        Decoration::Def
+    }
+    .min(x.decoration());
+    if yb - ya == 1.0 {
+        let y0 = interval!(ya, ya).unwrap();
+        let y1 = interval!(yb, yb).unwrap();
+        (
+            DecInterval::set_dec(y0, dec),
+            Some(DecInterval::set_dec(y1, dec)),
+        )
+    } else {
+        (DecInterval::set_dec(interval!(ya, yb).unwrap(), dec), None)
+    }
+}
+
+/// Evaluates `f`, a function whose poles are the nonpositive integers
+/// such as the digamma function, over `x`.
+///
+/// If `x` contains exactly one of the poles, it is split at the pole.
+/// The decoration of the result does not exceed `dec`.
+fn eval_gamma_like<F>(x: DecInterval, dec: Decoration, f: F) -> (DecInterval, Option<DecInterval>)
+where
+    F: Fn(Interval) -> Interval,
+{
+    let a = x.inf();
+    let b = x.sup();
+    let ia = a.ceil();
+    let ib = b.floor();
+    if ia == ib && a <= 0.0 {
+        // ∃i ∈ S : x ∩ S = {i}, where S = {0, -1, …}.
+        let dec = Decoration::Trv;
+        let x0 = interval!(a, ia).unwrap();
+        let x1 = interval!(ia, b).unwrap();
+        (
+            DecInterval::set_dec(f(x0), dec),
+            Some(DecInterval::set_dec(f(x1), dec)),
+        )
+    } else {
+        let dec = if ia < ib && a <= 0.0 {
+            // x ∩ S ≠ ∅.
+            Decoration::Trv
+        } else {
+            Decoration::Com.min(x.decoration()).min(dec)
+        };
+        let x = x.interval().unwrap();
+        (DecInterval::set_dec(f(x), dec), None)
+    }
+}
+
+/// `x` must be nonempty.
+fn cbrt(x: Interval) -> Interval {
+    interval!(cbrt_rd(x.inf()), cbrt_ru(x.sup())).unwrap()
+}
+
+/// `x` must be nonempty.
+pub(crate) fn digamma(x: Interval) -> Interval {
+    let a = x.inf();
+    let b = x.sup();
+    let ia = a.ceil();
+    let ib = b.floor();
+    if x.is_singleton() && a == ia && a <= 0.0 {
+        // ∃i ∈ S : x = {i}, where S = {0, -1, …}.
+        Interval::EMPTY
+    } else if a < 0.0 && (a < ia && ia <= ib && ib < b || x.wid() >= 1.0) {
+        // (∃i ∈ S : a < i < b) ∨ (a < 0 ∧ b - a ≥ 1).
+        Interval::ENTIRE
+    } else {
+        let inf = if a == ia && a <= 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            digamma_rd(a)
+        };
+        let sup = if b == ib && b <= 0.0 {
+            f64::INFINITY
+        } else {
+            digamma_ru(b)
+        };
+        interval!(inf, sup).unwrap()
+    }
+}
+
+/// `x` must be nonempty.
+pub(crate) fn erf(x: Interval) -> Interval {
+    interval!(erf_rd(x.inf()), erf_ru(x.sup())).unwrap()
+}
+
+/// `x` must be nonempty.
+pub(crate) fn erfc(x: Interval) -> Interval {
+    interval!(erfc_rd(x.sup()), erfc_ru(x.inf())).unwrap()
+}
+
+/// `x` must be nonempty.
+fn expm1(x: Interval) -> Interval {
+    interval!(expm1_rd(x.inf()), expm1_ru(x.sup())).unwrap()
+}
+
+/// `x` and `y` must be nonempty.
+fn hypot(x: Interval, y: Interval) -> Interval {
+    interval!(hypot_rd(x.mig(), y.mig()), hypot_ru(x.mag(), y.mag())).unwrap()
+}
+
+/// Returns an enclosure of the range of ln |Γ| over `x`.
+///
+/// `x` must be nonempty.
+fn ln_gamma(x: Interval) -> Interval {
+    // argmin_{x > 0} Γ(x), rounded down/up.
+    const ARGMIN_RD: f64 = 1.4616321449683622;
+    const ARGMIN_RU: f64 = 1.4616321449683625;
+    // min_{x > 0} ln Γ(x), rounded down.
+    const MIN_RD: f64 = -0.12148629053584961;
+    let a = x.inf();
+    let b = x.sup();
+    let ia = a.ceil();
+    let ib = b.floor();
+    if x.is_singleton() && a == ia && a <= 0.0 {
+        // ∃i ∈ S : x = {i}, where S = {0, -1, …}.
+        Interval::EMPTY
+    } else if a < 0.0 && (a < ia && ia <= ib && ib < b || x.wid() >= 1.0) {
+        // (∃i ∈ S : a < i < b) ∨ (a < 0 ∧ b - a ≥ 1).
+        Interval::ENTIRE
+    } else if a >= 0.0 {
+        // lgamma_rd/ru(±0.0) returns +∞.
+        if b <= ARGMIN_RD {
+            // b < x0, where x0 = argmin_{x > 0} Γ(x).
+            interval!(lgamma_rd(b), lgamma_ru(a)).unwrap()
+        } else if a >= ARGMIN_RU {
+            // x0 < a.
+            interval!(lgamma_rd(a), lgamma_ru(b)).unwrap()
+        } else {
+            // a < x0 < b.
+            interval!(MIN_RD, lgamma_ru(a).max(lgamma_ru(b))).unwrap()
+        }
+    } else {
+        // ∃i ∈ S : x ⊆ [i - 1, i].
+        // ln |Γ| is convex on the interval since (ln |Γ|)'' = ψ' > 0.
+        let a_is_pole = a == ia;
+        let b_is_pole = b == ib;
+        let inf = if !b_is_pole && digamma_ru(b) <= 0.0 {
+            // ln |Γ| is decreasing on x.
+            lgamma_rd(b)
+        } else if !a_is_pole && digamma_rd(a) >= 0.0 {
+            // ln |Γ| is increasing on x.
+            lgamma_rd(a)
+        } else {
+            // From the reflection formula,
+            //
+            //   ln |Γ(x)| = ln π - ln |sin(π x)| - ln Γ(1 - x) ≥ ln π - ln Γ(1 - x),
+            //
+            // and ln Γ is convex on [1, ∞).
+            let y = const_interval!(1.0, 1.0) - x;
+            let max = lgamma_ru(y.inf()).max(lgamma_ru(y.sup()));
+            (Interval::PI.ln() - interval!(max, max).unwrap()).inf()
+        };
+        let sup = if a_is_pole || b_is_pole {
+            f64::INFINITY
+        } else {
+            lgamma_ru(a).max(lgamma_ru(b))
+        };
+        interval!(inf, sup).unwrap()
+    }
+}
+
+/// `x` must be a nonempty subset of \[-1, +∞\] that is not \[-1, -1\].
+fn log1p(x: Interval) -> Interval {
+    interval!(log1p_rd(x.inf()), log1p_ru(x.sup())).unwrap()
+}
+
+/// The maximum order of the polygamma function for which enclosures are computed.
+const MAX_POLYGAMMA_ORDER: f64 = 64.0;
+
+/// Returns an enclosure of the range of the polygamma functions ψ^(n) over `x`
+/// for all integers n ≥ 0 in `n`.
+///
+/// `x` must be nonempty.
+fn polygamma(n: Interval, x: Interval) -> Interval {
+    let n0 = n.inf().max(0.0).ceil();
+    let n1 = n.sup().floor();
+    if n0 > n1 {
+        Interval::EMPTY
+    } else if n1 > MAX_POLYGAMMA_ORDER {
+        Interval::ENTIRE
+    } else {
+        (n0 as u32..=n1 as u32)
+            .map(|n| {
+                if n == 0 {
+                    digamma(x)
+                } else {
+                    polygamma_of_order(n, x)
+                }
+            })
+            .fold(Interval::EMPTY, |y, yn| y.convex_hull(yn))
+    }
+}
+
+/// Returns an enclosure of the range of ψ^(n) over `x`, where n ≥ 1.
+///
+/// `x` must be nonempty.
+fn polygamma_of_order(n: u32, x: Interval) -> Interval {
+    let a = x.inf();
+    let b = x.sup();
+    let ia = a.ceil();
+    let ib = b.floor();
+    let odd = n % 2 == 1;
+    if x.is_singleton() && a == ia && a <= 0.0 {
+        // ∃i ∈ S : x = {i}, where S = {0, -1, …}.
+        Interval::EMPTY
+    } else if a < 0.0 && (a < ia && ia <= ib && ib < b || x.wid() >= 1.0) {
+        // (∃i ∈ S : a < i < b) ∨ (a < 0 ∧ b - a ≥ 1).
+        if odd {
+            // ψ^(n) > 0 for odd n.
+            const_interval!(0.0, f64::INFINITY)
+        } else {
+            Interval::ENTIRE
+        }
+    } else {
+        // Between two consecutive poles, ψ^(n) is increasing if n is even
+        // and is convex if n is odd, as
+        //
+        //   ψ^(n)(x) = (-1)^(n+1) n! ∑_{j=0}^∞ 1 / (x + j)^(n+1).
+        let a_is_pole = a == ia && a <= 0.0;
+        let b_is_pole = b == ib && b <= 0.0;
+        // ψ^(n)(x) → 0 as x → ∞.
+        let f = |x: f64| {
+            if x == f64::INFINITY {
+                const_interval!(0.0, 0.0)
+            } else {
+                polygamma_point(n, x)
+            }
+        };
+        if !odd {
+            let inf = if a_is_pole {
+                f64::NEG_INFINITY
+            } else {
+                f(a).inf()
+            };
+            let sup = if b_is_pole { f64::INFINITY } else { f(b).sup() };
+            interval!(inf, sup).unwrap()
+        } else {
+            let inf = if a >= 0.0 || !b_is_pole && polygamma_point(n + 1, b).sup() <= 0.0 {
+                // ψ^(n) is decreasing on x.
+                f(b).inf()
+            } else if !a_is_pole && polygamma_point(n + 1, a).inf() >= 0.0 {
+                // ψ^(n) is increasing on x.
+                f(a).inf()
+            } else {
+                // Let t = x - a + 1 ∈ (0, 1).  Then,
+                //
+                //   ψ^(n)(x) ≥ n! (1 / t^(n+1) + 1 / (1 - t)^(n+1)) ≥ n! 2^(n+2).
+                let p = 2.0_f64.powi(n as i32 + 2);
+                (rising_factorial(const_interval!(1.0, 1.0), n) * interval!(p, p).unwrap()).inf()
+            };
+            let sup = if a_is_pole || b_is_pole {
+                f64::INFINITY
+            } else {
+                f(a).sup().max(f(b).sup())
+            };
+            interval!(inf, sup).unwrap()
+        }
+    }
+}
+
+/// Returns an enclosure of ψ^(n)(x), where n ≥ 1 and x is finite and not a pole.
+///
+/// The value is computed from the recurrence relation
+///
+///   ψ^(n)(x) = ψ^(n)(x + m) - (-1)^n n! ∑_{j=0}^{m-1} 1 / (x + j)^(n+1)
+///
+/// and the asymptotic expansion for large y = x + m
+///
+///   ψ^(n)(y) ~ (-1)^(n+1) (n - 1)! / y^n
+///     × (1 + n / (2y) + ∑_{k=1}^∞ B_{2k} n (n + 1) … (n + 2k - 1) / ((2k)! y^{2k})),
+///
+/// where B_{2k} are the Bernoulli numbers.  The error of truncating the series
+/// is bounded by the first omitted term in absolute value (Alzer 1997).
+fn polygamma_point(n: u32, x: f64) -> Interval {
+    // B_{2k} for k = 1, …, 9 as pairs of the numerator and the denominator.
+    const BERNOULLI: [(f64, f64); 9] = [
+        (1.0, 6.0),
+        (-1.0, 30.0),
+        (1.0, 42.0),
+        (-1.0, 30.0),
+        (5.0, 66.0),
+        (-691.0, 2730.0),
+        (7.0, 6.0),
+        (-3617.0, 510.0),
+        (43867.0, 798.0),
+    ];
+    // The maximum number of applications of the recurrence relation.
+    const MAX_SHIFT: f64 = 65536.0;
+    const ONE: Interval = const_interval!(1.0, 1.0);
+    let i = |x: f64| interval!(x, x).unwrap();
+
+    let m = ((2 * n).max(20) as f64 - x).ceil().max(0.0);
+    if m > MAX_SHIFT {
+        return Interval::ENTIRE;
+    }
+    let x = i(x);
+    let s = (0..m as u32).fold(const_interval!(0.0, 0.0), |s, j| {
+        s + (x + i(j as f64)).pown(-(n as i32) - 1)
+    });
+
+    let y = x + i(m);
+    let y_inv = ONE / y;
+    let y_inv2 = y_inv.sqr();
+    // (n - 1)! / y^n.
+    let c = (1..n).fold(y_inv, |c, k| c * i(k as f64) * y_inv);
+    let nf = i(n as f64);
+    let mut sum = ONE + nf * y_inv / const_interval!(2.0, 2.0);
+    // n (n + 1) … (n + 2k - 1) / ((2k)! y^{2k}).
+    let mut t = ONE;
+    for (k, &(p, q)) in BERNOULLI.iter().enumerate() {
+        let k2 = 2.0 * (k + 1) as f64;
+        t = t * (nf + i(k2 - 2.0)) * (nf + i(k2 - 1.0)) / (i(k2 - 1.0) * i(k2)) * y_inv2;
+        let term = i(p) / i(q) * t;
+        if k + 1 < BERNOULLI.len() {
+            sum += term;
+        } else {
+            let r = term.mag();
+            sum += interval!(-r, r).unwrap();
+        }
+    }
+
+    let y = c * sum + rising_factorial(ONE, n) * s;
+    if n % 2 == 1 {
+        y
+    } else {
+        -y
+    }
+}
+
+/// `x` must be nonempty.
+pub(crate) fn rootn(x: Interval, n: u32) -> Interval {
+    if n == 0 {
+        Interval::EMPTY
+    } else if n % 2 == 0 {
+        const DOM: Interval = const_interval!(0.0, f64::INFINITY);
+        let x = x.intersection(DOM);
+        if x.is_empty() {
+            return Interval::EMPTY;
+        }
+        let a = x.inf();
+        let b = x.sup();
+        interval!(rootn_rd(a, n), rootn_ru(b, n)).unwrap()
+    } else {
+        let a = x.inf();
+        let b = x.sup();
+        interval!(rootn_rd(a, n), rootn_ru(b, n)).unwrap()
+    }
+}
+
+/// `x` must be nonempty.
+pub(crate) fn sinc(x: Interval) -> Interval {
+    // argmin_{x > 0} sinc(x), rounded down.
+    const ARGMIN_RD: f64 = 4.493409457909063;
+    // min_{x > 0} sinc(x), rounded down.
+    const MIN_RD: f64 = -0.21723362821122166;
+    let a = x.inf();
+    let b = x.sup();
+    if a <= 0.0 && b >= 0.0 {
         let b2 = (-a).max(b);
         if b2 <= ARGMIN_RD {
             let x2 = interval!(b2, b2).unwrap();
@@ -1226,6 +2659,31 @@ pub(crate) fn sinc(x: Interval) -> Interval {
     }
 }
 
+/// `x` must be nonempty.
+pub(crate) fn sinhc(x: Interval) -> Interval {
+    // sinhc is even and is increasing on [0, +∞).
+    let f = |x: f64| {
+        let x = interval!(x, x).unwrap();
+        x.sinh() / x
+    };
+    let x = x.abs();
+    let a = x.inf();
+    let b = x.sup();
+    let inf = if a == 0.0 {
+        1.0
+    } else {
+        f(a).inf().clamp(1.0, f64::MAX)
+    };
+    let sup = if b == 0.0 {
+        1.0
+    } else if b == f64::INFINITY {
+        b
+    } else {
+        f(b).sup()
+    };
+    interval!(inf, sup).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1341,6 +2799,25 @@ mod tests {
         };
     }
 
+    #[test]
+    fn assoc_legendre_p() {
+        fn p(n: f64) -> impl Fn(TupperIntervalSet, TupperIntervalSet) -> TupperIntervalSet {
+            let n = TupperIntervalSet::from(DecInterval::new(interval!(n, n).unwrap()));
+            move |m, x| n.assoc_legendre_p(&m, &x)
+        }
+
+        test!(p(1.0), i!(1.0), i!(0.0), (vec![i!(-1.0)], Dac));
+        test!(p(2.0), i!(2.0), i!(0.0), (vec![i!(3.0)], Dac));
+        test!(p(2.0), i!(2.0), i!(2.0), (vec![i!(-9.0)], Dac));
+        test!(p(2.0), i!(-2.0), i!(0.0), (vec![i!(0.125)], Dac));
+        test!(p(-3.0), i!(2.0), i!(0.0), (vec![i!(3.0)], Dac));
+        test!(p(1.0), i!(2.0), i!(0.5), (vec![i!(0.0)], Dac));
+        test!(p(1.0), i!(1.0), i!(2.0), (vec![], Trv));
+        test!(p(1.0), i!(1.0), i!(0.0, 2.0), (vec![i!(-1.0, 0.0)], Trv));
+        test!(p(1.0), i!(0.5), i!(0.0), (vec![], Trv));
+        test!(p(1.0), i!(0.0, 1.0), i!(0.0), (vec![i!(-1.0, 0.0)], Trv));
+    }
+
     #[cfg(not(feature = "arb"))]
     #[test]
     fn atan2() {
@@ -1446,6 +2923,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn beta() {
+        fn f(x: TupperIntervalSet, y: TupperIntervalSet) -> TupperIntervalSet {
+            x.beta(&y, None)
+        }
+
+        test!(@commut f, i!(0.0), i!(1.0), (vec![], Trv));
+        test!(@commut f, i!(1.0), i!(1.0), (vec![i!(1.0)], Com));
+        test!(@commut f, i!(1.0), i!(2.0), (vec![i!(0.5)], Com));
+        test!(@commut f, i!(2.0), i!(3.0), (vec![interval!("[1/12]").unwrap()], Com));
+    }
+
+    #[test]
+    fn binom() {
+        fn f(x: TupperIntervalSet, y: TupperIntervalSet) -> TupperIntervalSet {
+            x.binom(&y, None)
+        }
+
+        test!(f, i!(5.0), i!(-1.0), (vec![i!(0.0)], Dac));
+        test!(f, i!(5.0), i!(0.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(5.0), i!(2.0), (vec![i!(10.0)], Dac));
+        test!(f, i!(-2.0), i!(2.0), (vec![i!(3.0)], Dac));
+        test!(f, i!(0.5, 1.0), i!(2.0), (vec![i!(-0.25, 0.0)], Dac));
+        test!(f, i!(-1.0), i!(0.5), (vec![], Trv));
+        test!(f, i!(-1.0), i!(0.0, 1.0), (vec![Interval::ENTIRE], Trv));
+    }
+
+    #[test]
+    fn bitwise() {
+        fn and(x: TupperIntervalSet, y: TupperIntervalSet) -> TupperIntervalSet {
+            x.bit_and(&y, None)
+        }
+        fn or(x: TupperIntervalSet, y: TupperIntervalSet) -> TupperIntervalSet {
+            x.bit_or(&y, None)
+        }
+        fn xor(x: TupperIntervalSet, y: TupperIntervalSet) -> TupperIntervalSet {
+            x.bit_xor(&y, None)
+        }
+
+        test!(@commut and, i!(12.0), i!(10.0), (vec![i!(8.0)], Dac));
+        test!(@commut or, i!(12.0), i!(10.0), (vec![i!(14.0)], Dac));
+        test!(@commut xor, i!(12.0), i!(10.0), (vec![i!(6.0)], Dac));
+        test!(@commut and, i!(-1.0), i!(5.0), (vec![i!(5.0)], Dac));
+        test!(@commut or, i!(-8.0), i!(3.0), (vec![i!(-5.0)], Dac));
+        test!(@commut xor, i!(-1.0), i!(5.0), (vec![i!(-6.0)], Dac));
+        test!(@commut and, i!(1.5), i!(1.0), (vec![], Trv));
+        // 2^60 & (2^60 + 2^55)
+        test!(
+            @commut and,
+            i!(1152921504606846976.0),
+            i!(1188950301625810944.0),
+            (vec![i!(1152921504606846976.0)], Dac)
+        );
+
+        test!(@commut and, i!(0.0, 1.0), i!(1.0), (vec![i!(0.0), i!(1.0)], Trv));
+        test!(@commut and, i!(0.0, 100.0), i!(0.0, 1000.0), (vec![i!(0.0, 100.0)], Trv));
+        test!(@commut or, i!(0.0, 100.0), i!(0.0, 1000.0), (vec![i!(0.0, 1023.0)], Trv));
+        test!(@commut xor, i!(0.0, 100.0), i!(0.0, 1000.0), (vec![i!(0.0, 1023.0)], Trv));
+        test!(@commut and, i!(-100.0, -1.0), i!(-1000.0, -1.0), (vec![i!(-1024.0, -1.0)], Trv));
+        test!(@commut or, i!(-100.0, -1.0), i!(0.0, 1000.0), (vec![i!(-100.0, -1.0)], Trv));
+        test!(@commut xor, i!(-100.0, -1.0), i!(0.0, 1000.0), (vec![i!(-1024.0, -1.0)], Trv));
+        test!(
+            @commut and,
+            i!(0.0, f64::INFINITY),
+            i!(f64::NEG_INFINITY, f64::INFINITY),
+            (vec![i!(0.0, f64::INFINITY)], Trv)
+        );
+    }
+
+    #[test]
+    fn cbrt() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.cbrt()
+        }
+
+        test!(f, @odd i!(0.0, 8.0), (vec![i!(0.0, 2.0)], Com));
+        test!(f, @odd i!(8.0, 27.0), (vec![i!(2.0, 3.0)], Com));
+        test!(
+            f,
+            i!(-8.0, f64::INFINITY),
+            (vec![i!(-2.0, f64::INFINITY)], Com)
+        );
+    }
+
     #[test]
     fn ceil() {
         fn f(x: TupperIntervalSet) -> TupperIntervalSet {
@@ -1463,6 +3024,32 @@ mod tests {
         test!(f, i!(-0.5, 0.5), (vec![i!(0.0), i!(1.0)], Def));
     }
 
+    #[test]
+    fn chebyshev_t() {
+        fn f(n: TupperIntervalSet, x: TupperIntervalSet) -> TupperIntervalSet {
+            n.chebyshev_t(&x)
+        }
+
+        test!(f, i!(0.0), i!(2.0), (vec![i!(1.0)], Dac));
+        test!(f, @even i!(3.0), i!(0.5), (vec![i!(-1.0)], Dac));
+        test!(f, i!(2.0), i!(-1.0, 1.0), (vec![i!(-1.0, 1.0)], Dac));
+        test!(f, i!(0.5), i!(0.5), (vec![], Trv));
+        test!(f, i!(0.0, 1.0), i!(0.5), (vec![i!(0.5, 1.0)], Trv));
+    }
+
+    #[test]
+    fn chebyshev_u() {
+        fn f(n: TupperIntervalSet, x: TupperIntervalSet) -> TupperIntervalSet {
+            n.chebyshev_u(&x)
+        }
+
+        test!(f, i!(1.0), i!(0.5), (vec![i!(1.0)], Dac));
+        test!(f, i!(2.0), i!(2.0), (vec![i!(15.0)], Dac));
+        test!(f, i!(-1.0), i!(2.0), (vec![i!(0.0)], Dac));
+        test!(f, i!(-3.0), i!(0.5), (vec![i!(-1.0)], Dac));
+        test!(f, i!(0.5), i!(0.5), (vec![], Trv));
+    }
+
     #[test]
     fn digamma() {
         fn f(x: TupperIntervalSet) -> TupperIntervalSet {
@@ -1566,6 +3153,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn divisor_sigma() {
+        fn f(k: TupperIntervalSet, n: TupperIntervalSet) -> TupperIntervalSet {
+            k.divisor_sigma(&n, None)
+        }
+
+        test!(f, i!(1.0), i!(0.0), (vec![], Trv));
+        test!(f, i!(1.0), i!(1.5), (vec![], Trv));
+        test!(f, i!(-1.0), i!(1.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(0.0), i!(12.0), (vec![i!(6.0)], Dac));
+        test!(f, i!(1.0), i!(12.0), (vec![i!(28.0)], Dac));
+        test!(f, i!(2.0), i!(12.0), (vec![i!(210.0)], Dac));
+        test!(f, i!(0.0, 1.0), i!(2.0), (vec![i!(2.0, 3.0)], Dac));
+        test!(f, i!(2.0), i!(1.0, 2.0), (vec![i!(1.0), i!(5.0)], Trv));
+        test!(f, i!(0.0), i!(1.0, 100.0), (vec![i!(1.0, 20.0)], Trv));
+    }
+
+    #[test]
+    fn expm1() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.expm1()
+        }
+
+        test!(f, i!(0.0), (vec![i!(0.0)], Com));
+        test!(f, i!(f64::NEG_INFINITY, 0.0), (vec![i!(-1.0, 0.0)], Com));
+
+        let y = f(TupperIntervalSet::from(const_dec_interval!(1e-300, 1e-300)));
+        let y = y.iter().next().unwrap().x;
+        assert!(y.inf() == 1e-300 && y.sup() > 1e-300 && y.wid() < 1e-315);
+    }
+
+    #[test]
+    fn factorial() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.factorial(None)
+        }
+
+        test!(f, i!(-2.0), (vec![], Trv));
+        test!(f, i!(-1.0), (vec![], Trv));
+        test!(f, i!(0.0), (vec![i!(1.0)], Com));
+        test!(f, i!(1.0), (vec![i!(1.0)], Com));
+        test!(f, i!(3.0), (vec![i!(6.0)], Com));
+        test!(f, i!(10.0), (vec![i!(3628800.0)], Com));
+    }
+
+    #[test]
+    fn clamp() {
+        fn c(a: f64, b: f64) -> impl Fn(TupperIntervalSet) -> TupperIntervalSet {
+            let a = TupperIntervalSet::from(DecInterval::new(interval!(a, a).unwrap()));
+            let b = TupperIntervalSet::from(DecInterval::new(interval!(b, b).unwrap()));
+            move |x| x.clamp(&a, &b)
+        }
+
+        test!(c(-1.0, 1.0), @odd i!(0.5), (vec![i!(0.5)], Com));
+        test!(c(-1.0, 1.0), @odd i!(2.0), (vec![i!(1.0)], Com));
+        test!(c(-1.0, 1.0), @odd i!(0.0, 2.0), (vec![i!(0.0, 1.0)], Com));
+        test!(
+            c(-1.0, 1.0),
+            i!(f64::NEG_INFINITY, f64::INFINITY),
+            (vec![i!(-1.0, 1.0)], Dac)
+        );
+    }
+
     #[test]
     fn floor() {
         fn f(x: TupperIntervalSet) -> TupperIntervalSet {
@@ -1645,6 +3295,86 @@ mod tests {
         );
     }
 
+    #[test]
+    fn heaviside() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.heaviside(None)
+        }
+
+        test!(f, i!(-1.0), (vec![i!(0.0)], Com));
+        test!(f, i!(0.0), (vec![i!(0.5)], Dac));
+        test!(f, i!(1.0), (vec![i!(1.0)], Com));
+        test!(f, i!(-1.0, 0.0), (vec![i!(0.0), i!(0.5)], Def));
+        test!(f, i!(0.0, 1.0), (vec![i!(0.5), i!(1.0)], Def));
+        test!(f, i!(-1.0, 1.0), (vec![i!(0.0), i!(0.5, 1.0)], Def));
+    }
+
+    #[test]
+    fn hermite_h() {
+        fn f(n: TupperIntervalSet, x: TupperIntervalSet) -> TupperIntervalSet {
+            n.hermite_h(&x)
+        }
+
+        test!(f, i!(0.0), i!(2.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(2.0), @even i!(1.0), (vec![i!(2.0)], Dac));
+        test!(f, i!(3.0), @odd i!(1.0), (vec![i!(-4.0)], Dac));
+        test!(f, i!(-1.0), i!(1.0), (vec![], Trv));
+        test!(f, i!(-1.0, 0.0), i!(1.0), (vec![i!(1.0)], Trv));
+    }
+
+    #[test]
+    fn hypot() {
+        fn f(x: TupperIntervalSet, y: TupperIntervalSet) -> TupperIntervalSet {
+            x.hypot(&y)
+        }
+
+        test!(@commut f, @even i!(3.0), @even i!(4.0), (vec![i!(5.0)], Com));
+        test!(@commut f, i!(-3.0, 3.0), i!(4.0), (vec![i!(4.0, 5.0)], Com));
+        test!(f, i!(0.0), i!(0.0), (vec![i!(0.0)], Com));
+        test!(
+            @commut f,
+            i!(0.0),
+            i!(0.0, f64::INFINITY),
+            (vec![i!(0.0, f64::INFINITY)], Com)
+        );
+
+        let z = f(
+            TupperIntervalSet::from(const_dec_interval!(1e-200, 1e-200)),
+            TupperIntervalSet::from(const_dec_interval!(1e-200, 1e-200)),
+        );
+        let z = z.iter().next().unwrap().x;
+        assert!(z.inf() > 1.414e-200 && z.sup() < 1.415e-200);
+    }
+
+    #[test]
+    fn is_prime() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.is_prime(None)
+        }
+
+        test!(f, i!(-2.0), (vec![i!(0.0)], Dac));
+        test!(f, i!(1.0), (vec![i!(0.0)], Dac));
+        test!(f, i!(2.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(2.5), (vec![], Trv));
+        test!(f, i!(4.0), (vec![i!(0.0)], Dac));
+        test!(f, i!(9007199254740881.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(9007199254740992.0), (vec![i!(0.0)], Dac));
+        test!(f, i!(2.0, 4.0), (vec![i!(0.0), i!(1.0)], Trv));
+        test!(f, i!(8.0, 10.0), (vec![i!(0.0)], Trv));
+        test!(f, i!(0.0, f64::INFINITY), (vec![i!(0.0, 1.0)], Trv));
+    }
+
+    #[test]
+    fn laguerre_l() {
+        fn f(n: TupperIntervalSet, x: TupperIntervalSet) -> TupperIntervalSet {
+            n.laguerre_l(&x)
+        }
+
+        test!(f, i!(1.0), i!(1.0), (vec![i!(0.0)], Dac));
+        test!(f, i!(2.0), i!(1.0), (vec![i!(-0.5)], Dac));
+        test!(f, i!(-1.0), i!(1.0), (vec![], Trv));
+    }
+
     #[test]
     fn lcm() {
         fn f(x: TupperIntervalSet, y: TupperIntervalSet) -> TupperIntervalSet {
@@ -1657,6 +3387,110 @@ mod tests {
         test!(@commut f, @even i!(3.0), @even i!(5.0), (vec![i!(15.0)], Dac));
     }
 
+    #[test]
+    fn legendre_p() {
+        fn f(n: TupperIntervalSet, x: TupperIntervalSet) -> TupperIntervalSet {
+            n.legendre_p(&x)
+        }
+
+        test!(f, i!(0.0), i!(2.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(2.0), @even i!(0.5), (vec![i!(-0.125)], Dac));
+        test!(f, i!(-3.0), i!(0.5), (vec![i!(-0.125)], Dac));
+        test!(f, i!(3.0), @odd i!(1.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(2.0), i!(0.5, 1.0), (vec![i!(-0.125, 1.0)], Dac));
+        test!(f, i!(2.0), i!(-1.0, 1.0), (vec![i!(-1.0, 1.0)], Dac));
+        test!(f, i!(0.5), i!(0.5), (vec![], Trv));
+        test!(f, i!(0.0, 1.0), i!(0.5), (vec![i!(0.5, 1.0)], Trv));
+    }
+
+    #[test]
+    fn ln_gamma() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.ln_gamma(None)
+        }
+
+        test!(f, i!(-3.0), (vec![], Trv));
+        test!(f, i!(-2.0), (vec![], Trv));
+        test!(f, i!(-1.0), (vec![], Trv));
+        test!(f, i!(0.0), (vec![], Trv));
+        test!(f, i!(1.0), (vec![i!(0.0)], Com));
+        test!(f, i!(2.0), (vec![i!(0.0)], Com));
+        test!(
+            f,
+            i!(3.0),
+            (
+                vec![interval!("[0.693147180559945309417232]").unwrap()],
+                Com
+            )
+        );
+        test!(f, i!(1.0, 2.0), (vec![i!(-0.12148629053584961, 0.0)], Com));
+        // ln |Γ(-5/2)| ≈ -0.05624, ln |Γ(-3/2)| ≈ 0.86005.
+        let y = f(TupperIntervalSet::from(const_dec_interval!(-2.5, -1.5)));
+        assert!(y.iter().all(|y| y.d == Trv && y.x.sup() == f64::INFINITY));
+        assert!(y.iter().any(|y| y.x.contains(-0.05624371649767405)));
+        assert!(y.iter().any(|y| y.x.contains(0.860047015376481)));
+
+        // ln |Γ(-1/2)| = ln (2 √π).
+        let y = f(TupperIntervalSet::from(const_dec_interval!(-0.5, -0.5)));
+        assert!(y
+            .iter()
+            .all(|y| y.x.contains(1.2655121234846453) && y.x.wid() < 1e-15));
+        // ln Γ(10^300) = 10^300 (300 ln 10 - 1) + O(ln 10^300).
+        let y = f(TupperIntervalSet::from(const_dec_interval!(1e300, 1e300)));
+        assert!(y
+            .iter()
+            .all(|y| y.x.contains(6.897755278982137e302) && y.x.wid() < 1e288));
+    }
+
+    #[test]
+    fn log1p() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.log1p()
+        }
+
+        test!(f, i!(-2.0, -1.0), (vec![], Trv));
+        test!(f, i!(-1.0, 0.0), (vec![i!(f64::NEG_INFINITY, 0.0)], Trv));
+        test!(f, i!(0.0), (vec![i!(0.0)], Com));
+        test!(
+            f,
+            i!(0.0, f64::INFINITY),
+            (vec![i!(0.0, f64::INFINITY)], Com)
+        );
+
+        let y = f(TupperIntervalSet::from(const_dec_interval!(1e-300, 1e-300)));
+        let y = y.iter().next().unwrap().x;
+        assert!(y.inf() < 1e-300 && y.sup() == 1e-300 && y.wid() < 1e-315);
+    }
+
+    #[test]
+    fn mobius() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.mobius(None)
+        }
+
+        test!(f, i!(0.0), (vec![], Trv));
+        test!(f, i!(1.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(1.5), (vec![], Trv));
+        test!(f, i!(12.0), (vec![i!(0.0)], Dac));
+        test!(f, i!(30.0), (vec![i!(-1.0)], Dac));
+        test!(f, i!(5.0, 6.0), (vec![i!(-1.0), i!(1.0)], Trv));
+        test!(f, i!(1.0, 100.0), (vec![i!(-1.0, 1.0)], Trv));
+    }
+
+    #[test]
+    fn num_divisors() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.num_divisors(None)
+        }
+
+        test!(f, i!(0.0), (vec![], Trv));
+        test!(f, i!(1.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(1.5), (vec![], Trv));
+        test!(f, i!(12.0), (vec![i!(6.0)], Dac));
+        test!(f, i!(1.0, 3.0), (vec![i!(1.0), i!(2.0)], Trv));
+        test!(f, i!(1.0, 100.0), (vec![i!(1.0, 20.0)], Trv));
+    }
+
     #[test]
     fn one() {
         fn f(x: TupperIntervalSet) -> TupperIntervalSet {
@@ -1666,6 +3500,75 @@ mod tests {
         test!(f, i!(-1.0, 1.0), (vec![i!(1.0)], Com));
     }
 
+    #[test]
+    fn poch() {
+        fn f(x: TupperIntervalSet, y: TupperIntervalSet) -> TupperIntervalSet {
+            x.poch(&y, None)
+        }
+
+        test!(f, i!(2.0), i!(0.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(2.0), i!(3.0), (vec![i!(24.0)], Dac));
+        test!(f, i!(-2.0), i!(3.0), (vec![i!(0.0)], Dac));
+        test!(f, i!(3.0), i!(-2.0), (vec![i!(0.5)], Dac));
+        test!(f, i!(1.0), i!(-1.0), (vec![], Trv));
+        test!(f, i!(-1.0), i!(0.5), (vec![], Trv));
+        test!(f, i!(-1.0), i!(0.0, 1.0), (vec![Interval::ENTIRE], Trv));
+    }
+
+    #[test]
+    fn polygamma() {
+        fn f(n: TupperIntervalSet, x: TupperIntervalSet) -> TupperIntervalSet {
+            n.polygamma(&x, None)
+        }
+
+        test!(f, i!(1.0), i!(-1.0), (vec![], Trv));
+        test!(f, i!(1.0), i!(0.0), (vec![], Trv));
+        test!(f, i!(-1.0), i!(1.0), (vec![], Trv));
+        test!(f, i!(0.5), i!(1.0), (vec![], Trv));
+        test!(
+            f,
+            i!(0.0),
+            i!(1.0),
+            (vec![i!(-0.5772156649015329, -0.5772156649015328)], Com)
+        );
+
+        let check = |n: f64, x: f64, y: f64| {
+            let n = TupperIntervalSet::from(DecInterval::new(interval!(n, n).unwrap()));
+            let x = TupperIntervalSet::from(DecInterval::new(interval!(x, x).unwrap()));
+            let y0 = f(n, x);
+            assert!(y0.iter().all(|y0| y0.x.contains(y) && y0.x.wid() < 1e-12));
+        };
+        // ψ'(1) = π^2 / 6.
+        check(1.0, 1.0, 1.6449340668482264);
+        // ψ''(1) = -2 ζ(3).
+        check(2.0, 1.0, -2.4041138063191885);
+        // ψ'(-1/2) = π^2 / 2 + 4.
+        check(1.0, -0.5, 8.934802200544679);
+        // ψ^(3)(1/2) = π^4.
+        check(3.0, 0.5, 97.40909103400243);
+        // ψ^(4)(100) ≈ 6 / 100^4.
+        check(4.0, 100.0, -6.120999930011997e-8);
+
+        // ψ' ≥ 8 on (-1, 0).
+        let y = f(
+            TupperIntervalSet::from(const_dec_interval!(1.0, 1.0)),
+            TupperIntervalSet::from(const_dec_interval!(-0.75, -0.25)),
+        );
+        assert!(y.iter().all(|y| y.x.inf() >= 8.0));
+        // ψ'' is increasing between consecutive poles.
+        let y = f(
+            TupperIntervalSet::from(const_dec_interval!(2.0, 2.0)),
+            TupperIntervalSet::from(const_dec_interval!(-0.5, 0.5)),
+        );
+        assert_eq!(y.len(), 2);
+        assert!(y
+            .iter()
+            .any(|y| y.x.inf() > -0.83 && y.x.sup() == f64::INFINITY));
+        assert!(y
+            .iter()
+            .any(|y| y.x.inf() == f64::NEG_INFINITY && y.x.sup() < -8.0));
+    }
+
     #[test]
     fn pow() {
         fn f(x: TupperIntervalSet, y: TupperIntervalSet) -> TupperIntervalSet {
@@ -1871,6 +3774,47 @@ mod tests {
         test!(f, @odd i!(2.0, 3.0), (vec![i!(8.0, 27.0)], Com));
     }
 
+    #[test]
+    fn popcount() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.popcount(None)
+        }
+
+        test!(f, i!(0.0), (vec![i!(0.0)], Dac));
+        test!(f, @even i!(7.0), (vec![i!(3.0)], Dac));
+        test!(f, i!(9007199254740992.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(2.5), (vec![], Trv));
+        test!(f, i!(1.0, 3.0), (vec![i!(1.0), i!(2.0)], Trv));
+        test!(f, i!(1.0, 1000.0), (vec![i!(1.0, 10.0)], Trv));
+        test!(
+            f,
+            i!(0.0, f64::INFINITY),
+            (vec![i!(0.0, f64::INFINITY)], Trv)
+        );
+    }
+
+    #[test]
+    fn prime_pi() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.prime_pi(None)
+        }
+
+        test!(f, i!(-1.0), (vec![i!(0.0)], Com));
+        test!(f, i!(1.5), (vec![i!(0.0)], Com));
+        test!(f, i!(2.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(2.5), (vec![i!(1.0)], Com));
+        test!(f, i!(100.0), (vec![i!(25.0)], Com));
+        test!(f, i!(2.0, 2.5), (vec![i!(1.0)], Dac));
+        test!(f, i!(1.5, 2.5), (vec![i!(0.0), i!(1.0)], Def));
+        test!(f, i!(0.0, 100.0), (vec![i!(0.0, 25.0)], Def));
+        test!(f, i!(1048576.0), (vec![i!(82025.0, 94931.0)], Def));
+        test!(
+            f,
+            i!(0.0, f64::INFINITY),
+            (vec![i!(0.0, f64::INFINITY)], Def)
+        );
+    }
+
     #[test]
     fn recip() {
         fn f(x: TupperIntervalSet) -> TupperIntervalSet {
@@ -1892,6 +3836,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rect() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.rect(None)
+        }
+
+        test!(f, i!(0.0), (vec![i!(1.0)], Com));
+        test!(f, @even i!(0.5), (vec![i!(0.5)], Dac));
+        test!(f, @even i!(1.0), (vec![i!(0.0)], Com));
+        test!(f, @even i!(0.0, 1.0), (vec![i!(0.0), i!(0.5, 1.0)], Def));
+        test!(f, @even i!(0.5, 1.0), (vec![i!(0.0), i!(0.5)], Def));
+    }
+
     #[test]
     fn rem_euclid() {
         fn f(x: TupperIntervalSet, y: TupperIntervalSet) -> TupperIntervalSet {
@@ -1943,6 +3900,83 @@ mod tests {
         test!(f, @odd i!(8.0, 27.0), (vec![i!(2.0, 3.0)], Com));
     }
 
+    #[test]
+    fn sawtooth_wave() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.sawtooth_wave(None)
+        }
+
+        test!(f, i!(0.0), (vec![i!(0.0)], Dac));
+        test!(f, @odd i!(0.25), (vec![i!(-0.5)], Com));
+        test!(f, @odd i!(1.0), (vec![i!(0.0)], Dac));
+        test!(f, @odd i!(0.25, 0.5), (vec![i!(-0.5, 0.0)], Com));
+        test!(f, @odd i!(0.0, 0.25), (vec![i!(-1.0, -0.5), i!(0.0)], Def));
+        test!(f, i!(0.75, 1.25), (vec![i!(-1.0, 0.0), i!(0.5, 1.0)], Def));
+        // The value at the jump is included in the piece on the right.
+        test!(
+            f,
+            i!(-1.25, -0.75),
+            (vec![i!(-1.0, 0.0), i!(0.5, 1.0)], Def)
+        );
+        test!(f, i!(0.0, 2.0), (vec![i!(-1.0, 1.0)], Def));
+    }
+
+    #[test]
+    fn sign() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.sign(None)
+        }
+
+        test!(f, i!(0.0), (vec![i!(0.0)], Dac));
+        test!(f, @odd i!(2.0), (vec![i!(1.0)], Com));
+        test!(f, @odd i!(0.0, 1.0), (vec![i!(0.0), i!(1.0)], Def));
+        test!(f, i!(-1.0, 1.0), (vec![i!(-1.0), i!(0.0, 1.0)], Def));
+    }
+
+    #[test]
+    fn sinhc() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.sinhc()
+        }
+
+        test!(f, i!(0.0), (vec![i!(1.0)], Com));
+        test!(f, @even i!(0.0, f64::INFINITY), (vec![i!(1.0, f64::INFINITY)], Dac));
+
+        let y = super::sinhc(i!(-1.0, 0.5));
+        assert_eq!(y.inf(), 1.0);
+        assert!(y.contains(1.0_f64.sinh()) && y.wid() < 0.2);
+        // sinh(x) overflows.
+        let y = super::sinhc(i!(1000.0));
+        assert!(y.inf() > 1e300 && y.sup() == f64::INFINITY);
+    }
+
+    #[test]
+    fn square_wave() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.square_wave(None)
+        }
+
+        test!(f, i!(0.0), (vec![i!(0.0)], Dac));
+        test!(f, @odd i!(0.25), (vec![i!(1.0)], Com));
+        test!(f, @odd i!(0.5), (vec![i!(0.0)], Dac));
+        test!(f, @odd i!(0.75), (vec![i!(-1.0)], Com));
+        test!(f, @odd i!(0.0, 0.25), (vec![i!(0.0), i!(1.0)], Def));
+        test!(f, i!(0.25, 0.75), (vec![i!(-1.0, 0.0), i!(1.0)], Def));
+        test!(f, i!(-0.75, -0.25), (vec![i!(-1.0, 0.0), i!(1.0)], Def));
+        test!(f, i!(0.0, 2.0), (vec![i!(-1.0, 1.0)], Def));
+    }
+
+    #[test]
+    fn tri() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.tri()
+        }
+
+        test!(f, i!(0.0), (vec![i!(1.0)], Com));
+        test!(f, @even i!(0.5), (vec![i!(0.5)], Com));
+        test!(f, @even i!(0.5, 2.0), (vec![i!(0.0, 0.5)], Com));
+    }
+
     #[test]
     fn undef_at_0() {
         fn f(x: TupperIntervalSet) -> TupperIntervalSet {
@@ -1955,6 +3989,21 @@ mod tests {
         test!(f, i!(-1.0, 1.0), (vec![i!(-1.0, 1.0)], Trv));
     }
 
+    #[test]
+    fn totient() {
+        fn f(x: TupperIntervalSet) -> TupperIntervalSet {
+            x.totient(None)
+        }
+
+        test!(f, i!(0.0), (vec![], Trv));
+        test!(f, i!(1.0), (vec![i!(1.0)], Dac));
+        test!(f, i!(1.5), (vec![], Trv));
+        test!(f, i!(12.0), (vec![i!(4.0)], Dac));
+        test!(f, i!(1.0, 2.0), (vec![i!(1.0)], Trv));
+        test!(f, i!(5.0, 6.0), (vec![i!(2.0), i!(4.0)], Trv));
+        test!(f, i!(1.0, 100.0), (vec![i!(1.0, 99.0)], Trv));
+    }
+
     #[test]
     fn ops_sanity() {
         // Check that operations do not panic due to invalid construction of an interval.
@@ -1979,6 +4028,7 @@ mod tests {
             TupperIntervalSet::asinh,
             TupperIntervalSet::atan,
             TupperIntervalSet::atanh,
+            TupperIntervalSet::cbrt,
             TupperIntervalSet::cos,
             TupperIntervalSet::cosh,
             TupperIntervalSet::erf,
@@ -1986,16 +4036,20 @@ mod tests {
             TupperIntervalSet::exp,
             TupperIntervalSet::exp10,
             TupperIntervalSet::exp2,
+            TupperIntervalSet::expm1,
             TupperIntervalSet::ln,
             TupperIntervalSet::log10,
+            TupperIntervalSet::log1p,
             TupperIntervalSet::log2,
             TupperIntervalSet::one,
             TupperIntervalSet::sin,
             TupperIntervalSet::sinc,
             TupperIntervalSet::sinh,
+            TupperIntervalSet::sinhc,
             TupperIntervalSet::sqr,
             TupperIntervalSet::sqrt,
             TupperIntervalSet::tanh,
+            TupperIntervalSet::tri,
             TupperIntervalSet::undef_at_0,
         ];
         for f in &fs {
@@ -2007,10 +4061,23 @@ mod tests {
         let fs = [
             TupperIntervalSet::ceil,
             TupperIntervalSet::digamma,
+            TupperIntervalSet::factorial,
             TupperIntervalSet::floor,
             TupperIntervalSet::gamma,
+            TupperIntervalSet::heaviside,
+            TupperIntervalSet::is_prime,
+            TupperIntervalSet::ln_gamma,
+            TupperIntervalSet::mobius,
+            TupperIntervalSet::num_divisors,
+            TupperIntervalSet::popcount,
+            TupperIntervalSet::prime_pi,
             TupperIntervalSet::recip,
+            TupperIntervalSet::rect,
+            TupperIntervalSet::sawtooth_wave,
+            TupperIntervalSet::sign,
+            TupperIntervalSet::square_wave,
             TupperIntervalSet::tan,
+            TupperIntervalSet::totient,
         ];
         for f in &fs {
             for x in &xs {
@@ -2022,6 +4089,12 @@ mod tests {
             |x: &_, y: &_| x + y,
             |x: &_, y: &_| x - y,
             |x: &_, y: &_| x * y,
+            TupperIntervalSet::chebyshev_t,
+            TupperIntervalSet::chebyshev_u,
+            TupperIntervalSet::hermite_h,
+            TupperIntervalSet::hypot,
+            TupperIntervalSet::laguerre_l,
+            TupperIntervalSet::legendre_p,
             TupperIntervalSet::max,
             TupperIntervalSet::min,
         ];
@@ -2035,10 +4108,18 @@ mod tests {
 
         let fs = [
             TupperIntervalSet::atan2,
+            TupperIntervalSet::beta,
+            TupperIntervalSet::binom,
+            TupperIntervalSet::bit_and,
+            TupperIntervalSet::bit_or,
+            TupperIntervalSet::bit_xor,
             TupperIntervalSet::div,
+            TupperIntervalSet::divisor_sigma,
             TupperIntervalSet::gcd,
             TupperIntervalSet::lcm,
             TupperIntervalSet::log,
+            TupperIntervalSet::poch,
+            TupperIntervalSet::polygamma,
             TupperIntervalSet::pow,
             TupperIntervalSet::rem_euclid,
         ];
@@ -2050,7 +4131,11 @@ mod tests {
             }
         }
 
-        let fs = [TupperIntervalSet::mul_add];
+        let fs = [
+            TupperIntervalSet::assoc_legendre_p,
+            TupperIntervalSet::clamp,
+            TupperIntervalSet::mul_add,
+        ];
         for f in &fs {
             for x in &xs {
                 for y in &xs {
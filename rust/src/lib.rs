@@ -1,9 +1,11 @@
 #![allow(clippy::float_cmp)]
-#![feature(box_patterns, box_syntax, once_cell)]
+#![feature(box_patterns, box_syntax, once_cell, scoped_threads)]
 
+pub mod expression;
 pub mod graph;
 pub mod relation;
 
+mod affine_form;
 #[cfg(feature = "arb")]
 mod arb;
 #[cfg(feature = "arb")]
@@ -17,7 +19,12 @@ mod eval_result;
 mod image;
 mod interval_set;
 mod interval_set_ops;
+mod mp_interval;
+mod number_theory;
 mod ops;
 mod parse;
 mod rational_ops;
+#[cfg(not(feature = "arb"))]
+mod special_functions;
+mod taylor_model;
 mod visit;
@@ -0,0 +1,927 @@
+use crate::interval_set::TupperIntervalSet;
+use inari::{interval, DecInterval, Decoration, Interval};
+use rug::{
+    float::{Constant, Round, Special},
+    ops::Pow,
+    Float, Integer, Rational,
+};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// An interval with multiple-precision endpoints and a decoration,
+/// which is used for evaluating relations beyond the precision of [`f64`].
+///
+/// Unlike [`TupperIntervalSet`], it can represent only a single interval.
+/// Results that consist of several intervals are replaced with their convex hull,
+/// whose decoration is weakened to [`Decoration::Def`] at most.
+///
+/// An empty interval is represented by NaN endpoints.
+#[derive(Clone, Debug)]
+pub struct MpInterval {
+    inf: Float,
+    sup: Float,
+    d: Decoration,
+}
+
+macro_rules! impl_increasing {
+    ($f:ident, $f_round:ident) => {
+        pub fn $f(&self) -> Self {
+            if self.is_empty() {
+                return self.clone();
+            }
+            let mut a = self.inf.clone();
+            a.$f_round(Round::Down);
+            let mut b = self.sup.clone();
+            b.$f_round(Round::Up);
+            Self::set_dec(a, b, self.d)
+        }
+    };
+}
+
+// The natural domain of the function is [a, b].
+macro_rules! impl_increasing_on {
+    ($f:ident, $f_round:ident, $a:expr, $b:expr) => {
+        pub fn $f(&self) -> Self {
+            match self.restrict($a, $b) {
+                Some((mut a, mut b, d)) => {
+                    a.$f_round(Round::Down);
+                    b.$f_round(Round::Up);
+                    Self::set_dec(a, b, d)
+                }
+                _ => Self::empty(self.prec()),
+            }
+        }
+    };
+}
+
+// The natural domain of the function is (a, +∞], where a = 0 unless specified.
+macro_rules! impl_log {
+    ($f:ident, $f_round:ident) => {
+        impl_log!($f, $f_round, 0);
+    };
+
+    ($f:ident, $f_round:ident, $a:expr) => {
+        pub fn $f(&self) -> Self {
+            if self.is_empty() || self.sup <= $a {
+                return Self::empty(self.prec());
+            }
+            let mut b = self.sup.clone();
+            b.$f_round(Round::Up);
+            if self.inf <= $a {
+                Self::set_dec(
+                    Float::with_val(self.prec(), Special::NegInfinity),
+                    b,
+                    Decoration::Trv,
+                )
+            } else {
+                let mut a = self.inf.clone();
+                a.$f_round(Round::Down);
+                Self::set_dec(a, b, self.d)
+            }
+        }
+    };
+}
+
+impl MpInterval {
+    /// Creates a new [`MpInterval`] with the given bounds and decoration.
+    ///
+    /// Panics if `inf > sup` or either of the bounds is NaN.
+    pub fn new(inf: Float, sup: Float, d: Decoration) -> Self {
+        assert!(inf <= sup);
+        Self::set_dec(inf, sup, d)
+    }
+
+    /// Creates an empty [`MpInterval`] with the given precision.
+    pub fn empty(prec: u32) -> Self {
+        Self {
+            inf: Float::with_val(prec, Special::Nan),
+            sup: Float::with_val(prec, Special::Nan),
+            d: Decoration::Trv,
+        }
+    }
+
+    /// Creates an [`MpInterval`] that encloses the given [`DecInterval`].
+    pub fn from_dec_interval(x: DecInterval, prec: u32) -> Self {
+        match (x.inf(), x.sup(), x.decoration()) {
+            (_, _, Decoration::Ill) => panic!("the interval must not be NaI"),
+            (a, b, d) if a <= b => Self::set_dec(
+                Float::with_val_round(prec, a, Round::Down).0,
+                Float::with_val_round(prec, b, Round::Up).0,
+                d,
+            ),
+            _ => Self::empty(prec),
+        }
+    }
+
+    /// Creates an [`MpInterval`] that encloses the given [`Rational`].
+    pub fn from_rational(x: &Rational, prec: u32) -> Self {
+        Self::set_dec(
+            Float::with_val_round(prec, x, Round::Down).0,
+            Float::with_val_round(prec, x, Round::Up).0,
+            Decoration::Com,
+        )
+    }
+
+    /// Creates an [`MpInterval`] that encloses the given constant.
+    ///
+    /// The constants π and e, which are identified by their enclosures in [`f64`],
+    /// are computed with the precision `prec`. Other irrational constants, including
+    /// the ones folded from expressions such as 2π, have the precision of [`f64`].
+    pub fn from_constant(x: &TupperIntervalSet, prec: u32) -> Self {
+        let d = x.decoration();
+        match x.single().map(|x| x.x) {
+            Some(x) if x == Interval::PI => Self::set_dec(
+                Float::with_val_round(prec, Constant::Pi, Round::Down).0,
+                Float::with_val_round(prec, Constant::Pi, Round::Up).0,
+                d,
+            ),
+            Some(x) if x == Interval::E => {
+                let mut a = Float::with_val(prec, 1);
+                a.exp_round(Round::Down);
+                let mut b = Float::with_val(prec, 1);
+                b.exp_round(Round::Up);
+                Self::set_dec(a, b, d)
+            }
+            _ => Self::from_tupper_interval_set(x, prec),
+        }
+    }
+
+    /// Creates an [`MpInterval`] that encloses all intervals in the given [`TupperIntervalSet`].
+    pub fn from_tupper_interval_set(xs: &TupperIntervalSet, prec: u32) -> Self {
+        let hull = xs
+            .iter()
+            .fold(Interval::EMPTY, |hull, x| hull.convex_hull(x.x));
+        let d = if xs.len() > 1 {
+            xs.decoration().min(Decoration::Def)
+        } else {
+            xs.decoration()
+        };
+        Self::from_dec_interval(DecInterval::set_dec(hull, d), prec)
+    }
+
+    /// Creates a singleton [`MpInterval`].
+    ///
+    /// Panics if `x` is infinite or NaN.
+    pub fn point(x: Float) -> Self {
+        assert!(x.is_finite());
+        Self::set_dec(x.clone(), x, Decoration::Com)
+    }
+
+    /// Returns the decoration of the interval.
+    pub fn decoration(&self) -> Decoration {
+        self.d
+    }
+
+    /// Returns the lower bound of the interval.
+    ///
+    /// Panics if the interval is empty.
+    pub fn inf(&self) -> &Float {
+        assert!(!self.is_empty());
+        &self.inf
+    }
+
+    /// Returns `true` if the interval is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inf.is_nan()
+    }
+
+    /// Returns the intersection of the intervals, whose decoration is [`Decoration::Trv`].
+    pub fn intersection(&self, rhs: &Self) -> Self {
+        if self.is_empty() || rhs.is_empty() {
+            return Self::empty(self.prec());
+        }
+        let a = Float::with_val(self.prec(), self.inf.max_ref(&rhs.inf));
+        let b = Float::with_val(self.prec(), self.sup.min_ref(&rhs.sup));
+        if a <= b {
+            Self::set_dec(a, b, Decoration::Trv)
+        } else {
+            Self::empty(self.prec())
+        }
+    }
+
+    /// Returns a number in the interval that is close to the midpoint.
+    ///
+    /// Panics if the interval is empty.
+    pub fn mid(&self) -> Float {
+        assert!(!self.is_empty());
+        match (self.inf.is_infinite(), self.sup.is_infinite()) {
+            (true, true) => Float::new(self.prec()),
+            (true, false) => self.sup.clone().min(&Float::with_val(self.prec(), 0)),
+            (false, true) => self.inf.clone().max(&Float::with_val(self.prec(), 0)),
+            _ => Float::with_val(self.prec(), &self.inf + &self.sup) / 2,
+        }
+    }
+
+    /// Returns the precision of the bounds.
+    pub fn prec(&self) -> u32 {
+        self.inf.prec()
+    }
+
+    /// Returns the upper bound of the interval.
+    ///
+    /// Panics if the interval is empty.
+    pub fn sup(&self) -> &Float {
+        assert!(!self.is_empty());
+        &self.sup
+    }
+
+    /// Returns the smallest [`DecInterval`] that contains the interval.
+    pub fn to_dec_interval(&self) -> DecInterval {
+        if self.is_empty() {
+            return DecInterval::EMPTY;
+        }
+        let a = self.inf.to_f64_round(Round::Down);
+        let b = self.sup.to_f64_round(Round::Up);
+        DecInterval::set_dec(interval!(a, b).unwrap(), self.d)
+    }
+
+    /// Returns the smallest [`TupperIntervalSet`] that contains the interval.
+    pub fn to_tupper_interval_set(&self) -> TupperIntervalSet {
+        self.to_dec_interval().into()
+    }
+
+    /// Returns the smallest interval with the precision `prec` that contains the interval.
+    pub fn with_prec(&self, prec: u32) -> Self {
+        if self.is_empty() {
+            return Self::empty(prec);
+        }
+        Self {
+            inf: Float::with_val_round(prec, &self.inf, Round::Down).0,
+            sup: Float::with_val_round(prec, &self.sup, Round::Up).0,
+            d: self.d,
+        }
+    }
+
+    pub fn abs(&self) -> Self {
+        if self.is_empty() || self.inf >= 0 {
+            self.clone()
+        } else if self.sup <= 0 {
+            -self
+        } else {
+            let b = Float::with_val(self.prec(), (-self.inf.clone()).max(&self.sup));
+            Self::set_dec(Float::new(self.prec()), b, self.d)
+        }
+    }
+
+    // The natural domain of the function is [-1, 1].
+    pub fn acos(&self) -> Self {
+        match self.restrict(-1.0, 1.0) {
+            Some((mut b, mut a, d)) => {
+                a.acos_round(Round::Down);
+                b.acos_round(Round::Up);
+                Self::set_dec(a, b, d)
+            }
+            _ => Self::empty(self.prec()),
+        }
+    }
+
+    // The natural domain of the function is (-1, 1).
+    pub fn atanh(&self) -> Self {
+        match self.restrict(-1.0, 1.0) {
+            Some((mut a, mut b, d)) if a < 1 && b > -1 => {
+                let d = if self.inf > -1 && self.sup < 1 {
+                    d
+                } else {
+                    Decoration::Trv
+                };
+                a.atanh_round(Round::Down);
+                b.atanh_round(Round::Up);
+                Self::set_dec(a, b, d)
+            }
+            _ => Self::empty(self.prec()),
+        }
+    }
+
+    // Returns `None` if the region is unbounded or intersects with the branch cut.
+    // Otherwise, the extrema are taken at the corners of the region.
+    pub fn atan2(&self, rhs: &Self) -> Option<Self> {
+        let (y, x) = (self, rhs);
+        if y.is_empty() || x.is_empty() {
+            return Some(Self::empty(self.prec()));
+        }
+        if [&y.inf, &y.sup, &x.inf, &x.sup]
+            .iter()
+            .any(|z| z.is_infinite())
+            || x.inf <= 0 && y.inf <= 0 && y.sup >= 0
+        {
+            return None;
+        }
+
+        let prec = self.prec();
+        let mut a = Float::with_val(prec, Special::Infinity);
+        let mut b = Float::with_val(prec, Special::NegInfinity);
+        for yy in &[&y.inf, &y.sup] {
+            for xx in &[&x.inf, &x.sup] {
+                let mut z = Float::with_val(prec, *yy);
+                z.atan2_round(xx, Round::Down);
+                a = a.min(&z);
+                let mut z = Float::with_val(prec, *yy);
+                z.atan2_round(xx, Round::Up);
+                b = b.max(&z);
+            }
+        }
+        Some(Self::set_dec(a, b, x.d.min(y.d)))
+    }
+
+    pub fn ceil(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let a = self.inf.clone().ceil();
+        let b = self.sup.clone().ceil();
+        // The function is discontinuous at integers.
+        let d = if a != b {
+            Decoration::Def
+        } else if self.sup == b {
+            Decoration::Dac
+        } else {
+            Decoration::Com
+        };
+        Self::set_dec(a, b, d.min(self.d))
+    }
+
+    pub fn cos(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let zero = Float::with_val(self.prec(), 0);
+        self.sin_cos_impl(&zero, Self::cos_round)
+    }
+
+    pub fn cosh(&self) -> Self {
+        let x = self.abs();
+        if x.is_empty() {
+            return x;
+        }
+        let mut a = x.inf;
+        a.cosh_round(Round::Down);
+        let mut b = x.sup;
+        b.cosh_round(Round::Up);
+        Self::set_dec(a, b, x.d)
+    }
+
+    // Valid only if the divisor does not contain zero,
+    // since the result can consist of two intervals otherwise.
+    pub fn div(&self, rhs: &Self) -> Option<Self> {
+        if self.is_empty() || rhs.is_empty() {
+            return Some(Self::empty(self.prec()));
+        }
+        if rhs.inf <= 0 && rhs.sup >= 0 {
+            return None;
+        }
+        Some(self.corners(rhs, |x, y, rnd| {
+            Float::with_val_round(x.prec().max(y.prec()), x / y, rnd).0
+        }))
+    }
+
+    pub fn erfc(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let mut a = self.sup.clone();
+        a.erfc_round(Round::Down);
+        let mut b = self.inf.clone();
+        b.erfc_round(Round::Up);
+        Self::set_dec(a, b, self.d)
+    }
+
+    pub fn floor(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let a = self.inf.clone().floor();
+        let b = self.sup.clone().floor();
+        // The function is discontinuous at integers.
+        let d = if a != b {
+            Decoration::Def
+        } else if self.inf == a {
+            Decoration::Dac
+        } else {
+            Decoration::Com
+        };
+        Self::set_dec(a, b, d.min(self.d))
+    }
+
+    pub fn hypot(&self, rhs: &Self) -> Self {
+        if self.is_empty() || rhs.is_empty() {
+            return Self::empty(self.prec().min(rhs.prec()));
+        }
+        let x = self.abs();
+        let y = rhs.abs();
+        let mut a = x.inf;
+        a.hypot_round(&y.inf, Round::Down);
+        let mut b = x.sup;
+        b.hypot_round(&y.sup, Round::Up);
+        Self::set_dec(a, b, self.d.min(rhs.d))
+    }
+
+    pub fn max(&self, rhs: &Self) -> Self {
+        if self.is_empty() || rhs.is_empty() {
+            return Self::empty(self.prec());
+        }
+        Self::set_dec(
+            Float::with_val(self.prec(), self.inf.max_ref(&rhs.inf)),
+            Float::with_val(self.prec(), self.sup.max_ref(&rhs.sup)),
+            self.d.min(rhs.d),
+        )
+    }
+
+    pub fn min(&self, rhs: &Self) -> Self {
+        if self.is_empty() || rhs.is_empty() {
+            return Self::empty(self.prec());
+        }
+        Self::set_dec(
+            Float::with_val(self.prec(), self.inf.min_ref(&rhs.inf)),
+            Float::with_val(self.prec(), self.sup.min_ref(&rhs.sup)),
+            self.d.min(rhs.d),
+        )
+    }
+
+    pub fn mul_add(&self, rhs: &Self, addend: &Self) -> Self {
+        &(self * rhs) + addend
+    }
+
+    pub fn one(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let one = Float::with_val(self.prec(), 1);
+        Self::set_dec(one.clone(), one, self.d)
+    }
+
+    // Valid only if the base is positive, since x^y for x ≤ 0 is defined only for some y.
+    pub fn pow(&self, rhs: &Self) -> Option<Self> {
+        if self.is_empty() || rhs.is_empty() {
+            return Some(Self::empty(self.prec().max(rhs.prec())));
+        }
+        if self.inf <= 0 {
+            return None;
+        }
+        // x^y is monotone in each of x and y for x > 0.
+        Some(self.corners(rhs, |x, y, rnd| {
+            Float::with_val_round(x.prec().max(y.prec()), x.pow(y), rnd).0
+        }))
+    }
+
+    // Valid only if `n ≥ 0` or the interval does not contain zero.
+    pub fn pown(&self, n: i32) -> Option<Self> {
+        if self.is_empty() {
+            return Some(self.clone());
+        }
+        let prec = self.prec();
+        let pow = |x: &Float, rnd| Float::with_val_round(prec, x.pow(n), rnd).0;
+        if n == 0 {
+            return Some(self.one());
+        }
+        if n < 0 && self.inf <= 0 && self.sup >= 0 {
+            return None;
+        }
+        Some(if n % 2 != 0 {
+            if n > 0 {
+                Self::set_dec(
+                    pow(&self.inf, Round::Down),
+                    pow(&self.sup, Round::Up),
+                    self.d,
+                )
+            } else {
+                Self::set_dec(
+                    pow(&self.sup, Round::Down),
+                    pow(&self.inf, Round::Up),
+                    self.d,
+                )
+            }
+        } else {
+            let x = self.abs();
+            if n > 0 {
+                Self::set_dec(pow(&x.inf, Round::Down), pow(&x.sup, Round::Up), x.d)
+            } else {
+                Self::set_dec(pow(&x.sup, Round::Down), pow(&x.inf, Round::Up), x.d)
+            }
+        })
+    }
+
+    pub fn recip(&self) -> Option<Self> {
+        self.pown(-1)
+    }
+
+    // The natural domain of the function is [0, +∞] if `n` is even.
+    pub fn rootn(&self, n: u32) -> Self {
+        let (mut a, mut b, d) = match n {
+            0 => return Self::empty(self.prec()),
+            _ if n % 2 == 1 => {
+                if self.is_empty() {
+                    return self.clone();
+                }
+                (self.inf.clone(), self.sup.clone(), self.d)
+            }
+            _ => match self.restrict(0.0, f64::INFINITY) {
+                Some(x) => x,
+                _ => return Self::empty(self.prec()),
+            },
+        };
+        a.root_round(n, Round::Down);
+        b.root_round(n, Round::Up);
+        Self::set_dec(a, b, d)
+    }
+
+    pub fn sin(&self) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let half = Float::with_val(self.prec(), 0.5);
+        self.sin_cos_impl(&half, Self::sin_round)
+    }
+
+    pub fn sqr(&self) -> Self {
+        self.pown(2).unwrap()
+    }
+
+    // The natural domain of the function is [0, +∞].
+    pub fn sqrt(&self) -> Self {
+        if self.is_empty() || self.sup < 0 {
+            return Self::empty(self.prec());
+        }
+        let mut b = self.sup.clone();
+        b.sqrt_round(Round::Up);
+        if self.inf < 0 {
+            Self::set_dec(Float::new(self.prec()), b, Decoration::Trv)
+        } else {
+            let mut a = self.inf.clone();
+            a.sqrt_round(Round::Down);
+            Self::set_dec(a, b, self.d)
+        }
+    }
+
+    // Returns `None` if the interval is unbounded or can contain a pole,
+    // since the result can consist of two intervals.
+    pub fn tan(&self) -> Option<Self> {
+        if self.is_empty() {
+            return Some(self.clone());
+        }
+        if self.inf.is_infinite() || self.sup.is_infinite() {
+            return None;
+        }
+        // tan has poles at `(n + 1/2) π`.
+        let half = Float::with_val(self.prec(), 0.5);
+        let (n_lo, n_hi) = self.pi_multiples(&half);
+        if n_lo <= n_hi {
+            return None;
+        }
+        let mut a = self.inf.clone();
+        a.tan_round(Round::Down);
+        let mut b = self.sup.clone();
+        b.tan_round(Round::Up);
+        Some(Self::set_dec(a, b, self.d))
+    }
+
+    pub fn undef_at_0(&self) -> Self {
+        if self.is_empty() || self.inf == 0 && self.sup == 0 {
+            Self::empty(self.prec())
+        } else if self.inf <= 0 && self.sup >= 0 {
+            Self {
+                d: Decoration::Trv,
+                ..self.clone()
+            }
+        } else {
+            self.clone()
+        }
+    }
+
+    impl_increasing!(asinh, asinh_round);
+    impl_increasing!(atan, atan_round);
+    impl_increasing!(cbrt, cbrt_round);
+    impl_increasing!(erf, erf_round);
+    impl_increasing!(exp, exp_round);
+    impl_increasing!(exp10, exp10_round);
+    impl_increasing!(exp2, exp2_round);
+    impl_increasing!(expm1, exp_m1_round);
+    impl_increasing!(sinh, sinh_round);
+    impl_increasing!(tanh, tanh_round);
+    impl_log!(ln, ln_round);
+    impl_log!(log10, log10_round);
+    impl_increasing_on!(acosh, acosh_round, 1.0, f64::INFINITY);
+    impl_increasing_on!(asin, asin_round, -1.0, 1.0);
+    impl_log!(log1p, ln_1p_round, -1);
+
+    /// Returns the hull of the values of `f` at the corners of `self × rhs`.
+    fn corners<F>(&self, rhs: &Self, f: F) -> Self
+    where
+        F: Fn(&Float, &Float, Round) -> Float,
+    {
+        let prec = self.prec().min(rhs.prec());
+        let mut a = Float::with_val(prec, Special::Infinity);
+        let mut b = Float::with_val(prec, Special::NegInfinity);
+        for x in &[&self.inf, &self.sup] {
+            for y in &[&rhs.inf, &rhs.sup] {
+                // Skip indeterminate forms such as ∞/∞, which are bounded by the other corners.
+                let z = f(x, y, Round::Down);
+                if !z.is_nan() {
+                    a = a.min(&Float::with_val_round(prec, z, Round::Down).0);
+                }
+                let z = f(x, y, Round::Up);
+                if !z.is_nan() {
+                    b = b.max(&Float::with_val_round(prec, z, Round::Up).0);
+                }
+            }
+        }
+        Self::set_dec(a, b, self.d.min(rhs.d))
+    }
+
+    fn cos_round(x: &mut Float, rnd: Round) {
+        x.cos_round(rnd);
+    }
+
+    /// Returns the range of the integers n that satisfy `(n + offset) π ∈ self`,
+    /// which can contain extra integers at both ends.
+    ///
+    /// Precondition: the interval is nonempty and bounded.
+    fn pi_multiples(&self, offset: &Float) -> (Integer, Integer) {
+        let prec = self.prec() + 16;
+        let pi_lo = Float::with_val_round(prec, Constant::Pi, Round::Down).0;
+        let pi_hi = Float::with_val_round(prec, Constant::Pi, Round::Up).0;
+        let mut lo = if self.inf >= 0 {
+            Float::with_val_round(prec, &self.inf / &pi_hi, Round::Down).0
+        } else {
+            Float::with_val_round(prec, &self.inf / &pi_lo, Round::Down).0
+        };
+        lo = Float::with_val_round(prec, &lo - offset, Round::Down).0;
+        let mut hi = if self.sup >= 0 {
+            Float::with_val_round(prec, &self.sup / &pi_lo, Round::Up).0
+        } else {
+            Float::with_val_round(prec, &self.sup / &pi_hi, Round::Up).0
+        };
+        hi = Float::with_val_round(prec, &hi - offset, Round::Up).0;
+        (
+            lo.ceil().to_integer().unwrap(),
+            hi.floor().to_integer().unwrap(),
+        )
+    }
+
+    /// Returns the bounds of the intersection of the interval and [a, b], along with
+    /// the decoration of a function whose natural domain is [a, b] evaluated over the interval.
+    /// Returns [`None`] if the intersection is empty.
+    fn restrict(&self, a: f64, b: f64) -> Option<(Float, Float, Decoration)> {
+        if self.is_empty() || self.sup < a || self.inf > b {
+            return None;
+        }
+        let prec = self.prec();
+        let d = if self.inf >= a && self.sup <= b {
+            self.d
+        } else {
+            Decoration::Trv
+        };
+        Some((
+            Float::with_val(prec, self.inf.max_ref(&Float::with_val(prec, a))),
+            Float::with_val(prec, self.sup.min_ref(&Float::with_val(prec, b))),
+            d,
+        ))
+    }
+
+    fn set_dec(inf: Float, sup: Float, d: Decoration) -> Self {
+        let d = if d == Decoration::Com && (inf.is_infinite() || sup.is_infinite()) {
+            Decoration::Dac
+        } else {
+            d
+        };
+        Self { inf, sup, d }
+    }
+
+    // sin and cos take the extrema 1 and -1 at `(n + offset) π`, where n is even and odd, respectively.
+    fn sin_cos_impl(&self, offset: &Float, f_round: fn(&mut Float, Round)) -> Self {
+        let prec = self.prec();
+        if self.inf.is_infinite() || self.sup.is_infinite() {
+            return Self::set_dec(Float::with_val(prec, -1), Float::with_val(prec, 1), self.d);
+        }
+
+        let (n_lo, n_hi) = self.pi_multiples(offset);
+        let mut a = self.inf.clone();
+        f_round(&mut a, Round::Down);
+        let mut a2 = self.sup.clone();
+        f_round(&mut a2, Round::Down);
+        let mut b = self.inf.clone();
+        f_round(&mut b, Round::Up);
+        let mut b2 = self.sup.clone();
+        f_round(&mut b2, Round::Up);
+        let mut a = a.min(&a2);
+        let mut b = b.max(&b2);
+        if n_lo < n_hi {
+            a = Float::with_val(prec, -1);
+            b = Float::with_val(prec, 1);
+        } else if n_lo == n_hi {
+            if n_lo.is_even() {
+                b = Float::with_val(prec, 1);
+            } else {
+                a = Float::with_val(prec, -1);
+            }
+        }
+        Self::set_dec(a, b, self.d)
+    }
+
+    fn sin_round(x: &mut Float, rnd: Round) {
+        x.sin_round(rnd);
+    }
+}
+
+impl Neg for &MpInterval {
+    type Output = MpInterval;
+
+    fn neg(self) -> MpInterval {
+        if self.is_empty() {
+            return self.clone();
+        }
+        MpInterval::set_dec(-self.sup.clone(), -self.inf.clone(), self.d)
+    }
+}
+
+impl Add for &MpInterval {
+    type Output = MpInterval;
+
+    fn add(self, rhs: Self) -> MpInterval {
+        let prec = self.prec().max(rhs.prec());
+        if self.is_empty() || rhs.is_empty() {
+            return MpInterval::empty(prec);
+        }
+        MpInterval::set_dec(
+            Float::with_val_round(prec, &self.inf + &rhs.inf, Round::Down).0,
+            Float::with_val_round(prec, &self.sup + &rhs.sup, Round::Up).0,
+            self.d.min(rhs.d),
+        )
+    }
+}
+
+impl Sub for &MpInterval {
+    type Output = MpInterval;
+
+    fn sub(self, rhs: Self) -> MpInterval {
+        let prec = self.prec().max(rhs.prec());
+        if self.is_empty() || rhs.is_empty() {
+            return MpInterval::empty(prec);
+        }
+        MpInterval::set_dec(
+            Float::with_val_round(prec, &self.inf - &rhs.sup, Round::Down).0,
+            Float::with_val_round(prec, &self.sup - &rhs.inf, Round::Up).0,
+            self.d.min(rhs.d),
+        )
+    }
+}
+
+impl Mul for &MpInterval {
+    type Output = MpInterval;
+
+    fn mul(self, rhs: Self) -> MpInterval {
+        if self.is_empty() || rhs.is_empty() {
+            return MpInterval::empty(self.prec().max(rhs.prec()));
+        }
+        self.corners(rhs, |x, y, rnd| {
+            let prec = x.prec().max(y.prec());
+            if x.is_zero() || y.is_zero() {
+                // 0 × ∞ is bounded by the other corners.
+                Float::new(prec)
+            } else {
+                Float::with_val_round(prec, x * y, rnd).0
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use inari::const_dec_interval;
+
+    const PREC: u32 = 256;
+
+    fn r(s: &str) -> Rational {
+        s.parse::<Rational>().unwrap()
+    }
+
+    fn contains(x: &MpInterval, y: &Rational) -> bool {
+        x.inf() <= y && x.sup() >= y
+    }
+
+    fn width(x: &MpInterval) -> Float {
+        Float::with_val(PREC, x.sup() - x.inf())
+    }
+
+    #[test]
+    fn arithmetic() {
+        let x = MpInterval::from_rational(&r("1/10"), PREC);
+        let y = MpInterval::from_rational(&r("-3/7"), PREC);
+        assert!(contains(&(&x + &y), &r("-23/70")));
+        assert!(contains(&(&x - &y), &r("37/70")));
+        assert!(contains(&(&x * &y), &r("-3/70")));
+        assert!(contains(&x.div(&y).unwrap(), &r("-7/30")));
+        assert!(contains(&y.pown(-3).unwrap(), &r("-343/27")));
+        assert!(contains(&y.sqr(), &r("9/49")));
+        assert!(width(&(&x * &y)) < 1e-70);
+        assert_eq!((&x * &y).decoration(), Decoration::Com);
+
+        let z = MpInterval::from_dec_interval(const_dec_interval!(-1.0, 2.0), PREC);
+        assert!(x.div(&z).is_none());
+        assert!(z.recip().is_none());
+        assert_eq!(z.sqr().inf(), &0);
+        assert_eq!(z.sqr().sup(), &4);
+    }
+
+    #[test]
+    fn elementary() {
+        let x = MpInterval::from_dec_interval(const_dec_interval!(-1.0, 2.0), PREC);
+        assert_eq!(x.sqrt().decoration(), Decoration::Trv);
+        assert_eq!(x.ln().decoration(), Decoration::Trv);
+        assert!(x.ln().inf().is_infinite());
+        assert_eq!(x.log1p().decoration(), Decoration::Trv);
+        assert!(x.log1p().inf().is_infinite());
+        assert!(x.undef_at_0().decoration() == Decoration::Trv);
+
+        // sin(x) near x = π.
+        let pi = Float::with_val(PREC, Constant::Pi);
+        let x = MpInterval::from_rational(&pi.to_rational().unwrap(), PREC);
+        let y = x.sin();
+        assert!(y.inf().is_sign_positive() && y.sup() < &1e-75);
+        assert_eq!(x.cos().inf(), &-1);
+
+        let x = MpInterval::from_dec_interval(const_dec_interval!(1.0, 2.0), PREC);
+        assert_eq!(x.sin().sup(), &1);
+        assert!(x.sin().inf() > &0.84);
+        assert!(x.cos().inf() < &-0.41 && x.cos().sup() > &0.54 && x.cos().sup() < &0.55);
+
+        let y = MpInterval::from_dec_interval(const_dec_interval!(-1.0, 1.0), PREC);
+        let t = y.atan2(&x).unwrap();
+        assert!(t.inf() < &-0.78 && t.sup() > &0.78 && t.sup() < &0.79);
+        assert!(x.atan2(&y).is_some());
+        assert!(y.atan2(&-&x).is_none());
+        let h = x.hypot(&y);
+        assert!(h.inf() == &1 && h.sup() > &2.236 && h.sup() < &2.237);
+
+        let x = MpInterval::from_rational(&r("1/2"), PREC);
+        assert!(width(&x.tan().unwrap()) < 1e-75);
+        assert!(x.tan().unwrap().inf() > &0.546 && x.tan().unwrap().sup() < &0.547);
+        let y = MpInterval::from_dec_interval(const_dec_interval!(1.0, 2.0), PREC);
+        assert!(y.tan().is_none());
+        assert!(x.pow(&y).unwrap().inf() == &0.25 && x.pow(&y).unwrap().sup() == &0.5);
+        assert!(x.pow(&-&y).unwrap().inf() == &2 && x.pow(&-&y).unwrap().sup() == &4);
+        assert!((-&x).pow(&y).is_none());
+        assert_eq!(x.rootn(2).decoration(), Decoration::Com);
+        assert!(width(&x.rootn(2)) < 1e-75);
+        assert_eq!((-&x).rootn(3).sup(), &-x.rootn(3).inf().clone());
+        assert!((-&x).rootn(2).is_empty());
+
+        let x = MpInterval::from_dec_interval(const_dec_interval!(-2.0, 0.5), PREC);
+        assert_eq!(x.asin().decoration(), Decoration::Trv);
+        assert!(x.asin().inf() < &-1.57 && x.asin().sup() > &0.52 && x.asin().sup() < &0.53);
+        assert!(x.acos().inf() > &1.04 && x.acos().inf() < &1.05 && x.acos().sup() > &3.1);
+        assert!(x.atanh().inf().is_infinite() && x.atanh().sup() < &0.55);
+        assert!(x.acosh().is_empty());
+        assert!(MpInterval::from_rational(&r("1"), PREC).atanh().is_empty());
+        assert_eq!(x.floor().inf(), &-2);
+        assert_eq!(x.floor().decoration(), Decoration::Def);
+        assert_eq!(x.ceil().sup(), &1);
+        let x = MpInterval::from_rational(&r("1/3"), PREC);
+        assert_eq!(x.floor().decoration(), Decoration::Com);
+        assert!(contains(&(&x.erf() + &x.erfc()), &r("1")));
+    }
+
+    #[test]
+    fn constants() {
+        let pi = Float::with_val(2 * PREC, Constant::Pi);
+        let x = MpInterval::from_constant(&TupperIntervalSet::from(DecInterval::PI), PREC);
+        assert!(x.inf() < &pi && x.sup() > &pi && width(&x) < 1e-75);
+        let e = Float::with_val(2 * PREC, 1).exp();
+        let x = MpInterval::from_constant(&TupperIntervalSet::from(DecInterval::E), PREC);
+        assert!(x.inf() < &e && x.sup() > &e && width(&x) < 1e-75);
+        let x = MpInterval::from_constant(
+            &TupperIntervalSet::from(const_dec_interval!(1.0, 2.0)),
+            PREC,
+        );
+        assert!(x.inf() == &1 && x.sup() == &2);
+    }
+
+    #[test]
+    fn conversion() {
+        let x = MpInterval::from_rational(&r("1/3"), PREC);
+        let y = x.to_dec_interval();
+        assert!(y.inf() < 1.0 / 3.0 + 1e-16 && y.sup() > 1.0 / 3.0 - 1e-16);
+        assert!(y.inf() < y.sup());
+
+        let mut xs = TupperIntervalSet::from(const_dec_interval!(0.0, 1.0));
+        xs.insert(
+            TupperIntervalSet::from(const_dec_interval!(2.0, 3.0))
+                .iter()
+                .copied()
+                .next()
+                .unwrap(),
+        );
+        let x = MpInterval::from_tupper_interval_set(&xs, PREC);
+        assert_eq!(x.inf(), &0);
+        assert_eq!(x.sup(), &3);
+        assert_eq!(x.decoration(), Decoration::Def);
+
+        assert!(MpInterval::from_tupper_interval_set(&TupperIntervalSet::new(), PREC).is_empty());
+
+        let x = MpInterval::from_rational(&r("1/3"), PREC);
+        let y = x.with_prec(2 * PREC);
+        assert_eq!((y.prec(), y.inf(), y.sup()), (2 * PREC, x.inf(), x.sup()));
+        let y = x.with_prec(PREC / 2);
+        assert!(y.inf() < x.inf() && y.sup() > x.sup());
+        assert!(MpInterval::empty(PREC).with_prec(2 * PREC).is_empty());
+    }
+}
@@ -0,0 +1,244 @@
+use itertools::Itertools;
+use smallvec::SmallVec;
+use std::lazy::SyncLazy;
+
+/// The primes less than this number are precomputed.
+pub const SIEVE_LIMIT: u64 = 1 << 20;
+
+static PRIMES: SyncLazy<Vec<u32>> = SyncLazy::new(|| {
+    let n = SIEVE_LIMIT as usize;
+    let mut is_composite = vec![false; n];
+    let mut primes = vec![];
+    for i in 2..n {
+        if !is_composite[i] {
+            primes.push(i as u32);
+            for j in (i * i..n).step_by(i) {
+                is_composite[j] = true;
+            }
+        }
+    }
+    primes
+});
+
+/// The prime factorization of a positive integer, a list of pairs of a prime factor
+/// and its multiplicity sorted by the prime factor.
+pub type Factorization = SmallVec<[(u64, u32); 16]>;
+
+/// Returns the prime factorization of `n`, which must be positive.
+pub fn factorize(mut n: u64) -> Factorization {
+    assert!(n > 0);
+    let mut fs = Factorization::new();
+    // Trial division by the primes less than 2^10.
+    for &p in PRIMES.iter().take_while(|&&p| p < 1 << 10) {
+        let p = p as u64;
+        if p * p > n {
+            break;
+        }
+        let mut e = 0;
+        loop {
+            let (q, r) = (n / p, n % p);
+            if r != 0 {
+                break;
+            }
+            n = q;
+            e += 1;
+        }
+        if e > 0 {
+            fs.push((p, e));
+        }
+    }
+    if n > 1 {
+        let mut ps = SmallVec::<[u64; 8]>::new();
+        split(n, &mut ps);
+        ps.sort_unstable();
+        for (p, group) in &ps.into_iter().group_by(|&p| p) {
+            fs.push((p, group.count() as u32));
+        }
+    }
+    fs
+}
+
+/// Returns `true` if `n` is a prime.
+pub fn is_prime(n: u64) -> bool {
+    if n < SIEVE_LIMIT {
+        return PRIMES.binary_search(&(n as u32)).is_ok();
+    }
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    // The product of `BASES`.
+    const BASES_PRODUCT: u64 = 7420738134810;
+    if gcd(n, BASES_PRODUCT) != 1 {
+        return false;
+    }
+    // The deterministic Miller–Rabin test, which is valid for all n < 2^64
+    // with the above set of bases.
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    BASES.iter().all(|&a| {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Returns the Möbius function μ(n), where `n` must be positive.
+pub fn mobius(n: u64) -> i32 {
+    let fs = factorize(n);
+    if fs.iter().any(|&(_, e)| e > 1) {
+        0
+    } else if fs.len() % 2 == 1 {
+        -1
+    } else {
+        1
+    }
+}
+
+/// Returns the number of positive divisors of `n`, which must be positive.
+pub fn num_divisors(n: u64) -> u64 {
+    factorize(n).iter().map(|&(_, e)| e as u64 + 1).product()
+}
+
+/// Returns the number of primes less than or equal to `n` if `n` < [`SIEVE_LIMIT`].
+pub fn prime_pi(n: u64) -> Option<u64> {
+    if n < SIEVE_LIMIT {
+        Some(match PRIMES.binary_search(&(n as u32)) {
+            Ok(i) => i as u64 + 1,
+            Err(i) => i as u64,
+        })
+    } else {
+        None
+    }
+}
+
+/// Returns Euler's totient function φ(n), where `n` must be positive.
+pub fn totient(n: u64) -> u64 {
+    factorize(n)
+        .iter()
+        .map(|&(p, e)| p.pow(e - 1) * (p - 1))
+        .product()
+}
+
+fn gcd(mut x: u64, mut y: u64) -> u64 {
+    while y != 0 {
+        let r = x % y;
+        x = y;
+        y = r;
+    }
+    x
+}
+
+fn mul_mod(x: u64, y: u64, m: u64) -> u64 {
+    (x as u128 * y as u128 % m as u128) as u64
+}
+
+fn pow_mod(mut x: u64, mut e: u64, m: u64) -> u64 {
+    let mut y = 1;
+    while e > 0 {
+        if e & 1 == 1 {
+            y = mul_mod(y, x, m);
+        }
+        x = mul_mod(x, x, m);
+        e >>= 1;
+    }
+    y
+}
+
+/// Returns a nontrivial divisor of `n`, which must be an odd composite number.
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1u64.. {
+        let f = |x: u64| ((x as u128 * x as u128 + c as u128) % n as u128) as u64;
+        let mut x = 2;
+        let mut y = 2;
+        let mut d = 1;
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = gcd(x.max(y) - x.min(y), n);
+        }
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!();
+}
+
+/// Appends the prime factors of `n`, which must not have a prime factor less than 2^10,
+/// to `ps` with repetition.
+fn split(n: u64, ps: &mut SmallVec<[u64; 8]>) {
+    if n < 1 << 20 || is_prime(n) {
+        ps.push(n);
+    } else {
+        let d = pollard_rho(n);
+        split(d, ps);
+        split(n / d, ps);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factorize() {
+        use super::factorize as f;
+        assert!(f(1).is_empty());
+        assert_eq!(f(2).as_slice(), &[(2, 1)]);
+        assert_eq!(f(360).as_slice(), &[(2, 3), (3, 2), (5, 1)]);
+        // The square of the largest prime less than 2^26.
+        assert_eq!(f(67108859 * 67108859).as_slice(), &[(67108859, 2)]);
+        // The product of the two largest primes less than 2^32.
+        assert_eq!(
+            f(4294967279 * 4294967291).as_slice(),
+            &[(4294967279, 1), (4294967291, 1)]
+        );
+        assert_eq!(
+            f(1021 * 1031 * 1033 * 1039).as_slice(),
+            &[(1021, 1), (1031, 1), (1033, 1), (1039, 1)]
+        );
+    }
+
+    #[test]
+    fn is_prime() {
+        use super::is_prime as f;
+        assert!(!f(0));
+        assert!(!f(1));
+        assert!(f(2));
+        assert!(f(3));
+        assert!(!f(4));
+        assert!(f(1048573)); // The largest prime less than 2^20.
+        assert!(!f(1048575));
+        assert!(f(4294967291)); // The largest prime less than 2^32.
+        assert!(!f(4294967297)); // The Fermat number F_5.
+        assert!(f(9007199254740881)); // The largest prime less than 2^53.
+        assert!(!f(3825123056546413051)); // A strong pseudoprime to bases 2, 3, …, 23.
+    }
+
+    #[test]
+    fn number_theoretic_functions() {
+        let ns = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        assert_eq!(
+            ns.iter().map(|&n| mobius(n)).collect::<Vec<_>>(),
+            vec![1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0]
+        );
+        assert_eq!(
+            ns.iter().map(|&n| num_divisors(n)).collect::<Vec<_>>(),
+            vec![1, 2, 2, 3, 2, 4, 2, 4, 3, 4, 2, 6]
+        );
+        assert_eq!(
+            ns.iter().map(|&n| totient(n)).collect::<Vec<_>>(),
+            vec![1, 1, 2, 2, 4, 2, 6, 4, 6, 4, 10, 4]
+        );
+        assert_eq!(prime_pi(0), Some(0));
+        assert_eq!(prime_pi(2), Some(1));
+        assert_eq!(prime_pi(100), Some(25));
+        assert_eq!(prime_pi(SIEVE_LIMIT - 1), Some(82025));
+        assert_eq!(prime_pi(SIEVE_LIMIT), None);
+    }
+}
@@ -1,8 +1,15 @@
 use crate::{
+    affine_form::AffineForm,
     ast::VarSet,
-    interval_set::{DecSignSet, Site, TupperIntervalSet},
+    interval_set::{BranchMap, DecSignSet, Site, TupperInterval, TupperIntervalSet},
+    mp_interval::MpInterval,
+    taylor_model::TaylorModel,
+};
+use inari::{const_interval, interval, DecInterval, Decoration, Interval};
+use std::{
+    mem::take,
+    ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub},
 };
-use std::ops::{Index, IndexMut};
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct StoreIndex(u32);
@@ -36,6 +43,103 @@ impl<T: Clone> IndexMut<StoreIndex> for ValueStore<T> {
     }
 }
 
+/// The values of a term at the points passed to [`Program::eval_points`].
+#[derive(Clone, Debug)]
+pub enum PointValues {
+    /// Each value is a single interval with an empty branch map, or an empty set.
+    /// The intervals and their decorations are stored in separate arrays.
+    Single(Vec<Interval>, Vec<Decoration>),
+    /// Values that can consist of multiple intervals or have nonempty branch maps.
+    Sets(Vec<TupperIntervalSet>),
+}
+
+impl PointValues {
+    /// Creates values that are `x` at all of the `n` points.
+    pub fn repeat(x: &TupperIntervalSet, n: usize) -> Self {
+        Self::from_sets(vec![x.clone(); n])
+    }
+
+    /// Returns the value at the `i`th point.
+    pub fn get(&self, i: usize) -> TupperIntervalSet {
+        match self {
+            Self::Single(xs, ds) => TupperIntervalSet::from(
+                TupperInterval {
+                    x: xs[i],
+                    d: ds[i],
+                    g: BranchMap::new(),
+                }
+                .dec_interval(),
+            ),
+            Self::Sets(xs) => xs[i].clone(),
+        }
+    }
+
+    /// Returns the number of the points.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Single(xs, _) => xs.len(),
+            Self::Sets(xs) => xs.len(),
+        }
+    }
+
+    /// Returns the only interval in the value at the `i`th point
+    /// in the same way as [`TupperIntervalSet::single`].
+    pub fn single(&self, i: usize) -> Option<TupperInterval> {
+        match self {
+            Self::Single(xs, ds) if !xs[i].is_empty() => Some(TupperInterval {
+                x: xs[i],
+                d: ds[i],
+                g: BranchMap::new(),
+            }),
+            Self::Single(..) => None,
+            Self::Sets(xs) => xs[i].single(),
+        }
+    }
+
+    /// Replaces the values with the results of `f` at each of the `n` points,
+    /// reusing the storage if possible.
+    fn fill_single<F: FnMut(usize) -> DecInterval>(&mut self, n: usize, mut f: F) {
+        if let Self::Sets(_) = self {
+            *self = Self::Single(vec![], vec![]);
+        }
+        if let Self::Single(xs, ds) = self {
+            xs.clear();
+            ds.clear();
+            for i in 0..n {
+                let y = TupperInterval::new(f(i), BranchMap::new());
+                xs.push(y.x);
+                ds.push(y.d);
+            }
+        }
+    }
+
+    /// Creates values from the sets, which are stored in the form of [`PointValues::Single`]
+    /// if possible.
+    pub fn from_sets(xs: Vec<TupperIntervalSet>) -> Self {
+        if xs
+            .iter()
+            .all(|x| x.is_empty() || matches!(x.single(), Some(x) if x.g == BranchMap::new()))
+        {
+            Self::Single(
+                xs.iter()
+                    .map(|x| x.single().map_or(Interval::EMPTY, |x| x.x))
+                    .collect(),
+                xs.iter()
+                    .map(|x| x.single().map_or(Decoration::Trv, |x| x.d))
+                    .collect(),
+            )
+        } else {
+            Self::Sets(xs)
+        }
+    }
+}
+
+impl Default for PointValues {
+    fn default() -> Self {
+        Self::Sets(vec![])
+    }
+}
+
 pub type FormIndex = u32;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -51,6 +155,7 @@ pub enum ScalarUnaryOp {
     Asinh,
     Atan,
     Atanh,
+    Cbrt,
     Ceil,
     Chi,
     Ci,
@@ -62,29 +167,48 @@ pub enum ScalarUnaryOp {
     EllipticK,
     Erf,
     Erfc,
+    Erfcinv,
     Erfi,
+    Erfinv,
     Exp,
     Exp10,
     Exp2,
+    Expm1,
+    Factorial,
     Floor,
     FresnelC,
     FresnelS,
     Gamma,
+    Heaviside,
+    IsPrime,
     Li,
     Ln,
+    LnGamma,
     Log10,
+    Log1p,
+    Mobius,
     Neg,
+    NumDivisors,
     One,
+    Popcount,
+    PrimePi,
     Recip,
+    Rect,
+    SawtoothWave,
     Shi,
     Si,
+    Sign,
     Sin,
     Sinc,
     Sinh,
+    Sinhc,
     Sqr,
     Sqrt,
+    SquareWave,
     Tan,
     Tanh,
+    Totient,
+    Tri,
     UndefAt0,
 }
 
@@ -96,24 +220,65 @@ pub enum ScalarBinaryOp {
     BesselJ,
     BesselK,
     BesselY,
+    Beta,
+    Binom,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ChebyshevT,
+    ChebyshevU,
     Div,
+    DivisorSigma,
+    EllipticEInc,
+    EllipticF,
+    ExpIntegralE,
     GammaInc,
+    GammaIncLower,
+    GammaRegularized,
+    GammaRegularizedLower,
     Gcd,
+    HermiteH,
+    Hyp0F1,
+    Hypot,
+    JacobiAm,
+    JacobiCn,
+    JacobiDn,
+    JacobiSn,
+    LaguerreL,
     Lcm,
+    LegendreP,
     Log,
     Max,
     Min,
     Mod,
     Mul,
+    Poch,
+    Polygamma,
     Pow,
+    SphericalBesselI,
+    SphericalBesselJ,
+    SphericalBesselK,
+    SphericalBesselY,
+    StruveH,
+    StruveL,
     Sub,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ScalarTernaryOp {
+    AssocLegendreP,
+    BetaRegularized,
+    Clamp,
+    EllipticPi,
+    Hyp1F1,
     MulAdd,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScalarQuaternaryOp {
+    Hyp2F1,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum RankedMinMaxOp {
     RankedMax,
@@ -140,6 +305,8 @@ pub enum StaticTermKind {
     Unary(ScalarUnaryOp, StoreIndex),
     Binary(ScalarBinaryOp, StoreIndex, StoreIndex),
     Ternary(ScalarTernaryOp, StoreIndex, StoreIndex, StoreIndex),
+    // Box the indices to keep the enum small.
+    Quaternary(ScalarQuaternaryOp, Box<[StoreIndex; 4]>),
     Pown(StoreIndex, i32),
     Rootn(StoreIndex, u32),
     // Box the `Vec` to keep the enum small.
@@ -148,6 +315,91 @@ pub enum StaticTermKind {
     RankedMinMax(RankedMinMaxOp, Box<Vec<StoreIndex>>, StoreIndex),
 }
 
+/// Enclosures of the partial derivatives of a term with respect to x and y.
+#[derive(Clone, Copy, Debug)]
+pub struct Gradient {
+    pub dx: Interval,
+    pub dy: Interval,
+}
+
+impl Gradient {
+    pub const ZERO: Self = Self {
+        dx: const_interval!(0.0, 0.0),
+        dy: const_interval!(0.0, 0.0),
+    };
+
+    /// The gradient of a term about which nothing is known.
+    pub const ENTIRE: Self = Self {
+        dx: Interval::ENTIRE,
+        dy: Interval::ENTIRE,
+    };
+
+    const X: Self = Self {
+        dx: const_interval!(1.0, 1.0),
+        dy: const_interval!(0.0, 0.0),
+    };
+
+    const Y: Self = Self {
+        dx: const_interval!(0.0, 0.0),
+        dy: const_interval!(1.0, 1.0),
+    };
+}
+
+impl Add for Gradient {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            dx: self.dx + rhs.dx,
+            dy: self.dy + rhs.dy,
+        }
+    }
+}
+
+impl Sub for Gradient {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            dx: self.dx - rhs.dx,
+            dy: self.dy - rhs.dy,
+        }
+    }
+}
+
+impl Neg for Gradient {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            dx: -self.dx,
+            dy: -self.dy,
+        }
+    }
+}
+
+impl Mul<Interval> for Gradient {
+    type Output = Self;
+
+    fn mul(self, rhs: Interval) -> Self {
+        Self {
+            dx: self.dx * rhs,
+            dy: self.dy * rhs,
+        }
+    }
+}
+
+impl Div<Interval> for Gradient {
+    type Output = Self;
+
+    fn div(self, rhs: Interval) -> Self {
+        Self {
+            dx: self.dx / rhs,
+            dy: self.dy / rhs,
+        }
+    }
+}
+
 /// A term in a cache-efficient representation.
 #[derive(Clone, Debug)]
 pub struct StaticTerm {
@@ -168,8 +420,8 @@ impl StaticTerm {
     /// or [`StaticTermKind::NTheta`].
     pub fn put_eval(&self, ts: &mut ValueStore<TupperIntervalSet>) {
         use {
-            RankedMinMaxOp::*, ScalarBinaryOp::*, ScalarTernaryOp::*, ScalarUnaryOp::*,
-            StaticTermKind::*,
+            RankedMinMaxOp::*, ScalarBinaryOp::*, ScalarQuaternaryOp::*, ScalarTernaryOp::*,
+            ScalarUnaryOp::*, StaticTermKind::*,
         };
         match &self.kind {
             Constant(x) => self.put(ts, *x.clone()),
@@ -184,6 +436,7 @@ impl StaticTerm {
             Unary(Asinh, x) => self.put(ts, ts[*x].asinh()),
             Unary(Atan, x) => self.put(ts, ts[*x].atan()),
             Unary(Atanh, x) => self.put(ts, ts[*x].atanh()),
+            Unary(Cbrt, x) => self.put(ts, ts[*x].cbrt()),
             Unary(Ceil, x) => self.put(ts, ts[*x].ceil(self.site)),
             Unary(Chi, x) => self.put(ts, ts[*x].chi()),
             Unary(Ci, x) => self.put(ts, ts[*x].ci()),
@@ -195,29 +448,48 @@ impl StaticTerm {
             Unary(EllipticK, x) => self.put(ts, ts[*x].elliptic_k()),
             Unary(Erf, x) => self.put(ts, ts[*x].erf()),
             Unary(Erfc, x) => self.put(ts, ts[*x].erfc()),
+            Unary(Erfcinv, x) => self.put(ts, ts[*x].erfcinv()),
             Unary(Erfi, x) => self.put(ts, ts[*x].erfi()),
+            Unary(Erfinv, x) => self.put(ts, ts[*x].erfinv()),
             Unary(Exp, x) => self.put(ts, ts[*x].exp()),
             Unary(Exp10, x) => self.put(ts, ts[*x].exp10()),
             Unary(Exp2, x) => self.put(ts, ts[*x].exp2()),
+            Unary(Expm1, x) => self.put(ts, ts[*x].expm1()),
+            Unary(Factorial, x) => self.put(ts, ts[*x].factorial(self.site)),
             Unary(Floor, x) => self.put(ts, ts[*x].floor(self.site)),
             Unary(FresnelC, x) => self.put(ts, ts[*x].fresnel_c()),
             Unary(FresnelS, x) => self.put(ts, ts[*x].fresnel_s()),
             Unary(Gamma, x) => self.put(ts, ts[*x].gamma(self.site)),
+            Unary(Heaviside, x) => self.put(ts, ts[*x].heaviside(self.site)),
+            Unary(IsPrime, x) => self.put(ts, ts[*x].is_prime(self.site)),
             Unary(Li, x) => self.put(ts, ts[*x].li()),
             Unary(Ln, x) => self.put(ts, ts[*x].ln()),
+            Unary(LnGamma, x) => self.put(ts, ts[*x].ln_gamma(self.site)),
             Unary(Log10, x) => self.put(ts, ts[*x].log10()),
+            Unary(Log1p, x) => self.put(ts, ts[*x].log1p()),
+            Unary(Mobius, x) => self.put(ts, ts[*x].mobius(self.site)),
             Unary(Neg, x) => self.put(ts, -&ts[*x]),
+            Unary(NumDivisors, x) => self.put(ts, ts[*x].num_divisors(self.site)),
             Unary(One, x) => self.put(ts, ts[*x].one()),
+            Unary(Popcount, x) => self.put(ts, ts[*x].popcount(self.site)),
+            Unary(PrimePi, x) => self.put(ts, ts[*x].prime_pi(self.site)),
             Unary(Recip, x) => self.put(ts, ts[*x].recip(self.site)),
+            Unary(Rect, x) => self.put(ts, ts[*x].rect(self.site)),
+            Unary(SawtoothWave, x) => self.put(ts, ts[*x].sawtooth_wave(self.site)),
             Unary(Shi, x) => self.put(ts, ts[*x].shi()),
             Unary(Si, x) => self.put(ts, ts[*x].si()),
+            Unary(Sign, x) => self.put(ts, ts[*x].sign(self.site)),
             Unary(Sin, x) => self.put(ts, ts[*x].sin()),
             Unary(Sinc, x) => self.put(ts, ts[*x].sinc()),
             Unary(Sinh, x) => self.put(ts, ts[*x].sinh()),
+            Unary(Sinhc, x) => self.put(ts, ts[*x].sinhc()),
             Unary(Sqr, x) => self.put(ts, ts[*x].sqr()),
             Unary(Sqrt, x) => self.put(ts, ts[*x].sqrt()),
+            Unary(SquareWave, x) => self.put(ts, ts[*x].square_wave(self.site)),
             Unary(Tan, x) => self.put(ts, ts[*x].tan(self.site)),
             Unary(Tanh, x) => self.put(ts, ts[*x].tanh()),
+            Unary(Totient, x) => self.put(ts, ts[*x].totient(self.site)),
+            Unary(Tri, x) => self.put(ts, ts[*x].tri()),
             Unary(UndefAt0, x) => self.put(ts, ts[*x].undef_at_0()),
             Binary(Add, x, y) => self.put(ts, &ts[*x] + &ts[*y]),
             Binary(Atan2, y, x) => self.put(ts, ts[*y].atan2(&ts[*x], self.site)),
@@ -225,19 +497,65 @@ impl StaticTerm {
             Binary(BesselJ, n, x) => self.put(ts, ts[*n].bessel_j(&ts[*x])),
             Binary(BesselK, n, x) => self.put(ts, ts[*n].bessel_k(&ts[*x])),
             Binary(BesselY, n, x) => self.put(ts, ts[*n].bessel_y(&ts[*x])),
+            Binary(Beta, x, y) => self.put(ts, ts[*x].beta(&ts[*y], self.site)),
+            Binary(Binom, n, k) => self.put(ts, ts[*n].binom(&ts[*k], self.site)),
+            Binary(BitAnd, x, y) => self.put(ts, ts[*x].bit_and(&ts[*y], self.site)),
+            Binary(BitOr, x, y) => self.put(ts, ts[*x].bit_or(&ts[*y], self.site)),
+            Binary(BitXor, x, y) => self.put(ts, ts[*x].bit_xor(&ts[*y], self.site)),
+            Binary(ChebyshevT, n, x) => self.put(ts, ts[*n].chebyshev_t(&ts[*x])),
+            Binary(ChebyshevU, n, x) => self.put(ts, ts[*n].chebyshev_u(&ts[*x])),
             Binary(Div, x, y) => self.put(ts, ts[*x].div(&ts[*y], self.site)),
+            Binary(DivisorSigma, k, n) => self.put(ts, ts[*k].divisor_sigma(&ts[*n], self.site)),
+            Binary(EllipticEInc, phi, m) => self.put(ts, ts[*phi].elliptic_e_inc(&ts[*m])),
+            Binary(EllipticF, phi, m) => self.put(ts, ts[*phi].elliptic_f(&ts[*m])),
+            Binary(ExpIntegralE, n, x) => self.put(ts, ts[*n].exp_integral_e(&ts[*x])),
             Binary(GammaInc, a, x) => self.put(ts, ts[*a].gamma_inc(&ts[*x])),
+            Binary(GammaIncLower, a, x) => self.put(ts, ts[*a].gamma_inc_lower(&ts[*x])),
+            Binary(GammaRegularized, a, x) => self.put(ts, ts[*a].gamma_regularized(&ts[*x])),
+            Binary(GammaRegularizedLower, a, x) => {
+                self.put(ts, ts[*a].gamma_regularized_lower(&ts[*x]))
+            }
             Binary(Gcd, x, y) => self.put(ts, ts[*x].gcd(&ts[*y], self.site)),
+            Binary(HermiteH, n, x) => self.put(ts, ts[*n].hermite_h(&ts[*x])),
+            Binary(Hyp0F1, b, x) => self.put(ts, ts[*b].hyp0f1(&ts[*x])),
+            Binary(Hypot, x, y) => self.put(ts, ts[*x].hypot(&ts[*y])),
+            Binary(JacobiAm, u, m) => self.put(ts, ts[*u].jacobi_am(&ts[*m])),
+            Binary(JacobiCn, u, m) => self.put(ts, ts[*u].jacobi_cn(&ts[*m])),
+            Binary(JacobiDn, u, m) => self.put(ts, ts[*u].jacobi_dn(&ts[*m])),
+            Binary(JacobiSn, u, m) => self.put(ts, ts[*u].jacobi_sn(&ts[*m])),
+            Binary(LaguerreL, n, x) => self.put(ts, ts[*n].laguerre_l(&ts[*x])),
             Binary(Lcm, x, y) => self.put(ts, ts[*x].lcm(&ts[*y], self.site)),
+            Binary(LegendreP, n, x) => self.put(ts, ts[*n].legendre_p(&ts[*x])),
             // Beware the order of arguments.
             Binary(Log, b, x) => self.put(ts, ts[*x].log(&ts[*b], self.site)),
             Binary(Max, x, y) => self.put(ts, ts[*x].max(&ts[*y])),
             Binary(Min, x, y) => self.put(ts, ts[*x].min(&ts[*y])),
             Binary(Mod, x, y) => self.put(ts, ts[*x].rem_euclid(&ts[*y], self.site)),
             Binary(Mul, x, y) => self.put(ts, &ts[*x] * &ts[*y]),
+            Binary(Poch, x, n) => self.put(ts, ts[*x].poch(&ts[*n], self.site)),
+            Binary(Polygamma, n, x) => self.put(ts, ts[*n].polygamma(&ts[*x], self.site)),
             Binary(Pow, x, y) => self.put(ts, ts[*x].pow(&ts[*y], self.site)),
+            Binary(SphericalBesselI, n, x) => self.put(ts, ts[*n].spherical_bessel_i(&ts[*x])),
+            Binary(SphericalBesselJ, n, x) => self.put(ts, ts[*n].spherical_bessel_j(&ts[*x])),
+            Binary(SphericalBesselK, n, x) => self.put(ts, ts[*n].spherical_bessel_k(&ts[*x])),
+            Binary(SphericalBesselY, n, x) => self.put(ts, ts[*n].spherical_bessel_y(&ts[*x])),
+            Binary(StruveH, nu, x) => self.put(ts, ts[*nu].struve_h(&ts[*x])),
+            Binary(StruveL, nu, x) => self.put(ts, ts[*nu].struve_l(&ts[*x])),
             Binary(Sub, x, y) => self.put(ts, &ts[*x] - &ts[*y]),
+            Ternary(AssocLegendreP, n, m, x) => {
+                self.put(ts, ts[*n].assoc_legendre_p(&ts[*m], &ts[*x]))
+            }
+            Ternary(BetaRegularized, x, a, b) => {
+                self.put(ts, ts[*x].beta_regularized(&ts[*a], &ts[*b]))
+            }
+            Ternary(Clamp, x, a, b) => self.put(ts, ts[*x].clamp(&ts[*a], &ts[*b])),
+            Ternary(EllipticPi, n, phi, m) => self.put(ts, ts[*n].elliptic_pi(&ts[*phi], &ts[*m])),
+            Ternary(Hyp1F1, a, b, x) => self.put(ts, ts[*a].hyp1f1(&ts[*b], &ts[*x])),
             Ternary(MulAdd, x, y, z) => self.put(ts, ts[*x].mul_add(&ts[*y], &ts[*z])),
+            Quaternary(Hyp2F1, xs) => {
+                let [a, b, c, x] = **xs;
+                self.put(ts, ts[a].hyp2f1(&ts[b], &ts[c], &ts[x]))
+            }
             Pown(x, n) => self.put(ts, ts[*x].pown(*n, self.site)),
             Rootn(x, n) => self.put(ts, ts[*x].rootn(*n)),
             RankedMinMax(RankedMax, xs, n) => {
@@ -263,6 +581,889 @@ impl StaticTerm {
             X | Y | NTheta => panic!("this term cannot be evaluated"),
         }
     }
+
+    /// Evaluates the term in multiple precision and puts the result in the value store.
+    ///
+    /// Terms that have no multiple-precision implementation are evaluated by [`Self::put_eval`]
+    /// with `ts` as a scratch store, and thus with the precision of [`f64`].
+    ///
+    /// Panics if the term is of the kind [`StaticTermKind::X`], [`StaticTermKind::Y`]
+    /// or [`StaticTermKind::NTheta`].
+    pub fn put_eval_mp(
+        &self,
+        ms: &mut ValueStore<MpInterval>,
+        ts: &mut ValueStore<TupperIntervalSet>,
+        prec: u32,
+    ) {
+        use {ScalarBinaryOp::*, ScalarTernaryOp::*, ScalarUnaryOp::*, StaticTermKind::*};
+        let y = match &self.kind {
+            Constant(x) => Some(MpInterval::from_constant(x, prec)),
+            Unary(Abs, x) => Some(ms[*x].abs()),
+            Unary(Acos, x) => Some(ms[*x].acos()),
+            Unary(Acosh, x) => Some(ms[*x].acosh()),
+            Unary(Asin, x) => Some(ms[*x].asin()),
+            Unary(Asinh, x) => Some(ms[*x].asinh()),
+            Unary(Atan, x) => Some(ms[*x].atan()),
+            Unary(Atanh, x) => Some(ms[*x].atanh()),
+            Unary(Cbrt, x) => Some(ms[*x].cbrt()),
+            Unary(Ceil, x) => Some(ms[*x].ceil()),
+            Unary(Cos, x) => Some(ms[*x].cos()),
+            Unary(Cosh, x) => Some(ms[*x].cosh()),
+            Unary(Erf, x) => Some(ms[*x].erf()),
+            Unary(Erfc, x) => Some(ms[*x].erfc()),
+            Unary(Exp, x) => Some(ms[*x].exp()),
+            Unary(Exp10, x) => Some(ms[*x].exp10()),
+            Unary(Exp2, x) => Some(ms[*x].exp2()),
+            Unary(Expm1, x) => Some(ms[*x].expm1()),
+            Unary(Floor, x) => Some(ms[*x].floor()),
+            Unary(Ln, x) => Some(ms[*x].ln()),
+            Unary(Log10, x) => Some(ms[*x].log10()),
+            Unary(Log1p, x) => Some(ms[*x].log1p()),
+            Unary(Neg, x) => Some(-&ms[*x]),
+            Unary(One, x) => Some(ms[*x].one()),
+            Unary(Recip, x) => ms[*x].recip(),
+            Unary(Sin, x) => Some(ms[*x].sin()),
+            Unary(Sinh, x) => Some(ms[*x].sinh()),
+            Unary(Sqr, x) => Some(ms[*x].sqr()),
+            Unary(Sqrt, x) => Some(ms[*x].sqrt()),
+            Unary(Tan, x) => ms[*x].tan(),
+            Unary(Tanh, x) => Some(ms[*x].tanh()),
+            Unary(UndefAt0, x) => Some(ms[*x].undef_at_0()),
+            Binary(Add, x, y) => Some(&ms[*x] + &ms[*y]),
+            Binary(Atan2, y, x) => ms[*y].atan2(&ms[*x]),
+            Binary(Div, x, y) => ms[*x].div(&ms[*y]),
+            Binary(Hypot, x, y) => Some(ms[*x].hypot(&ms[*y])),
+            Binary(Log, b, x) => ms[*x].ln().div(&ms[*b].ln()),
+            Binary(Max, x, y) => Some(ms[*x].max(&ms[*y])),
+            Binary(Min, x, y) => Some(ms[*x].min(&ms[*y])),
+            Binary(Mul, x, y) => Some(&ms[*x] * &ms[*y]),
+            Binary(Pow, x, y) => ms[*x].pow(&ms[*y]),
+            Binary(Sub, x, y) => Some(&ms[*x] - &ms[*y]),
+            Ternary(MulAdd, x, y, z) => Some(ms[*x].mul_add(&ms[*y], &ms[*z])),
+            Pown(x, n) => ms[*x].pown(*n),
+            Rootn(x, n) => Some(ms[*x].rootn(*n)),
+            X | Y | NTheta => panic!("this term cannot be evaluated"),
+            _ => None,
+        };
+
+        match y {
+            Some(y) => self.put(ms, y),
+            _ => {
+                for x in self.args() {
+                    ts[x] = ms[x].to_tupper_interval_set();
+                }
+                self.put_eval(ts);
+                self.put(
+                    ms,
+                    MpInterval::from_tupper_interval_set(&ts[self.store_index], prec),
+                );
+            }
+        }
+    }
+
+    /// Computes an enclosure of the gradient of the term by forward-mode automatic
+    /// differentiation and puts it in the store.
+    ///
+    /// The values and gradients of the arguments must have been put in `ts` and `ds`.
+    /// The result is only valid if the value of the term is a single interval
+    /// with the decoration ≥ [`Decoration::Dac`](inari::Decoration::Dac), i.e.,
+    /// if the term is continuous over the domain. The gradient is set to [`Gradient::ENTIRE`]
+    /// if the value of any argument is not a single interval or the term is not differentiable
+    /// by the implementation.
+    pub fn put_eval_gradient(
+        &self,
+        ts: &ValueStore<TupperIntervalSet>,
+        ds: &mut ValueStore<Gradient>,
+    ) {
+        use {ScalarBinaryOp::*, ScalarTernaryOp::*, StaticTermKind::*};
+        let v = |i: StoreIndex| ts[i].single().map(|x| x.x);
+        let d = if !self.vars.intersects(VarSet::XY) {
+            Some(Gradient::ZERO)
+        } else {
+            match &self.kind {
+                X => Some(Gradient::X),
+                Y => Some(Gradient::Y),
+                Unary(op, x) => v(*x)
+                    .and_then(|a| Self::unary_derivative(*op, a))
+                    .map(|d| ds[*x] * d),
+                Binary(op, x, y) => v(*x).zip(v(*y)).and_then(|(a, b)| {
+                    let (da, db) = (ds[*x], ds[*y]);
+                    match op {
+                        Add => Some(da + db),
+                        // The arguments are in the order of `Atan2(y, x)`.
+                        Atan2 => Some((da * b - db * a) / (a.sqr() + b.sqr())),
+                        Div => Some((da * b - db * a) / b.sqr()),
+                        Hypot => Some((da * a + db * b) / (a.sqr() + b.sqr()).sqrt()),
+                        Max if a.inf() >= b.sup() => Some(da),
+                        Max if b.inf() >= a.sup() => Some(db),
+                        Min if a.sup() <= b.inf() => Some(da),
+                        Min if b.sup() <= a.inf() => Some(db),
+                        Mul => Some(da * b + db * a),
+                        Sub => Some(da - db),
+                        _ => None,
+                    }
+                }),
+                Ternary(MulAdd, x, y, z) => v(*x)
+                    .zip(v(*y))
+                    .zip(v(*z))
+                    .map(|((a, b), _)| ds[*x] * b + ds[*y] * a + ds[*z]),
+                Pown(_, 0) => Some(Gradient::ZERO),
+                Pown(x, n) => v(*x).map(|a| {
+                    let c = *n as f64;
+                    ds[*x] * (interval!(c, c).unwrap() * a.pown(*n - 1))
+                }),
+                _ => None,
+            }
+        };
+        self.put(ds, d.unwrap_or(Gradient::ENTIRE));
+    }
+
+    /// Evaluates the term in affine arithmetic and puts the result in the store.
+    ///
+    /// The values of the term and its arguments in interval arithmetic must have been put in `ts`.
+    /// Terms that are not supported by affine arithmetic are represented by the convex hulls
+    /// of their values in `ts`.
+    ///
+    /// Panics if the term is of the kind [`StaticTermKind::X`], [`StaticTermKind::Y`]
+    /// or [`StaticTermKind::NTheta`].
+    pub fn put_eval_affine(
+        &self,
+        ts: &ValueStore<TupperIntervalSet>,
+        fs: &mut ValueStore<AffineForm>,
+    ) {
+        use {ScalarBinaryOp::*, ScalarTernaryOp::*, ScalarUnaryOp::*, StaticTermKind::*};
+        let hull = |i: StoreIndex| Self::hull(ts, i);
+        let recip = |x: StoreIndex| {
+            fs[x].linearize(|x| x.recip(), |x| Self::unary_derivative(Recip, x), hull(x))
+        };
+        let y = if let Some((x, neg)) = self.signed_arg(ts) {
+            Some(if neg { -fs[x] } else { fs[x] })
+        } else {
+            match &self.kind {
+                Unary(Recip, x) => recip(*x),
+                Unary(Sqr, x) => Some(fs[*x].sqr()),
+                Unary(op, x) => Program::inari_unary_fn(*op).and_then(|f| {
+                    fs[*x].linearize(f, |x| Self::unary_derivative(*op, x), hull(*x))
+                }),
+                Binary(Add, x, y) => Some(fs[*x] + fs[*y]),
+                Binary(Div, x, y) => recip(*y).map(|y| fs[*x] * y),
+                Binary(Mul, x, y) => Some(fs[*x] * fs[*y]),
+                Binary(Sub, x, y) => Some(fs[*x] - fs[*y]),
+                Ternary(MulAdd, x, y, z) => Some(fs[*x] * fs[*y] + fs[*z]),
+                Pown(x, 2) => Some(fs[*x].sqr()),
+                Pown(x, n) if *n != 0 => {
+                    let c = *n as f64;
+                    let c = interval!(c, c).unwrap();
+                    fs[*x].linearize(|x| x.pown(*n), |x| Some(c * x.pown(*n - 1)), hull(*x))
+                }
+                X | Y | NTheta => panic!("this term cannot be evaluated"),
+                _ => None,
+            }
+        };
+        self.put(
+            fs,
+            y.unwrap_or_else(|| AffineForm::constant(hull(self.store_index))),
+        );
+    }
+
+    /// Evaluates the term as a Taylor model and puts the result in the store.
+    ///
+    /// The values of the term and its arguments in interval arithmetic must have been put in `ts`.
+    /// Terms that are not supported are represented by the convex hulls of their values in `ts`,
+    /// as Taylor models over the same box as `base`.
+    ///
+    /// Panics if the term is of the kind [`StaticTermKind::X`], [`StaticTermKind::Y`]
+    /// or [`StaticTermKind::NTheta`].
+    pub fn put_eval_taylor(
+        &self,
+        ts: &ValueStore<TupperIntervalSet>,
+        ms: &mut ValueStore<TaylorModel>,
+        base: &TaylorModel,
+    ) {
+        use {ScalarBinaryOp::*, ScalarTernaryOp::*, ScalarUnaryOp::*, StaticTermKind::*};
+        let hull = |i: StoreIndex| Self::hull(ts, i);
+        let recip = |x: StoreIndex| ms[x].compose(|x, n| Self::unary_taylor_coeffs(Recip, x, n));
+        let y = if let Some((x, neg)) = self.signed_arg(ts) {
+            Some(if neg { -ms[x] } else { ms[x] })
+        } else {
+            match &self.kind {
+                Unary(Sqr, x) => Some(ms[*x].sqr()),
+                Unary(op, x) => ms[*x].compose(|x, n| Self::unary_taylor_coeffs(*op, x, n)),
+                Binary(Add, x, y) => Some(ms[*x] + ms[*y]),
+                Binary(Div, x, y) => recip(*y).map(|y| ms[*x] * y),
+                Binary(Mul, x, y) => Some(ms[*x] * ms[*y]),
+                Binary(Sub, x, y) => Some(ms[*x] - ms[*y]),
+                Ternary(MulAdd, x, y, z) => Some(ms[*x] * ms[*y] + ms[*z]),
+                Pown(x, 2) => Some(ms[*x].sqr()),
+                Pown(x, n) if *n != 0 => ms[*x].compose(|x, k| {
+                    // The coefficients are binom(n, k) xⁿ⁻ᵏ.
+                    if *n < 0 && x.contains(0.0) {
+                        return None;
+                    }
+                    let mut a = vec![];
+                    let mut b = const_interval!(1.0, 1.0);
+                    for k in 0..=k as i32 {
+                        a.push(if *n >= 0 && k > *n {
+                            const_interval!(0.0, 0.0)
+                        } else {
+                            b * x.pown(*n - k)
+                        });
+                        b = b * interval!((*n - k) as f64, (*n - k) as f64).unwrap()
+                            / interval!((k + 1) as f64, (k + 1) as f64).unwrap();
+                    }
+                    Some(a)
+                }),
+                X | Y | NTheta => panic!("this term cannot be evaluated"),
+                _ => None,
+            }
+        };
+        self.put(
+            ms,
+            y.unwrap_or_else(|| base.with_constant(hull(self.store_index))),
+        );
+    }
+
+    /// Returns the convex hull of the value of the term at `i` in `ts`.
+    fn hull(ts: &ValueStore<TupperIntervalSet>, i: StoreIndex) -> Interval {
+        ts[i]
+            .iter()
+            .fold(Interval::EMPTY, |acc, x| acc.convex_hull(x.x))
+    }
+
+    /// Returns `(x, neg)` if the value of the term is known to be the same as that of
+    /// the argument `x` (if `neg` is `false`) or its negation (if `neg` is `true`)
+    /// from the values of the arguments in `ts`, such as |x| for x ≤ 0 and max(x, y) for x ≥ y.
+    fn signed_arg(&self, ts: &ValueStore<TupperIntervalSet>) -> Option<(StoreIndex, bool)> {
+        use {ScalarBinaryOp::*, ScalarUnaryOp::*, StaticTermKind::*};
+        let hull = |i: StoreIndex| Self::hull(ts, i);
+        match self.kind {
+            Unary(Abs, x) if hull(x).inf() >= 0.0 => Some((x, false)),
+            Unary(Abs, x) if hull(x).sup() <= 0.0 => Some((x, true)),
+            Unary(Neg, x) => Some((x, true)),
+            Binary(Max, x, y) if hull(x).inf() >= hull(y).sup() => Some((x, false)),
+            Binary(Max, x, y) if hull(y).inf() >= hull(x).sup() => Some((y, false)),
+            Binary(Min, x, y) if hull(x).sup() <= hull(y).inf() => Some((x, false)),
+            Binary(Min, x, y) if hull(y).sup() <= hull(x).inf() => Some((y, false)),
+            Pown(x, 1) => Some((x, false)),
+            _ => None,
+        }
+    }
+
+    /// Returns enclosures of the Taylor coefficients [f(x), f'(x), f''(x)/2!, …, f⁽ⁿ⁾(x)/n!]
+    /// of the function over `x`, or [`None`] if the function is not n times continuously
+    /// differentiable over `x` or not supported by the implementation.
+    fn unary_taylor_coeffs(op: ScalarUnaryOp, x: Interval, n: usize) -> Option<Vec<Interval>> {
+        use ScalarUnaryOp::*;
+        if !x.is_common_interval() {
+            return None;
+        }
+        let int = |k: usize| interval!(k as f64, k as f64).unwrap();
+        // Returns [d₀/0!, d₁/1!, …, dₙ/n!], where dₖ = ds[k % ds.len()].
+        let cyclic = |ds: &[Interval]| {
+            let mut a = vec![];
+            let mut f = const_interval!(1.0, 1.0);
+            for k in 0..=n {
+                a.push(ds[k % ds.len()] / f);
+                f *= int(k + 1);
+            }
+            a
+        };
+        let a = match op {
+            Cos => cyclic(&[x.cos(), -x.sin(), -x.cos(), x.sin()]),
+            Cosh => cyclic(&[x.cosh(), x.sinh()]),
+            Exp => cyclic(&[x.exp()]),
+            Sin => cyclic(&[x.sin(), x.cos(), -x.sin(), -x.cos()]),
+            Sinh => cyclic(&[x.sinh(), x.cosh()]),
+            Ln if x.inf() > 0.0 => {
+                // The coefficients are (-1)ᵏ⁺¹ / (k xᵏ) for k ≥ 1.
+                let mut a = vec![x.ln()];
+                for k in 1..=n {
+                    let c = x.recip().pown(k as i32) / int(k);
+                    a.push(if k % 2 == 0 { -c } else { c });
+                }
+                a
+            }
+            Recip if !x.contains(0.0) => {
+                // The coefficients are (-1)ᵏ / xᵏ⁺¹.
+                (0..=n)
+                    .map(|k| {
+                        let c = x.recip().pown(k as i32 + 1);
+                        if k % 2 == 0 {
+                            c
+                        } else {
+                            -c
+                        }
+                    })
+                    .collect()
+            }
+            Sqrt if x.inf() > 0.0 => {
+                // The coefficients are binom(1/2, k) x^(1/2 - k).
+                let mut a = vec![];
+                let mut b = const_interval!(1.0, 1.0);
+                for k in 0..=n {
+                    a.push(b * x.sqrt() * x.recip().pown(k as i32));
+                    b = b * (const_interval!(0.5, 0.5) - int(k)) / int(k + 1);
+                }
+                a
+            }
+            _ => return None,
+        };
+        Some(a)
+    }
+
+    /// Narrows the domains of the arguments of the term in `cs` by propagating
+    /// the domain of the term backward, which must be [`Some`].
+    ///
+    /// The domain of an argument is initialized to the convex hull of its value in `ts`
+    /// if it is [`None`]. Domains are only propagated through the operations that are undefined
+    /// wherever any of their arguments is undefined; the domains of the arguments
+    /// of the other terms are left unchanged.
+    pub fn contract_args(
+        &self,
+        ts: &ValueStore<TupperIntervalSet>,
+        cs: &mut ValueStore<Option<Interval>>,
+    ) {
+        use {ScalarBinaryOp::*, ScalarTernaryOp::*, ScalarUnaryOp::*, StaticTermKind::*};
+        const NON_NEG: Interval = const_interval!(0.0, f64::INFINITY);
+        let z = cs[self.store_index].unwrap();
+        // Returns the hull of the parts of `x` that are in s or -s.
+        let symmetric =
+            |x: Interval, s: Interval| x.intersection(s).convex_hull(x.intersection(-s));
+        // Returns an enclosure of {x | x y ∈ z, y ∈ y}, unless it is the entire real line.
+        let div_rev = |z: Interval, y: Interval| {
+            if y.contains(0.0) && z.contains(0.0) {
+                Interval::ENTIRE
+            } else {
+                z / y
+            }
+        };
+        match &self.kind {
+            Unary(Abs, x) => {
+                let a = Self::domain(ts, cs, *x);
+                Self::narrow(ts, cs, *x, symmetric(a, z.intersection(NON_NEG)))
+            }
+            Unary(Exp, x) => Self::narrow(ts, cs, *x, z.ln()),
+            Unary(Exp10, x) => Self::narrow(ts, cs, *x, z.log10()),
+            Unary(Exp2, x) => Self::narrow(ts, cs, *x, z.log2()),
+            Unary(Ln, x) => Self::narrow(ts, cs, *x, z.exp()),
+            Unary(Log10, x) => Self::narrow(ts, cs, *x, z.exp10()),
+            Unary(Neg, x) => Self::narrow(ts, cs, *x, -z),
+            Unary(Recip, x) => Self::narrow(ts, cs, *x, z.recip()),
+            Unary(Sqr, x) => {
+                let a = Self::domain(ts, cs, *x);
+                Self::narrow(ts, cs, *x, symmetric(a, z.intersection(NON_NEG).sqrt()))
+            }
+            Unary(Sqrt, x) => Self::narrow(ts, cs, *x, z.intersection(NON_NEG).sqr()),
+            Binary(Add, x, y) => {
+                let b = Self::domain(ts, cs, *y);
+                Self::narrow(ts, cs, *x, z - b);
+                let a = Self::domain(ts, cs, *x);
+                Self::narrow(ts, cs, *y, z - a);
+            }
+            Binary(Div, x, y) => {
+                let b = Self::domain(ts, cs, *y);
+                Self::narrow(ts, cs, *x, z * b);
+                let a = Self::domain(ts, cs, *x);
+                Self::narrow(ts, cs, *y, div_rev(a, z));
+            }
+            Binary(Mul, x, y) => {
+                let b = Self::domain(ts, cs, *y);
+                Self::narrow(ts, cs, *x, div_rev(z, b));
+                let a = Self::domain(ts, cs, *x);
+                Self::narrow(ts, cs, *y, div_rev(z, a));
+            }
+            Binary(Sub, x, y) => {
+                let b = Self::domain(ts, cs, *y);
+                Self::narrow(ts, cs, *x, z + b);
+                let a = Self::domain(ts, cs, *x);
+                Self::narrow(ts, cs, *y, a - z);
+            }
+            Ternary(MulAdd, x, y, w) => {
+                let (a, b) = (Self::domain(ts, cs, *x), Self::domain(ts, cs, *y));
+                Self::narrow(ts, cs, *w, z - a * b);
+                let zw = z - Self::domain(ts, cs, *w);
+                Self::narrow(ts, cs, *x, div_rev(zw, b));
+                let a = Self::domain(ts, cs, *x);
+                Self::narrow(ts, cs, *y, div_rev(zw, a));
+            }
+            Pown(x, 1) => Self::narrow(ts, cs, *x, z),
+            Pown(x, n) if *n >= 2 => {
+                let e = const_interval!(1.0, 1.0) / interval!(*n as f64, *n as f64).unwrap();
+                let root = |z: Interval| z.intersection(NON_NEG).pow(e);
+                let a = Self::domain(ts, cs, *x);
+                let x_new = if n % 2 == 0 {
+                    symmetric(a, root(z))
+                } else {
+                    root(z).convex_hull(-root(-z))
+                };
+                Self::narrow(ts, cs, *x, x_new)
+            }
+            _ => (),
+        }
+    }
+
+    /// Returns the domain of the term in `cs`, or the convex hull of its value in `ts`
+    /// if it has not been set.
+    fn domain(
+        ts: &ValueStore<TupperIntervalSet>,
+        cs: &ValueStore<Option<Interval>>,
+        x: StoreIndex,
+    ) -> Interval {
+        cs[x].unwrap_or_else(|| Self::hull(ts, x))
+    }
+
+    /// Intersects the domain of the term with `y`.
+    fn narrow(
+        ts: &ValueStore<TupperIntervalSet>,
+        cs: &mut ValueStore<Option<Interval>>,
+        x: StoreIndex,
+        y: Interval,
+    ) {
+        cs[x] = Some(Self::domain(ts, cs, x).intersection(y));
+    }
+
+    /// Returns an enclosure of the derivative of the function over `x`,
+    /// or [`None`] if the function is not differentiable by the implementation.
+    ///
+    /// The result is only valid if the function is continuous over `x`.
+    fn unary_derivative(op: ScalarUnaryOp, x: Interval) -> Option<Interval> {
+        use ScalarUnaryOp::*;
+        const ONE: Interval = const_interval!(1.0, 1.0);
+        const TWO: Interval = const_interval!(2.0, 2.0);
+        let d = match op {
+            Abs if x.inf() >= 0.0 => ONE,
+            Abs if x.sup() <= 0.0 => -ONE,
+            Acos => -(ONE - x.sqr()).sqrt().recip(),
+            Acosh => (x.sqr() - ONE).sqrt().recip(),
+            Asin => (ONE - x.sqr()).sqrt().recip(),
+            Asinh => (ONE + x.sqr()).sqrt().recip(),
+            Atan => (ONE + x.sqr()).recip(),
+            Atanh => (ONE - x.sqr()).recip(),
+            Cos => -x.sin(),
+            Cosh => x.sinh(),
+            Exp | Expm1 => x.exp(),
+            Exp10 => Interval::LN_10 * x.exp10(),
+            Exp2 => Interval::LN_2 * x.exp2(),
+            Ln => x.recip(),
+            Log10 => (Interval::LN_10 * x).recip(),
+            Log1p => (ONE + x).recip(),
+            Neg => -ONE,
+            Recip => -x.sqr().recip(),
+            Sin => x.cos(),
+            Sinh => x.cosh(),
+            Sqr => TWO * x,
+            Sqrt => (TWO * x.sqrt()).recip(),
+            Tanh => ONE - x.tanh().sqr(),
+            _ => return None,
+        };
+        Some(d)
+    }
+
+    /// Returns the store indices of the arguments of the term.
+    fn args(&self) -> Vec<StoreIndex> {
+        use StaticTermKind::*;
+        match &self.kind {
+            Constant(_) | X | Y | NTheta => vec![],
+            Unary(_, x) | Pown(x, _) | Rootn(x, _) => vec![*x],
+            Binary(_, x, y) => vec![*x, *y],
+            Ternary(_, x, y, z) => vec![*x, *y, *z],
+            Quaternary(_, xs) => xs.to_vec(),
+            RankedMinMax(_, xs, n) => xs.iter().copied().chain(std::iter::once(*n)).collect(),
+        }
+    }
+}
+
+/// An instruction of a [`Program`].
+///
+/// The instructions other than [`Instruction::Term`] have fast paths for the case where
+/// each argument consists of a single interval, which is by far the most common one.
+/// In the fast paths, the functions are applied directly to [`DecInterval`]s
+/// and the results are written to the value store in place.
+/// Otherwise, the terms are evaluated by [`StaticTerm::put_eval`].
+#[derive(Clone, Debug)]
+enum Instruction {
+    X(StoreIndex),
+    Y(StoreIndex),
+    NTheta(StoreIndex),
+    Unary {
+        f: fn(DecInterval) -> DecInterval,
+        x: StoreIndex,
+        dst: StoreIndex,
+        term: u32,
+    },
+    Binary {
+        f: fn(DecInterval, DecInterval) -> DecInterval,
+        x: StoreIndex,
+        y: StoreIndex,
+        dst: StoreIndex,
+        term: u32,
+    },
+    MulAdd {
+        x: StoreIndex,
+        y: StoreIndex,
+        z: StoreIndex,
+        dst: StoreIndex,
+        term: u32,
+    },
+    /// Power with an exponent that never causes a branch cut, i.e., a nonnegative or even one.
+    Pown {
+        x: StoreIndex,
+        n: i32,
+        dst: StoreIndex,
+        term: u32,
+    },
+    Term(u32),
+}
+
+/// A list of [`StaticTerm`]s compiled for fast evaluation.
+#[derive(Clone, Debug)]
+pub struct Program(Vec<Instruction>);
+
+impl Program {
+    /// Compiles the terms for which `filter` returns `true`.
+    pub fn new<F: Fn(&StaticTerm) -> bool>(terms: &[StaticTerm], filter: F) -> Self {
+        use {ScalarTernaryOp::*, StaticTermKind::*};
+        Self(
+            terms
+                .iter()
+                .enumerate()
+                .filter(|(_, t)| filter(t))
+                .map(|(i, t)| {
+                    let term = i as u32;
+                    let dst = t.store_index;
+                    match &t.kind {
+                        X => Instruction::X(dst),
+                        Y => Instruction::Y(dst),
+                        NTheta => Instruction::NTheta(dst),
+                        Unary(op, x) => match Self::unary_fn(*op) {
+                            Some(f) => Instruction::Unary {
+                                f,
+                                x: *x,
+                                dst,
+                                term,
+                            },
+                            _ => Instruction::Term(term),
+                        },
+                        Binary(op, x, y) => match Self::binary_fn(*op) {
+                            Some(f) => Instruction::Binary {
+                                f,
+                                x: *x,
+                                y: *y,
+                                dst,
+                                term,
+                            },
+                            _ => Instruction::Term(term),
+                        },
+                        Ternary(MulAdd, x, y, z) => Instruction::MulAdd {
+                            x: *x,
+                            y: *y,
+                            z: *z,
+                            dst,
+                            term,
+                        },
+                        Pown(x, n) if *n >= 0 || *n % 2 == 0 => Instruction::Pown {
+                            x: *x,
+                            n: *n,
+                            dst,
+                            term,
+                        },
+                        _ => Instruction::Term(term),
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Evaluates the program and puts the results in the value store.
+    ///
+    /// `terms` must be the ones from which the program has been compiled.
+    pub fn eval(
+        &self,
+        terms: &[StaticTerm],
+        ts: &mut ValueStore<TupperIntervalSet>,
+        x: Interval,
+        y: Interval,
+        n_theta: Interval,
+    ) {
+        for instr in &self.0 {
+            match *instr {
+                Instruction::X(dst) => ts[dst] = DecInterval::new(x).into(),
+                Instruction::Y(dst) => ts[dst] = DecInterval::new(y).into(),
+                Instruction::NTheta(dst) => ts[dst] = DecInterval::new(n_theta).into(),
+                Instruction::Unary { f, x, dst, term } => {
+                    if let Some(x) = ts[x].single() {
+                        ts[dst].set_single(TupperInterval::new(f(x.dec_interval()), x.g));
+                    } else {
+                        terms[term as usize].put_eval(ts);
+                    }
+                }
+                Instruction::Binary { f, x, y, dst, term } => {
+                    if let (Some(x), Some(y)) = (ts[x].single(), ts[y].single()) {
+                        if let Some(g) = x.g.union(y.g) {
+                            ts[dst].set_single(TupperInterval::new(
+                                f(x.dec_interval(), y.dec_interval()),
+                                g,
+                            ));
+                            continue;
+                        }
+                    }
+                    terms[term as usize].put_eval(ts);
+                }
+                Instruction::MulAdd { x, y, z, dst, term } => {
+                    if let (Some(x), Some(y), Some(z)) =
+                        (ts[x].single(), ts[y].single(), ts[z].single())
+                    {
+                        if let Some(g) = x.g.union(y.g).and_then(|g| g.union(z.g)) {
+                            ts[dst].set_single(TupperInterval::new(
+                                x.dec_interval().mul_add(y.dec_interval(), z.dec_interval()),
+                                g,
+                            ));
+                            continue;
+                        }
+                    }
+                    terms[term as usize].put_eval(ts);
+                }
+                Instruction::Pown { x, n, dst, term } => {
+                    if let Some(x) = ts[x].single() {
+                        ts[dst].set_single(TupperInterval::new(x.dec_interval().pown(n), x.g));
+                    } else {
+                        terms[term as usize].put_eval(ts);
+                    }
+                }
+                Instruction::Term(term) => terms[term as usize].put_eval(ts),
+            }
+        }
+    }
+
+    /// Evaluates the program at each of the given points and puts the results in `bs`.
+    ///
+    /// The values of the constant terms must have been stored in `bs` for all points.
+    /// `ts` is used as a scratch store for evaluating the terms without fast paths.
+    ///
+    /// `terms` must be the ones from which the program has been compiled.
+    pub fn eval_points(
+        &self,
+        terms: &[StaticTerm],
+        bs: &mut ValueStore<PointValues>,
+        ts: &mut ValueStore<TupperIntervalSet>,
+        points: &[(f64, f64)],
+        n_theta: Interval,
+    ) {
+        use PointValues::*;
+        let n = points.len();
+        let dec = |x: Interval, d: Decoration| {
+            TupperInterval {
+                x,
+                d,
+                g: BranchMap::new(),
+            }
+            .dec_interval()
+        };
+        for instr in &self.0 {
+            match *instr {
+                Instruction::X(dst) => {
+                    bs[dst].fill_single(n, |i| {
+                        DecInterval::new(interval!(points[i].0, points[i].0).unwrap())
+                    });
+                }
+                Instruction::Y(dst) => {
+                    bs[dst].fill_single(n, |i| {
+                        DecInterval::new(interval!(points[i].1, points[i].1).unwrap())
+                    });
+                }
+                Instruction::NTheta(dst) => {
+                    bs[dst].fill_single(n, |_| DecInterval::new(n_theta));
+                }
+                Instruction::Unary { f, x, dst, term } => {
+                    let mut ds = take(&mut bs[dst]);
+                    match &bs[x] {
+                        Single(xs, xd) => ds.fill_single(n, |i| f(dec(xs[i], xd[i]))),
+                        xs => {
+                            ds = PointValues::from_sets(
+                                (0..n)
+                                    .map(|i| match xs.single(i) {
+                                        Some(x) => {
+                                            TupperInterval::new(f(x.dec_interval()), x.g).into()
+                                        }
+                                        _ => Self::put_eval_point(&terms[term as usize], bs, ts, i),
+                                    })
+                                    .collect(),
+                            )
+                        }
+                    }
+                    bs[dst] = ds;
+                }
+                Instruction::Binary { f, x, y, dst, term } => {
+                    let mut ds = take(&mut bs[dst]);
+                    match (&bs[x], &bs[y]) {
+                        (Single(xs, xd), Single(ys, yd)) => {
+                            ds.fill_single(n, |i| f(dec(xs[i], xd[i]), dec(ys[i], yd[i])))
+                        }
+                        (xs, ys) => {
+                            ds = PointValues::from_sets(
+                                (0..n)
+                                    .map(|i| match (xs.single(i), ys.single(i)) {
+                                        (Some(x), Some(y)) => match x.g.union(y.g) {
+                                            Some(g) => TupperInterval::new(
+                                                f(x.dec_interval(), y.dec_interval()),
+                                                g,
+                                            )
+                                            .into(),
+                                            _ => Self::put_eval_point(
+                                                &terms[term as usize],
+                                                bs,
+                                                ts,
+                                                i,
+                                            ),
+                                        },
+                                        _ => Self::put_eval_point(&terms[term as usize], bs, ts, i),
+                                    })
+                                    .collect(),
+                            )
+                        }
+                    }
+                    bs[dst] = ds;
+                }
+                Instruction::MulAdd { x, y, z, dst, term } => {
+                    let mut ds = take(&mut bs[dst]);
+                    match (&bs[x], &bs[y], &bs[z]) {
+                        (Single(xs, xd), Single(ys, yd), Single(zs, zd)) => ds
+                            .fill_single(n, |i| {
+                                dec(xs[i], xd[i]).mul_add(dec(ys[i], yd[i]), dec(zs[i], zd[i]))
+                            }),
+                        (xs, ys, zs) => {
+                            ds = PointValues::from_sets(
+                                (0..n)
+                                    .map(|i| match (xs.single(i), ys.single(i), zs.single(i)) {
+                                        (Some(x), Some(y), Some(z)) => {
+                                            match x.g.union(y.g).and_then(|g| g.union(z.g)) {
+                                                Some(g) => TupperInterval::new(
+                                                    x.dec_interval().mul_add(
+                                                        y.dec_interval(),
+                                                        z.dec_interval(),
+                                                    ),
+                                                    g,
+                                                )
+                                                .into(),
+                                                _ => Self::put_eval_point(
+                                                    &terms[term as usize],
+                                                    bs,
+                                                    ts,
+                                                    i,
+                                                ),
+                                            }
+                                        }
+                                        _ => Self::put_eval_point(&terms[term as usize], bs, ts, i),
+                                    })
+                                    .collect(),
+                            )
+                        }
+                    }
+                    bs[dst] = ds;
+                }
+                Instruction::Pown { x, n: k, dst, term } => {
+                    let mut ds = take(&mut bs[dst]);
+                    match &bs[x] {
+                        Single(xs, xd) => ds.fill_single(n, |i| dec(xs[i], xd[i]).pown(k)),
+                        xs => {
+                            ds = PointValues::from_sets(
+                                (0..n)
+                                    .map(|i| match xs.single(i) {
+                                        Some(x) => {
+                                            TupperInterval::new(x.dec_interval().pown(k), x.g)
+                                                .into()
+                                        }
+                                        _ => Self::put_eval_point(&terms[term as usize], bs, ts, i),
+                                    })
+                                    .collect(),
+                            )
+                        }
+                    }
+                    bs[dst] = ds;
+                }
+                Instruction::Term(term) => {
+                    let t = &terms[term as usize];
+                    bs[t.store_index] = PointValues::from_sets(
+                        (0..n).map(|i| Self::put_eval_point(t, bs, ts, i)).collect(),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Evaluates the term at the `i`th point with `ts` as a scratch store and returns the result.
+    fn put_eval_point(
+        t: &StaticTerm,
+        bs: &ValueStore<PointValues>,
+        ts: &mut ValueStore<TupperIntervalSet>,
+        i: usize,
+    ) -> TupperIntervalSet {
+        for x in t.args() {
+            ts[x] = bs[x].get(i);
+        }
+        t.put_eval(ts);
+        take(&mut ts[t.store_index])
+    }
+
+    /// Returns the function that computes the operation on a single interval
+    /// in the same way as the corresponding method of [`TupperIntervalSet`].
+    ///
+    /// The operations that are implemented with Arb when the feature `arb` is enabled
+    /// have no such functions then.
+    fn unary_fn(op: ScalarUnaryOp) -> Option<fn(DecInterval) -> DecInterval> {
+        #[cfg(feature = "arb")]
+        use ScalarUnaryOp::*;
+        match op {
+            #[cfg(feature = "arb")]
+            Acos | Acosh | Asin | Asinh | Atan | Atanh | Cos | Cosh | Exp | Exp10 | Exp2 | Ln
+            | Log10 | Sin | Sinh | Tanh => None,
+            _ => Self::inari_unary_fn(op),
+        }
+    }
+
+    /// Returns the function that computes the operation on a single interval with inari.
+    fn inari_unary_fn(op: ScalarUnaryOp) -> Option<fn(DecInterval) -> DecInterval> {
+        use ScalarUnaryOp::*;
+        let f: fn(DecInterval) -> DecInterval = match op {
+            Abs => |x| x.abs(),
+            Acos => |x| x.acos(),
+            Acosh => |x| x.acosh(),
+            Asin => |x| x.asin(),
+            Asinh => |x| x.asinh(),
+            Atan => |x| x.atan(),
+            Atanh => |x| x.atanh(),
+            Cos => |x| x.cos(),
+            Cosh => |x| x.cosh(),
+            Exp => |x| x.exp(),
+            Exp10 => |x| x.exp10(),
+            Exp2 => |x| x.exp2(),
+            Ln => |x| x.ln(),
+            Log10 => |x| x.log10(),
+            Neg => |x| -x,
+            Sin => |x| x.sin(),
+            Sinh => |x| x.sinh(),
+            Sqr => |x| x.sqr(),
+            Sqrt => |x| x.sqrt(),
+            Tanh => |x| x.tanh(),
+            _ => return None,
+        };
+        Some(f)
+    }
+
+    /// Returns the function that computes the operation on single intervals
+    /// in the same way as the corresponding method of [`TupperIntervalSet`].
+    fn binary_fn(op: ScalarBinaryOp) -> Option<fn(DecInterval, DecInterval) -> DecInterval> {
+        use ScalarBinaryOp::*;
+        let f: fn(DecInterval, DecInterval) -> DecInterval = match op {
+            Add => |x, y| x + y,
+            Max => |x, y| x.max(y),
+            Min => |x, y| x.min(y),
+            Mul => |x, y| x * y,
+            Sub => |x, y| x - y,
+            _ => return None,
+        };
+        Some(f)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -283,15 +1484,41 @@ impl StaticForm {
     ///
     /// Panics if the formula is *not* of the kind [`StaticFormKind::Atomic`].
     pub fn eval(&self, ts: &ValueStore<TupperIntervalSet>) -> DecSignSet {
-        use {RelOp::*, StaticFormKind::*};
         match &self.kind {
-            Atomic(EqZero, x) => ts[*x].eq_zero(),
-            Atomic(LeZero, x) => ts[*x].le_zero(),
-            Atomic(LtZero, x) => ts[*x].lt_zero(),
-            Atomic(NeqZero, x) => ts[*x].neq_zero(),
-            Atomic(NleZero, x) => ts[*x].nle_zero(),
-            Atomic(NltZero, x) => ts[*x].nlt_zero(),
-            And(_, _) | Or(_, _) => panic!("non-atomic formulas cannot be evaluated"),
+            StaticFormKind::Atomic(op, x) => Self::eval_atomic(*op, &ts[*x]),
+            _ => panic!("non-atomic formulas cannot be evaluated"),
+        }
+    }
+
+    /// Evaluates the formula at the `i`th point of the values computed by [`Program::eval_points`].
+    ///
+    /// Panics if the formula is *not* of the kind [`StaticFormKind::Atomic`].
+    pub fn eval_point(&self, bs: &ValueStore<PointValues>, i: usize) -> DecSignSet {
+        match &self.kind {
+            StaticFormKind::Atomic(op, x) => Self::eval_atomic(*op, &bs[*x].get(i)),
+            _ => panic!("non-atomic formulas cannot be evaluated"),
+        }
+    }
+
+    /// Evaluates the formula with the given value of its term.
+    ///
+    /// Panics if the formula is *not* of the kind [`StaticFormKind::Atomic`].
+    pub fn eval_with(&self, x: &TupperIntervalSet) -> DecSignSet {
+        match &self.kind {
+            StaticFormKind::Atomic(op, _) => Self::eval_atomic(*op, x),
+            _ => panic!("non-atomic formulas cannot be evaluated"),
+        }
+    }
+
+    fn eval_atomic(op: RelOp, x: &TupperIntervalSet) -> DecSignSet {
+        use RelOp::*;
+        match op {
+            EqZero => x.eq_zero(),
+            LeZero => x.le_zero(),
+            LtZero => x.lt_zero(),
+            NeqZero => x.neq_zero(),
+            NleZero => x.nle_zero(),
+            NltZero => x.nlt_zero(),
         }
     }
 }
@@ -299,8 +1526,86 @@ impl StaticForm {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use inari::const_interval;
     use std::mem::size_of;
 
+    #[test]
+    fn program() {
+        use {ScalarBinaryOp::*, ScalarUnaryOp::*, StaticTermKind::*};
+
+        fn term(kind: StaticTermKind, i: u32) -> StaticTerm {
+            StaticTerm {
+                site: None,
+                kind,
+                vars: VarSet::XY,
+                store_index: StoreIndex::new(i),
+            }
+        }
+
+        let (x, y) = (StoreIndex::new(0), StoreIndex::new(1));
+        let kinds = vec![
+            Unary(Abs, x),
+            Unary(Acos, x),
+            Unary(Acosh, x),
+            Unary(Asin, x),
+            Unary(Asinh, x),
+            Unary(Atan, x),
+            Unary(Atanh, x),
+            Unary(Cos, x),
+            Unary(Cosh, x),
+            Unary(Exp, x),
+            Unary(Exp10, x),
+            Unary(Exp2, x),
+            Unary(Ln, x),
+            Unary(Log10, x),
+            Unary(Neg, x),
+            Unary(Sin, x),
+            Unary(Sinh, x),
+            Unary(Sqr, x),
+            Unary(Sqrt, x),
+            Unary(Tanh, x),
+            Binary(Add, x, y),
+            Binary(Max, x, y),
+            Binary(Min, x, y),
+            Binary(Mul, x, y),
+            Binary(Sub, x, y),
+            Ternary(ScalarTernaryOp::MulAdd, x, y, x),
+            Pown(x, 3),
+            Pown(x, -2),
+            Pown(x, -1),
+            Unary(Recip, x),
+        ];
+        let mut terms = vec![term(X, 0), term(Y, 1)];
+        terms.extend(
+            kinds
+                .into_iter()
+                .enumerate()
+                .map(|(i, k)| term(k, i as u32 + 2)),
+        );
+        let n_terms = terms.len();
+
+        for &(x, y) in &[
+            (const_interval!(-2.0, 0.5), const_interval!(0.25, 3.0)),
+            (const_interval!(0.5, 0.75), const_interval!(-3.0, -2.0)),
+            (Interval::EMPTY, const_interval!(-1.0, 1.0)),
+        ] {
+            let mut ts = ValueStore::new(TupperIntervalSet::new(), n_terms);
+            Program::new(&terms, |_| true).eval(&terms, &mut ts, x, y, Interval::ENTIRE);
+
+            let mut expected = ValueStore::new(TupperIntervalSet::new(), n_terms);
+            expected[StoreIndex::new(0)] = DecInterval::new(x).into();
+            expected[StoreIndex::new(1)] = DecInterval::new(y).into();
+            for t in &terms[2..] {
+                t.put_eval(&mut expected);
+            }
+
+            for i in 0..n_terms as u32 {
+                let i = StoreIndex::new(i);
+                assert_eq!(ts[i], expected[i]);
+            }
+        }
+    }
+
     #[test]
     fn struct_size() {
         assert_eq!(size_of::<StaticTermKind>(), 16);
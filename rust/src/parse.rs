@@ -170,6 +170,20 @@ fn primary_expr(i: InputWithContext) -> ParseResult<Expr> {
     ))(i)
 }
 
+// The factorial x! binds tighter than ^: x^y! is the same as x^(y!).
+// x!! is rejected so that it will not be confused with the double factorial.
+fn postfix_expr(i: InputWithContext) -> ParseResult<Expr> {
+    let ctx = i.ctx;
+
+    map(
+        pair(primary_expr, opt(pair(char('!'), not(peek(char('!')))))),
+        move |(x, bang)| match bang {
+            Some(_) => ctx.apply("factorial", vec![x]).unwrap(),
+            None => x,
+        },
+    )(i)
+}
+
 // ^ is right-associative: x^y^z is the same as x^(y^z).
 fn power_expr(i: InputWithContext) -> ParseResult<Expr> {
     let ctx = i.ctx;
@@ -177,13 +191,13 @@ fn power_expr(i: InputWithContext) -> ParseResult<Expr> {
     alt((
         map(
             separated_pair(
-                primary_expr,
+                postfix_expr,
                 delimited(space0, char('^'), space0),
                 cut(unary_expr),
             ),
             move |(x, y)| ctx.apply("^", vec![x, y]).unwrap(),
         ),
-        primary_expr,
+        postfix_expr,
     ))(i)
 }
 
@@ -388,12 +402,14 @@ fn convert_error(input: InputWithContext, e: VerboseError<InputWithContext>) ->
 #[cfg(test)]
 mod tests {
     use crate::context::Context;
+    use std::time::{Duration, Instant};
 
     #[test]
     fn parse_expr() {
         test_parse_expr("e", "@");
         test_parse_expr("gamma", "@");
         test_parse_expr("γ", "@");
+        test_parse_expr("gamma(x)", "(Mul @ x)");
         test_parse_expr("pi", "@");
         test_parse_expr("π", "@");
         test_parse_expr("[x, y, z]", "(List x y z)");
@@ -419,37 +435,108 @@ mod tests {
         test_parse_expr("cosh(x)", "(Cosh x)");
         test_parse_expr("psi(x)", "(Digamma x)");
         test_parse_expr("ψ(x)", "(Digamma x)");
+        test_parse_expr("psi(n, x)", "(Polygamma n x)");
+        test_parse_expr("ψ(n, x)", "(Polygamma n x)");
         test_parse_expr("Ei(x)", "(Ei x)");
         test_parse_expr("E(x)", "(EllipticE x)");
+        test_parse_expr("EllipticE(x)", "(EllipticE x)");
         test_parse_expr("K(x)", "(EllipticK x)");
         test_parse_expr("erf(x)", "(Erf x)");
         test_parse_expr("erfc(x)", "(Erfc x)");
+        test_parse_expr("erfcinv(x)", "(Erfcinv x)");
         test_parse_expr("erfi(x)", "(Erfi x)");
+        test_parse_expr("erfinv(x)", "(Erfinv x)");
         test_parse_expr("exp(x)", "(Exp x)");
+        test_parse_expr("factorial(x)", "(Factorial x)");
         test_parse_expr("floor(x)", "(Floor x)");
         test_parse_expr("C(x)", "(FresnelC x)");
         test_parse_expr("S(x)", "(FresnelS x)");
         test_parse_expr("Gamma(x)", "(Gamma x)");
         test_parse_expr("Γ(x)", "(Gamma x)");
+        test_parse_expr("Heaviside(x)", "(Heaviside x)");
+        test_parse_expr("isprime(x)", "(IsPrime x)");
         test_parse_expr("li(x)", "(Li x)");
         test_parse_expr("ln(x)", "(Ln x)");
+        test_parse_expr("lnGamma(x)", "(LnGamma x)");
+        test_parse_expr("lnΓ(x)", "(LnGamma x)");
         test_parse_expr("log(x)", "(Log10 x)");
+        test_parse_expr("mobius(x)", "(Mobius x)");
+        test_parse_expr("μ(x)", "(Mobius x)");
+        test_parse_expr("numdivisors(x)", "(NumDivisors x)");
+        test_parse_expr("popcount(x)", "(Popcount x)");
+        test_parse_expr("primepi(x)", "(PrimePi x)");
+        test_parse_expr("rect(x)", "(Rect x)");
+        test_parse_expr("sawtooth(x)", "(SawtoothWave x)");
         test_parse_expr("Shi(x)", "(Shi x)");
         test_parse_expr("Si(x)", "(Si x)");
+        test_parse_expr("sgn(x)", "(Sign x)");
+        test_parse_expr("sign(x)", "(Sign x)");
         test_parse_expr("sin(x)", "(Sin x)");
         test_parse_expr("sinh(x)", "(Sinh x)");
         test_parse_expr("sqrt(x)", "(Sqrt x)");
+        test_parse_expr("square(x)", "(SquareWave x)");
         test_parse_expr("tan(x)", "(Tan x)");
         test_parse_expr("tanh(x)", "(Tanh x)");
+        test_parse_expr("totient(x)", "(Totient x)");
+        test_parse_expr("tri(x)", "(Tri x)");
+        test_parse_expr("φ(x)", "(Totient x)");
         test_parse_expr("atan2(y, x)", "(Atan2 y x)");
         test_parse_expr("I(n, x)", "(BesselI n x)");
         test_parse_expr("J(n, x)", "(BesselJ n x)");
         test_parse_expr("K(n, x)", "(BesselK n x)");
         test_parse_expr("Y(n, x)", "(BesselY n x)");
+        test_parse_expr("i(n, x)", "(SphericalBesselI n x)");
+        test_parse_expr("j(n, x)", "(SphericalBesselJ n x)");
+        test_parse_expr("k(n, x)", "(SphericalBesselK n x)");
+        test_parse_expr("y(n, x)", "(SphericalBesselY n x)");
+        test_parse_expr("StruveH(nu, x)", "(StruveH nu x)");
+        test_parse_expr("StruveL(nu, x)", "(StruveL nu x)");
+        test_parse_expr("Beta(x, y)", "(Beta x y)");
+        test_parse_expr("binom(n, k)", "(Binom n k)");
+        test_parse_expr("bitand(x, y)", "(BitAnd x y)");
+        test_parse_expr("bitor(x, y)", "(BitOr x y)");
+        test_parse_expr("bitxor(x, y)", "(BitXor x y)");
+        test_parse_expr("T(n, x)", "(ChebyshevT n x)");
+        test_parse_expr("U(n, x)", "(ChebyshevU n x)");
+        test_parse_expr("sigma(k, n)", "(DivisorSigma k n)");
+        test_parse_expr("σ(k, n)", "(DivisorSigma k n)");
+        test_parse_expr("EllipticE(phi, m)", "(EllipticEInc phi m)");
+        test_parse_expr("F(phi, m)", "(EllipticF phi m)");
+        test_parse_expr("E(n, x)", "(ExpIntegralE n x)");
         test_parse_expr("Gamma(a, x)", "(GammaInc a x)");
         test_parse_expr("Γ(a, x)", "(GammaInc a x)");
+        test_parse_expr("gammaLower(a, x)", "(GammaIncLower a x)");
+        test_parse_expr("Q(a, x)", "(GammaRegularized a x)");
+        test_parse_expr("gammaQ(a, x)", "(GammaRegularized a x)");
+        test_parse_expr("gammaP(a, x)", "(GammaRegularizedLower a x)");
+        test_parse_expr("H(n, x)", "(HermiteH n x)");
+        test_parse_expr("hyp0f1(b, x)", "(Hyp0F1 b x)");
+        test_parse_expr("am(u, m)", "(JacobiAm u m)");
+        test_parse_expr("cn(u, m)", "(JacobiCn u m)");
+        test_parse_expr("dn(u, m)", "(JacobiDn u m)");
+        test_parse_expr("sn(u, m)", "(JacobiSn u m)");
+        test_parse_expr("L(n, x)", "(LaguerreL n x)");
+        test_parse_expr("P(n, x)", "(LegendreP n x)");
         test_parse_expr("log(b, x)", "(Log b x)");
         test_parse_expr("mod(x, y)", "(Mod x y)");
+        test_parse_expr("poch(x, n)", "(Poch x n)");
+        test_parse_expr("P(n, m, x)", "(AssocLegendreP n m x)");
+        test_parse_expr("I(x, a, b)", "(BetaRegularized x a b)");
+        test_parse_expr("clamp(x, a, b)", "(Clamp x a b)");
+        test_parse_expr("Pi(n, phi, m)", "(EllipticPi n phi m)");
+        test_parse_expr("Π(n, phi, m)", "(EllipticPi n phi m)");
+        test_parse_expr("hyp1f1(a, b, x)", "(Hyp1F1 a b x)");
+        test_parse_expr("hyp2f1(a, b, c, x)", "(Hyp2F1 a b c x)");
+        test_parse_expr("normcdf(x)", "(Div (Erfc (Div (Neg x) (Sqrt 2))) 2)");
+        test_parse_expr(
+            "normcdf(x, m, s)",
+            "(Div (Erfc (Div (Neg (Div (Sub x m) s)) (Sqrt 2))) 2)",
+        );
+        test_parse_expr(
+            "chi2cdf(x, k)",
+            "(GammaRegularizedLower (Div k 2) (Div (Max x 0) 2))",
+        );
+        test_parse_expr("bitand(x, y, z)", "(BitAnd (BitAnd x y) z)");
         test_parse_expr("gcd(x, y, z)", "(Gcd (Gcd x y) z)");
         test_parse_expr("lcm(x, y, z)", "(Lcm (Lcm x y) z)");
         test_parse_expr("max(x, y, z)", "(Max (Max x y) z)");
@@ -457,6 +544,11 @@ mod tests {
         test_parse_expr("ranked_max([x, y, z], k)", "(RankedMax (List x y z) k)");
         test_parse_expr("ranked_min([x, y, z], k)", "(RankedMin (List x y z) k)");
         test_parse_expr("x ^ y ^ z", "(Pow x (Pow y z))");
+        test_parse_expr("x!", "(Factorial x)");
+        test_parse_expr("-x!", "(Neg (Factorial x))");
+        test_parse_expr("x!^y!", "(Pow (Factorial x) (Factorial y))");
+        test_parse_expr("(x!)!", "(Factorial (Factorial x))");
+        test_parse_expr("2x!", "(Mul 2 (Factorial x))");
         test_parse_expr("-x ^ -y", "(Neg (Pow x (Neg y)))");
         test_parse_expr("+x", "x");
         test_parse_expr("-x", "(Neg x)");
@@ -487,16 +579,15 @@ mod tests {
             "(x = y || y = z) && z = x",
             "(And (Or (Eq x y) (Eq y z)) (Eq z x))",
         );
+    }
 
-        // TODO: Do we need this?
-        test_parse_expr(
-            "sgn(x)",
-            "(Add (Floor (Min (Max x (Neg 0.5)) 0.5)) (Ceil (Min (Max x (Neg 0.5)) 0.5)))",
-        );
-        test_parse_expr(
-            "sign(x)",
-            "(Add (Floor (Min (Max x (Neg 0.5)) 0.5)) (Ceil (Min (Max x (Neg 0.5)) 0.5)))",
-        );
+    #[test]
+    fn parse_nested_expr() {
+        let n = 12;
+        let input = format!("y = {}x{}", "sin(".repeat(n), ")".repeat(n));
+        let now = Instant::now();
+        super::parse_expr(&input, Context::builtin_context()).unwrap();
+        assert!(now.elapsed() < Duration::from_secs(5));
     }
 
     fn test_parse_expr(input: &str, expected: &str) {
@@ -1,6 +1,63 @@
+use crate::number_theory;
 use gmp_mpfr_sys::{mpfr, mpfr::rnd_t};
 use inari::{interval, Interval};
-use rug::{Float, Rational};
+use rug::{ops::Pow, Float, Integer, Rational};
+
+/// The maximum integer argument for which factorials and related functions are computed exactly.
+const MAX_FACTORIAL_ARG: u32 = 1000;
+
+// B(x, y) = (y - 1)! / poch(x, y) for y ∈ ℤ_{>0}.
+pub fn beta(x: Rational, y: Rational) -> Option<Rational> {
+    if *x.denom() == 1 && x <= 0 || *y.denom() == 1 && y <= 0 {
+        // B(x, y) is undefined as Γ(x) or Γ(y) has a pole.
+        return None;
+    }
+    let (x, y) = if *y.denom() == 1 && y > 0 {
+        (x, y)
+    } else if *x.denom() == 1 && x > 0 {
+        (y, x)
+    } else {
+        return None;
+    };
+    let n = Rational::from(&y - 1);
+    div(factorial(n)?, poch(x, y)?)
+}
+
+pub fn binom(x: Rational, y: Rational) -> Option<Rational> {
+    if *y.denom() != 1 {
+        return None;
+    }
+    if y < 0 {
+        return Some(Rational::new());
+    }
+    let k = y.numer().to_u32().filter(|&k| k <= MAX_FACTORIAL_ARG)?;
+    if *x.denom() == 1 {
+        Some(Integer::from(x.numer().binomial_ref(k)).into())
+    } else {
+        // x (x - 1) … (x - k + 1) / k!.
+        let mut z = Rational::from(1);
+        for i in 0..k {
+            z *= Rational::from(&x - i);
+            z /= i + 1;
+        }
+        Some(z)
+    }
+}
+
+pub fn bit_and(x: Rational, y: Rational) -> Option<Rational> {
+    let (x, y) = to_integers(&x, &y)?;
+    Some(Integer::from(x & y).into())
+}
+
+pub fn bit_or(x: Rational, y: Rational) -> Option<Rational> {
+    let (x, y) = to_integers(&x, &y)?;
+    Some(Integer::from(x | y).into())
+}
+
+pub fn bit_xor(x: Rational, y: Rational) -> Option<Rational> {
+    let (x, y) = to_integers(&x, &y)?;
+    Some(Integer::from(x ^ y).into())
+}
 
 pub fn div(x: Rational, y: Rational) -> Option<Rational> {
     if y == 0 {
@@ -10,6 +67,37 @@ pub fn div(x: Rational, y: Rational) -> Option<Rational> {
     }
 }
 
+// σ_k(n) = ∏_{p^e ∥ n} ∑_{j=0}^e p^{jk} for k ∈ ℤ_{≥0} and σ_{-k}(n) = σ_k(n) / n^k.
+pub fn divisor_sigma(k: Rational, n: Rational) -> Option<Rational> {
+    let n = to_positive_u64(&n)?;
+    if *k.denom() != 1 {
+        return None;
+    }
+    let k_abs = k.numer().to_i32()?.unsigned_abs();
+    if k_abs > MAX_FACTORIAL_ARG {
+        return None;
+    }
+    let sigma = number_theory::factorize(n)
+        .iter()
+        .fold(Integer::from(1), |y, &(p, e)| {
+            let s = (0..=e).fold(Integer::new(), |s, j| s + Integer::from(p).pow(j * k_abs));
+            y * s
+        });
+    if k >= 0 {
+        Some(sigma.into())
+    } else {
+        Some(Rational::from((sigma, Integer::from(n).pow(k_abs))))
+    }
+}
+
+pub fn factorial(x: Rational) -> Option<Rational> {
+    if *x.denom() != 1 {
+        return None;
+    }
+    let n = x.numer().to_u32().filter(|&n| n <= MAX_FACTORIAL_ARG)?;
+    Some(Integer::from(Integer::factorial(n)).into())
+}
+
 pub fn gcd(mut x: Rational, mut y: Rational) -> Option<Rational> {
     while y != 0 {
         let rem = rem_euclid(x, y.clone())?;
@@ -19,6 +107,22 @@ pub fn gcd(mut x: Rational, mut y: Rational) -> Option<Rational> {
     Some(x.abs())
 }
 
+pub fn heaviside(x: Rational) -> Option<Rational> {
+    Some((x.signum() + 1u32) / 2u32)
+}
+
+pub fn is_prime(x: Rational) -> Option<Rational> {
+    if *x.denom() != 1 {
+        return None;
+    }
+    let y = match x.numer().to_u64() {
+        Some(n) => number_theory::is_prime(n),
+        _ if x < 0 => false,
+        _ => return None,
+    };
+    Some((y as u32).into())
+}
+
 pub fn lcm(x: Rational, y: Rational) -> Option<Rational> {
     if x == 0 && y == 0 {
         Some(Rational::new())
@@ -44,6 +148,48 @@ pub fn min(x: Rational, y: Rational) -> Option<Rational> {
     }
 }
 
+pub fn mobius(x: Rational) -> Option<Rational> {
+    let n = to_positive_u64(&x)?;
+    Some(number_theory::mobius(n).into())
+}
+
+pub fn num_divisors(x: Rational) -> Option<Rational> {
+    let n = to_positive_u64(&x)?;
+    Some(number_theory::num_divisors(n).into())
+}
+
+pub fn poch(x: Rational, y: Rational) -> Option<Rational> {
+    if *y.denom() != 1 {
+        return None;
+    }
+    let n = y.numer().to_i32()?;
+    if n.unsigned_abs() > MAX_FACTORIAL_ARG {
+        return None;
+    }
+    if n >= 0 {
+        // x (x + 1) … (x + n - 1).
+        let mut z = Rational::from(1);
+        for i in 0..n {
+            z *= Rational::from(&x + i);
+        }
+        Some(z)
+    } else {
+        // 1 / ((x - 1) (x - 2) … (x + n)).
+        let mut z = Rational::from(1);
+        for i in 1..=-n {
+            z *= Rational::from(&x - i);
+        }
+        div(Rational::from(1), z)
+    }
+}
+
+pub fn popcount(x: Rational) -> Option<Rational> {
+    if *x.denom() != 1 {
+        return None;
+    }
+    Some(x.numer().clone().abs().count_ones()?.into())
+}
+
 pub fn pow(x: Rational, y: Rational) -> Option<Rational> {
     let xn = x.numer().to_i32()?;
     let xd = x.denom().to_u32()?;
@@ -75,6 +221,18 @@ pub fn pow(x: Rational, y: Rational) -> Option<Rational> {
     }
 }
 
+pub fn prime_pi(x: Rational) -> Option<Rational> {
+    if x < 2 {
+        return Some(Rational::new());
+    }
+    let n = x.floor().numer().to_u64()?;
+    Some(number_theory::prime_pi(n)?.into())
+}
+
+pub fn rect(x: Rational) -> Option<Rational> {
+    heaviside(Rational::from((1, 2)) - x.abs())
+}
+
 pub fn rem_euclid(x: Rational, y: Rational) -> Option<Rational> {
     if y == 0 {
         None
@@ -84,6 +242,26 @@ pub fn rem_euclid(x: Rational, y: Rational) -> Option<Rational> {
     }
 }
 
+pub fn sawtooth_wave(x: Rational) -> Option<Rational> {
+    if *x.denom() == 1 {
+        Some(Rational::new())
+    } else {
+        let n = x.clone().floor();
+        Some(2u32 * (x - n) - 1u32)
+    }
+}
+
+pub fn square_wave(x: Rational) -> Option<Rational> {
+    let t = 2u32 * x;
+    if *t.denom() == 1 {
+        Some(Rational::new())
+    } else if t.floor().numer().is_even() {
+        Some(Rational::from(1))
+    } else {
+        Some(Rational::from(-1))
+    }
+}
+
 // Based on `inari::parse::rational_to_f64`.
 #[allow(clippy::many_single_char_names)]
 pub fn to_interval(r: &Rational) -> Interval {
@@ -108,6 +286,31 @@ pub fn to_interval(r: &Rational) -> Interval {
     }
 }
 
+pub fn totient(x: Rational) -> Option<Rational> {
+    let n = to_positive_u64(&x)?;
+    Some(number_theory::totient(n).into())
+}
+
+pub fn tri(x: Rational) -> Option<Rational> {
+    max(1u32 - x.abs(), Rational::new())
+}
+
+/// Returns the numerators of `x` and `y` if both of them are integers; otherwise, [`None`].
+fn to_integers<'a>(x: &'a Rational, y: &'a Rational) -> Option<(&'a Integer, &'a Integer)> {
+    if *x.denom() != 1 || *y.denom() != 1 {
+        return None;
+    }
+    Some((x.numer(), y.numer()))
+}
+
+/// Returns `Some(n)` if `x` is a positive integer that fits in [`u64`]; otherwise, [`None`].
+fn to_positive_u64(x: &Rational) -> Option<u64> {
+    if *x.denom() != 1 {
+        return None;
+    }
+    x.numer().to_u64().filter(|&n| n > 0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -153,6 +356,38 @@ mod tests {
         };
     }
 
+    #[test]
+    fn beta() {
+        use super::beta;
+        test!(@commut beta, r!(0), r!(2), None);
+        test!(@commut beta, r!(-1), r!(2), None);
+        test!(@commut beta, r!(2), r!(3), Some(r!(1 / 12)));
+        test!(@commut beta, r!(1 / 2), r!(2), Some(r!(4 / 3)));
+        test!(beta, r!(1 / 2), r!(1 / 2), None);
+    }
+
+    #[test]
+    fn binom() {
+        use super::binom;
+        test!(binom, r!(5), r!(-1), Some(r!(0)));
+        test!(binom, r!(5), r!(0), Some(r!(1)));
+        test!(binom, r!(5), r!(2), Some(r!(10)));
+        test!(binom, r!(-2), r!(2), Some(r!(3)));
+        test!(binom, r!(1 / 2), r!(2), Some(r!(-1 / 8)));
+        test!(binom, r!(5), r!(1 / 2), None);
+    }
+
+    #[test]
+    fn bitwise() {
+        test!(@commut bit_and, r!(12), r!(10), Some(r!(8)));
+        test!(@commut bit_or, r!(12), r!(10), Some(r!(14)));
+        test!(@commut bit_xor, r!(12), r!(10), Some(r!(6)));
+        test!(@commut bit_and, r!(-1), r!(5), Some(r!(5)));
+        test!(@commut bit_or, r!(-8), r!(3), Some(r!(-5)));
+        test!(@commut bit_xor, r!(-1), r!(5), Some(r!(-6)));
+        test!(@commut bit_and, r!(1 / 2), r!(1), None);
+    }
+
     #[test]
     fn div() {
         use super::div;
@@ -161,6 +396,27 @@ mod tests {
         test!(div, @odd r!(2 / 3), @odd r!(4 / 5), Some(r!(5 / 6)));
     }
 
+    #[test]
+    fn divisor_sigma() {
+        use super::divisor_sigma;
+        test!(divisor_sigma, r!(0), r!(0), None);
+        test!(divisor_sigma, r!(0), r!(12), Some(r!(6)));
+        test!(divisor_sigma, r!(1), r!(12), Some(r!(28)));
+        test!(divisor_sigma, r!(2), r!(12), Some(r!(210)));
+        test!(divisor_sigma, r!(-1), r!(12), Some(r!(7 / 3)));
+        test!(divisor_sigma, r!(1 / 2), r!(12), None);
+        test!(divisor_sigma, r!(1), r!(1 / 2), None);
+    }
+
+    #[test]
+    fn factorial() {
+        use super::factorial;
+        assert_eq!(factorial(r!(-1)), None);
+        assert_eq!(factorial(r!(0)), Some(r!(1)));
+        assert_eq!(factorial(r!(5)), Some(r!(120)));
+        assert_eq!(factorial(r!(1 / 2)), None);
+    }
+
     #[test]
     fn gcd() {
         use super::gcd;
@@ -169,6 +425,24 @@ mod tests {
         test!(@commut gcd, @even r!(2 / 3), @even r!(4 / 5), Some(r!(2 / 15)));
     }
 
+    #[test]
+    fn heaviside() {
+        use super::heaviside;
+        assert_eq!(heaviside(r!(-1 / 2)), Some(r!(0)));
+        assert_eq!(heaviside(r!(0)), Some(r!(1 / 2)));
+        assert_eq!(heaviside(r!(1 / 2)), Some(r!(1)));
+    }
+
+    #[test]
+    fn is_prime() {
+        use super::is_prime;
+        assert_eq!(is_prime(r!(-2)), Some(r!(0)));
+        assert_eq!(is_prime(r!(1)), Some(r!(0)));
+        assert_eq!(is_prime(r!(2)), Some(r!(1)));
+        assert_eq!(is_prime(r!(9)), Some(r!(0)));
+        assert_eq!(is_prime(r!(1 / 2)), None);
+    }
+
     #[test]
     fn lcm() {
         use super::lcm;
@@ -189,6 +463,57 @@ mod tests {
         test!(@commut min, r!(2 / 3), r!(4 / 5), Some(r!(2 / 3)));
     }
 
+    #[test]
+    fn mobius() {
+        use super::mobius;
+        assert_eq!(mobius(r!(0)), None);
+        assert_eq!(mobius(r!(1)), Some(r!(1)));
+        assert_eq!(mobius(r!(30)), Some(r!(-1)));
+        assert_eq!(mobius(r!(12)), Some(r!(0)));
+        assert_eq!(mobius(r!(1 / 2)), None);
+    }
+
+    #[test]
+    fn num_divisors() {
+        use super::num_divisors;
+        assert_eq!(num_divisors(r!(0)), None);
+        assert_eq!(num_divisors(r!(1)), Some(r!(1)));
+        assert_eq!(num_divisors(r!(12)), Some(r!(6)));
+        assert_eq!(num_divisors(r!(1 / 2)), None);
+    }
+
+    #[test]
+    fn popcount() {
+        use super::popcount;
+        assert_eq!(popcount(r!(0)), Some(r!(0)));
+        assert_eq!(popcount(r!(7)), Some(r!(3)));
+        assert_eq!(popcount(r!(-7)), Some(r!(3)));
+        assert_eq!(popcount(r!(1 / 2)), None);
+    }
+
+    #[test]
+    fn periodic_waves() {
+        assert_eq!(sawtooth_wave(r!(1 / 4)), Some(r!(-1 / 2)));
+        assert_eq!(sawtooth_wave(r!(-1 / 4)), Some(r!(1 / 2)));
+        assert_eq!(sawtooth_wave(r!(2)), Some(r!(0)));
+        assert_eq!(square_wave(r!(1 / 4)), Some(r!(1)));
+        assert_eq!(square_wave(r!(3 / 4)), Some(r!(-1)));
+        assert_eq!(square_wave(r!(-1 / 4)), Some(r!(-1)));
+        assert_eq!(square_wave(r!(1 / 2)), Some(r!(0)));
+    }
+
+    #[test]
+    fn poch() {
+        use super::poch;
+        test!(poch, r!(2), r!(0), Some(r!(1)));
+        test!(poch, r!(2), r!(3), Some(r!(24)));
+        test!(poch, r!(-2), r!(3), Some(r!(0)));
+        test!(poch, r!(1 / 2), r!(2), Some(r!(3 / 4)));
+        test!(poch, r!(3), r!(-2), Some(r!(1 / 2)));
+        test!(poch, r!(1), r!(-1), None);
+        test!(poch, r!(2), r!(1 / 2), None);
+    }
+
     #[test]
     fn pow() {
         use super::pow;
@@ -215,6 +540,23 @@ mod tests {
         test!(pow, r!(1), r!(1 / 2), None);
     }
 
+    #[test]
+    fn prime_pi() {
+        use super::prime_pi;
+        assert_eq!(prime_pi(r!(-1)), Some(r!(0)));
+        assert_eq!(prime_pi(r!(2)), Some(r!(1)));
+        assert_eq!(prime_pi(r!(21 / 2)), Some(r!(4)));
+        assert_eq!(prime_pi(r!(100)), Some(r!(25)));
+    }
+
+    #[test]
+    fn rect() {
+        use super::rect;
+        assert_eq!(rect(r!(1 / 4)), Some(r!(1)));
+        assert_eq!(rect(r!(-1 / 2)), Some(r!(1 / 2)));
+        assert_eq!(rect(r!(1)), Some(r!(0)));
+    }
+
     #[test]
     fn rem_euclid() {
         use super::rem_euclid;
@@ -225,4 +567,20 @@ mod tests {
         test!(rem_euclid, r!(-2 / 3), @even r!(4 / 5), Some(r!(2 / 15)));
         test!(rem_euclid, r!(-4 / 5), @even r!(2 / 3), Some(r!(8 / 15)));
     }
+
+    #[test]
+    fn totient() {
+        use super::totient;
+        assert_eq!(totient(r!(0)), None);
+        assert_eq!(totient(r!(1)), Some(r!(1)));
+        assert_eq!(totient(r!(12)), Some(r!(4)));
+        assert_eq!(totient(r!(1 / 2)), None);
+    }
+
+    #[test]
+    fn tri() {
+        use super::tri;
+        assert_eq!(tri(r!(-1 / 4)), Some(r!(3 / 4)));
+        assert_eq!(tri(r!(2)), Some(r!(0)));
+    }
 }
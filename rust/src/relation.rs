@@ -1,20 +1,26 @@
 use crate::{
+    affine_form::AffineForm,
     ast::{BinaryOp, Expr, NaryOp, UnaryOp, ValueType, VarSet},
     binary, constant,
     context::Context,
-    eval_result::EvalResult,
-    interval_set::TupperIntervalSet,
+    eval_result::{EvalResult, EvalResultMask},
+    interval_set::{DecSignSet, SignSet, TupperInterval, TupperIntervalSet},
+    mp_interval::MpInterval,
     nary,
-    ops::{StaticForm, StaticFormKind, StaticTerm, StaticTermKind, StoreIndex, ValueStore},
+    ops::{
+        FormIndex, Gradient, PointValues, Program, RelOp, StaticForm, StaticFormKind, StaticTerm,
+        StaticTermKind, StoreIndex, ValueStore,
+    },
     parse::parse_expr,
+    taylor_model::TaylorModel,
     unary, var,
     visit::*,
 };
-use inari::{const_interval, interval, DecInterval, Interval};
-use rug::Integer;
+use inari::{const_interval, interval, DecInterval, Decoration, Interval};
+use rug::{Integer, Rational};
 use std::{
     collections::{hash_map::Entry, HashMap},
-    mem::size_of,
+    mem::{size_of, take},
     str::FromStr,
 };
 
@@ -125,12 +131,52 @@ pub enum RelationType {
     Polar,
 }
 
+/// The truth value of a relation over a box.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TruthValue {
+    /// The relation holds at every point in the box.
+    True,
+    /// The relation holds at no point in the box.
+    False,
+    /// The relation could not be decided over the box.
+    Unknown,
+}
+
+/// Value stores for evaluating a relation in multiple precision.
+#[derive(Clone, Debug)]
+struct MpValueStore {
+    prec: u32,
+    ms: ValueStore<MpInterval>,
+    // Used for evaluating terms that have no multiple-precision implementation.
+    ts: ValueStore<TupperIntervalSet>,
+}
+
 #[derive(Clone, Debug)]
 pub struct Relation {
     terms: Vec<StaticTerm>,
+    // The programs that evaluate the terms other than the constant ones, indexed by
+    // `x_cached as usize | (y_cached as usize) << 1`, where `x_cached`/`y_cached` denotes
+    // whether the terms that only depend on x/y are skipped as they are restored from a cache.
+    programs: Vec<Program>,
     forms: Vec<StaticForm>,
     n_atom_forms: usize,
     ts: ValueStore<TupperIntervalSet>,
+    // The values of the terms at the points evaluated in a batch, stored per term.
+    batch_ts: ValueStore<PointValues>,
+    // Used for evaluating the terms at the points that have no fast paths,
+    // so that the values in `ts` are kept intact.
+    batch_scratch_ts: ValueStore<TupperIntervalSet>,
+    // The gradients of the terms computed by `eval_tightened` and `prove_solutions`.
+    ds: ValueStore<Gradient>,
+    // The values of the terms in affine arithmetic computed by `eval_affine`.
+    fs: ValueStore<AffineForm>,
+    // The Taylor models of the terms computed by `eval_taylor`.
+    ms: ValueStore<TaylorModel>,
+    // The domains of the terms narrowed by `contract`, which are `None` for the terms
+    // that have not been reached.
+    cs: ValueStore<Option<Interval>>,
+    rational_constants: Vec<(StoreIndex, Rational)>,
+    mp: Option<MpValueStore>,
     eval_count: usize,
     mx: Vec<StoreIndex>,
     my: Vec<StoreIndex>,
@@ -156,7 +202,652 @@ impl Relation {
         }
     }
 
-    /// Returns the number of calls of `self.eval` that have been made thus far.
+    /// Evaluates the relation at each of the given points.
+    ///
+    /// Each term is evaluated at all the points at once, which is faster than evaluating
+    /// the relation at the points one by one. If `cache` is given, only the points
+    /// that are not found in it are evaluated, and the values of the terms that only depend
+    /// on x/y are restored from it if they are available for all of such points.
+    ///
+    /// Precondition: `cache` has never been passed to other relations.
+    pub fn eval_points(
+        &mut self,
+        points: &[(f64, f64)],
+        n_theta: Interval,
+        cache: Option<&mut EvalCache>,
+    ) -> Vec<EvalResult> {
+        let cache = match cache {
+            Some(cache) => cache,
+            _ => {
+                self.eval_terms_at_points(points, n_theta, 0);
+                return self.results_at_points(points.len());
+            }
+        };
+
+        let point = |x: f64| interval!(x, x).unwrap();
+        let cached_rs = points
+            .iter()
+            .map(|&(x, y)| cache.get_xy(point(x), point(y)).cloned())
+            .collect::<Vec<_>>();
+        let uncached_points = points
+            .iter()
+            .zip(&cached_rs)
+            .filter(|(_, r)| r.is_none())
+            .map(|(&p, _)| p)
+            .collect::<Vec<_>>();
+        self.eval_count += points.len() - uncached_points.len();
+
+        let n = uncached_points.len();
+        let mx_ts = uncached_points
+            .iter()
+            .map(|&(x, _)| cache.get_x(point(x)))
+            .collect::<Option<Vec<_>>>();
+        let my_ts = uncached_points
+            .iter()
+            .map(|&(_, y)| cache.get_y(point(y)))
+            .collect::<Option<Vec<_>>>();
+        if let Some(mx_ts) = &mx_ts {
+            for (i, &mx) in self.mx.iter().enumerate() {
+                self.batch_ts[mx] =
+                    PointValues::from_sets(mx_ts.iter().map(|ts| ts[i].clone()).collect());
+            }
+        }
+        if let Some(my_ts) = &my_ts {
+            for (i, &my) in self.my.iter().enumerate() {
+                self.batch_ts[my] =
+                    PointValues::from_sets(my_ts.iter().map(|ts| ts[i].clone()).collect());
+            }
+        }
+        let program = mx_ts.is_some() as usize | (my_ts.is_some() as usize) << 1;
+        self.eval_terms_at_points(&uncached_points, n_theta, program);
+        let mut uncached_rs = self.results_at_points(n).into_iter();
+
+        let bs = &self.batch_ts;
+        let mut i = 0;
+        points
+            .iter()
+            .zip(cached_rs)
+            .map(|(&(x, y), r)| {
+                r.unwrap_or_else(|| {
+                    let r = uncached_rs.next().unwrap();
+                    let (x, y) = (point(x), point(y));
+                    cache.insert_x_with(x, || self.mx.iter().map(|&mx| bs[mx].get(i)).collect());
+                    cache.insert_y_with(y, || self.my.iter().map(|&my| bs[my].get(i)).collect());
+                    cache.insert_xy_with(x, y, || r.clone());
+                    i += 1;
+                    r
+                })
+            })
+            .collect()
+    }
+
+    /// Evaluates the relation over the box X × Y and returns whether it holds.
+    ///
+    /// For a polar relation, the box is tested against all branches of θ
+    /// given by [`Self::n_theta_range`].
+    pub fn eval_on_box(&mut self, x: Interval, y: Interval) -> TruthValue {
+        let r = self.eval(x, y, self.n_theta_range, None);
+        if r.map(|DecSignSet(ss, d)| ss == SignSet::ZERO && d >= Decoration::Def)
+            .eval(&self.forms)
+        {
+            TruthValue::True
+        } else if !r
+            .map(|DecSignSet(ss, _)| ss.contains(SignSet::ZERO))
+            .eval(&self.forms)
+        {
+            TruthValue::False
+        } else {
+            TruthValue::Unknown
+        }
+    }
+
+    /// Tightens the results of the atomic formulas in `r` that are not decided
+    /// using the gradients of their terms, which are computed by forward-mode
+    /// automatic differentiation.
+    ///
+    /// `r` must be the result of the last call of [`Self::eval`], which must have been made
+    /// with the same arguments and with no cache or a cache of [`EvalCacheLevel::PerAxis`],
+    /// so that the values of the terms over the box are available.
+    ///
+    /// The range of such a term f over the box X × Y is enclosed as follows:
+    ///
+    /// - If the signs of both ∂f/∂x and ∂f/∂y are known, f is monotone in each variable,
+    ///   and the range is enclosed by the values at the two corners of the box
+    ///   where f attains its minimum and maximum.
+    /// - Otherwise, the mean-value form f(c) + ∂f/∂x (X − c_x) + ∂f/∂y (Y − c_y) is used,
+    ///   where c = (c_x, c_y) is the center of the box.
+    ///
+    /// The enclosure is intersected with the one from the plain evaluation.
+    /// This is only done for the terms whose values are single intervals with the decoration
+    /// ≥ [`Decoration::Dac`], i.e., the ones that are continuous over the box.
+    /// The values at the points are evaluated in a batch as [`Self::eval_points`] does.
+    pub fn eval_tightened(
+        &mut self,
+        x: Interval,
+        y: Interval,
+        n_theta: Interval,
+        mut r: EvalResult,
+    ) -> EvalResult {
+        if !x.is_common_interval() || !y.is_common_interval() {
+            return r;
+        }
+
+        let ts = &self.ts;
+        if !self.forms[..self.n_atom_forms]
+            .iter()
+            .zip(r.0.iter())
+            .any(|(f, r)| match f.kind {
+                StaticFormKind::Atomic(_, i) => {
+                    r.0.is_undecided()
+                        && matches!(ts[i].single(), Some(t) if t.d >= Decoration::Dac)
+                }
+                _ => false,
+            })
+        {
+            return r;
+        }
+
+        self.eval_gradients();
+        let ts = &self.ts;
+        let ds = &self.ds;
+
+        // `points[0]` is the center of the box, and `points[1 + i + 2 * j]` is the corner
+        // at the lower (i = 0) or upper (i = 1) end of X and the same for Y with j.
+        let points = [
+            (x.mid(), y.mid()),
+            (x.inf(), y.inf()),
+            (x.sup(), y.inf()),
+            (x.inf(), y.sup()),
+            (x.sup(), y.sup()),
+        ];
+        // The index of each atomic formula that can be tightened, along with the value
+        // and the gradient of its term and the points at which the term attains
+        // its minimum and maximum, or (0, 0) if the mean-value form is used.
+        let mut targets = vec![];
+        let mut is_needed = [false; 5];
+        for (k, (f, r)) in self.forms[..self.n_atom_forms]
+            .iter()
+            .zip(r.0.iter())
+            .enumerate()
+        {
+            let i = match f.kind {
+                StaticFormKind::Atomic(_, i) => i,
+                _ => continue,
+            };
+            let t = match ts[i].single() {
+                Some(t) if t.d >= Decoration::Dac && r.0.is_undecided() => t,
+                _ => continue,
+            };
+            let d = ds[i];
+            let sign = |d: Interval| {
+                if d.is_empty() {
+                    None
+                } else if d.inf() >= 0.0 {
+                    Some(1)
+                } else if d.sup() <= 0.0 {
+                    Some(0)
+                } else {
+                    None
+                }
+            };
+            let ps = match (sign(d.dx), sign(d.dy)) {
+                (Some(sx), Some(sy)) => (1 + (1 - sx) + 2 * (1 - sy), 1 + sx + 2 * sy),
+                _ if d.dx.is_common_interval() && d.dy.is_common_interval() => (0, 0),
+                _ => continue,
+            };
+            is_needed[ps.0] = true;
+            is_needed[ps.1] = true;
+            targets.push((k, i, t, d, ps));
+        }
+        if targets.is_empty() {
+            return r;
+        }
+
+        let mut batch_indices = [0; 5];
+        let mut batch_points = vec![];
+        for (j, &p) in points.iter().enumerate() {
+            if is_needed[j] {
+                batch_indices[j] = batch_points.len();
+                batch_points.push(p);
+            }
+        }
+        self.eval_terms_at_points(&batch_points, n_theta, 0);
+
+        let bs = &self.batch_ts;
+        for (k, i, t, d, (p_min, p_max)) in targets {
+            let value_at = |p: usize| bs[i].single(batch_indices[p]).map(|t| t.x);
+            let z = if p_min == 0 {
+                value_at(0).map(|fc| {
+                    let (cx, cy) = points[0];
+                    let cx = interval!(cx, cx).unwrap();
+                    let cy = interval!(cy, cy).unwrap();
+                    fc + d.dx * (x - cx) + d.dy * (y - cy)
+                })
+            } else {
+                value_at(p_min)
+                    .zip(value_at(p_max))
+                    .and_then(|(f_min, f_max)| interval!(f_min.inf(), f_max.sup()).ok())
+            };
+            if let Some(z) = z.map(|z| z.intersection(t.x)).filter(|z| !z.is_empty()) {
+                let z = TupperInterval::new(DecInterval::set_dec(z, t.d), t.g);
+                r.0[k] = self.forms[k].eval_with(&z.into());
+            }
+        }
+        r
+    }
+
+    /// Tightens the results of the atomic formulas in `r` that are not decided
+    /// by evaluating their terms also in affine arithmetic and intersecting the results.
+    ///
+    /// `r` must be the result of the last call of [`Self::eval`]
+    /// as described in [`Self::eval_tightened`].
+    ///
+    /// Affine arithmetic keeps track of linear dependency of the terms on x, y and n_θ,
+    /// so it gives tighter enclosures for relations in which the variables appear many times,
+    /// such as "x^2 - 2xy + y^2 = 1", especially over small boxes.
+    pub fn eval_affine(
+        &mut self,
+        x: Interval,
+        y: Interval,
+        n_theta: Interval,
+        mut r: EvalResult,
+    ) -> EvalResult {
+        if !r.0.iter().any(|r| r.0.is_undecided()) {
+            return r;
+        }
+
+        let ts = &self.ts;
+        let fs = &mut self.fs;
+        for t in &self.terms {
+            match t.kind {
+                StaticTermKind::X => t.put(fs, AffineForm::variable(x, 0)),
+                StaticTermKind::Y => t.put(fs, AffineForm::variable(y, 1)),
+                StaticTermKind::NTheta => t.put(fs, AffineForm::variable(n_theta, 2)),
+                _ => t.put_eval_affine(ts, fs),
+            }
+        }
+
+        self.intersect_undecided(&mut r, |i| self.fs[i].range());
+        r
+    }
+
+    /// Tightens the results of the atomic formulas in `r` that are not decided
+    /// by evaluating their terms also as Taylor models and intersecting the results.
+    ///
+    /// `r` must be the result of the last call of [`Self::eval`]
+    /// as described in [`Self::eval_tightened`].
+    ///
+    /// A Taylor model represents a term as a polynomial of degree ≤ 3 in x - x₀ and y - y₀,
+    /// where (x₀, y₀) is the center of the box, plus an interval remainder. It captures
+    /// the curvature of the terms, so it gives tighter enclosures than [`Self::eval_affine`]
+    /// for smooth relations over small boxes, such as near the points where a curve
+    /// is tangent to another or to itself. n_θ is treated as a constant.
+    pub fn eval_taylor(
+        &mut self,
+        x: Interval,
+        y: Interval,
+        n_theta: Interval,
+        mut r: EvalResult,
+    ) -> EvalResult {
+        if !r.0.iter().any(|r| r.0.is_undecided()) {
+            return r;
+        }
+        let (mx, my) = match TaylorModel::variables(x, y) {
+            Some(xy) => xy,
+            _ => return r,
+        };
+
+        let ts = &self.ts;
+        let ms = &mut self.ms;
+        for t in &self.terms {
+            match t.kind {
+                StaticTermKind::X => t.put(ms, mx),
+                StaticTermKind::Y => t.put(ms, my),
+                StaticTermKind::NTheta => t.put(ms, mx.with_constant(n_theta)),
+                _ => t.put_eval_taylor(ts, ms, &mx),
+            }
+        }
+
+        self.intersect_undecided(&mut r, |i| self.ms[i].range());
+        r
+    }
+
+    /// Replaces the results of the undecided atomic formulas with the ones computed
+    /// from the values of their terms intersected with `z(i)`, where `i` is the store index
+    /// of the term.
+    fn intersect_undecided<F>(&self, r: &mut EvalResult, z: F)
+    where
+        F: Fn(StoreIndex) -> Interval,
+    {
+        let ts = &self.ts;
+        for (k, f) in self.forms[..self.n_atom_forms].iter().enumerate() {
+            let i = match f.kind {
+                StaticFormKind::Atomic(_, i) if r.0[k].0.is_undecided() => i,
+                _ => continue,
+            };
+            let z = z(i);
+            let xs = ts[i]
+                .iter()
+                .filter_map(|x| {
+                    let x_z = x.x.intersection(z);
+                    if x_z.is_empty() {
+                        None
+                    } else {
+                        Some(TupperInterval::new(DecInterval::set_dec(x_z, x.d), x.g))
+                    }
+                })
+                .collect::<TupperIntervalSet>();
+            if !xs.is_empty() {
+                r.0[k] = f.eval_with(&xs);
+            }
+        }
+    }
+
+    /// Returns a box within X × Y that contains all solutions of the relation in X × Y,
+    /// which is computed by a forward-backward contractor (HC4-revise).
+    ///
+    /// The relation is evaluated over the box as [`Self::eval`] does without a cache,
+    /// and then for each atomic formula f = 0, f ≤ 0 or f < 0, the constraint on the value of f
+    /// is propagated backward through the terms to narrow the domains of x and y.
+    /// The results are intersected for conjunctions and joined for disjunctions.
+    /// The other atomic formulas are not used, as they hold wherever f is undefined.
+    pub fn contract(
+        &mut self,
+        x: Interval,
+        y: Interval,
+        n_theta: Interval,
+    ) -> (Interval, Interval) {
+        self.eval(x, y, n_theta, None);
+        let (cx, cy) = self.contract_form(self.forms.len() - 1);
+        let (cx, cy) = (x.intersection(cx), y.intersection(cy));
+        if cx.is_empty() || cy.is_empty() {
+            (Interval::EMPTY, Interval::EMPTY)
+        } else {
+            (cx, cy)
+        }
+    }
+
+    /// Returns the box that contains all solutions of the formula in the box
+    /// over which the terms have been evaluated, without intersecting it with the box.
+    fn contract_form(&mut self, k: usize) -> (Interval, Interval) {
+        const NON_POS: Interval = const_interval!(f64::NEG_INFINITY, 0.0);
+        match self.forms[k].kind {
+            StaticFormKind::Atomic(op, i) => {
+                let z = match op {
+                    RelOp::EqZero => const_interval!(0.0, 0.0),
+                    RelOp::LeZero | RelOp::LtZero => NON_POS,
+                    _ => return (Interval::ENTIRE, Interval::ENTIRE),
+                };
+
+                let ts = &self.ts;
+                let cs = &mut self.cs;
+                for t in &self.terms {
+                    cs[t.store_index] = None;
+                }
+                let z = ts[i]
+                    .iter()
+                    .fold(Interval::EMPTY, |acc, x| acc.convex_hull(x.x))
+                    .intersection(z);
+                cs[i] = Some(z);
+                for t in self.terms.iter().rev() {
+                    match cs[t.store_index] {
+                        Some(z) if z.is_empty() => return (Interval::EMPTY, Interval::EMPTY),
+                        Some(_) => t.contract_args(ts, cs),
+                        _ => (),
+                    }
+                }
+
+                let mut cx = Interval::ENTIRE;
+                let mut cy = Interval::ENTIRE;
+                for t in &self.terms {
+                    match (&t.kind, cs[t.store_index]) {
+                        (StaticTermKind::X, Some(x)) => cx = x,
+                        (StaticTermKind::Y, Some(y)) => cy = y,
+                        _ => (),
+                    }
+                }
+                (cx, cy)
+            }
+            StaticFormKind::And(f, g) => {
+                let (fx, fy) = self.contract_form(f as usize);
+                let (gx, gy) = self.contract_form(g as usize);
+                (fx.intersection(gx), fy.intersection(gy))
+            }
+            StaticFormKind::Or(f, g) => {
+                let (fx, fy) = self.contract_form(f as usize);
+                let (gx, gy) = self.contract_form(g as usize);
+                if fx.is_empty() || fy.is_empty() {
+                    (gx, gy)
+                } else if gx.is_empty() || gy.is_empty() {
+                    (fx, fy)
+                } else {
+                    (fx.convex_hull(gx), fy.convex_hull(gy))
+                }
+            }
+        }
+    }
+
+    /// Tries to prove the existence of solutions of equations in the box
+    /// by the interval Newton method, using the gradients of the terms.
+    ///
+    /// Returns a mask that tells whether each atomic formula f = 0 is proven to have
+    /// a solution in the box, along with the indices of the conjunctions f = 0 ∧ g = 0
+    /// of two atomic formulas that are proven to have a common solution in the box.
+    ///
+    /// For a single equation, the Newton operator c − f(c)/(∂f/∂x) is computed along
+    /// the horizontal line through the center c of the box, and similarly along the vertical one.
+    /// For a pair of equations, the Krawczyk operator is used, which also proves
+    /// uniqueness of the solution. Like [`Self::eval_tightened`], this is only done for
+    /// the terms whose values are single intervals with the decoration ≥ [`Decoration::Dac`].
+    ///
+    /// The values and the gradients of the terms are taken from the last call of [`Self::eval`],
+    /// which must have been made over a box that contains X × Y as described in
+    /// [`Self::eval_tightened`]. Such values are enclosures of those over X × Y,
+    /// so the proofs are still valid, while the relation is not evaluated again.
+    pub fn prove_solutions(
+        &mut self,
+        x: Interval,
+        y: Interval,
+        n_theta: Interval,
+    ) -> (EvalResultMask, Vec<FormIndex>) {
+        let mut zero_mask = EvalResultMask((0..self.n_atom_forms).map(|_| false).collect());
+        let mut common_zero_forms = vec![];
+        let is_smooth_equation = |ts: &ValueStore<TupperIntervalSet>, f: &StaticForm| match f.kind {
+            StaticFormKind::Atomic(RelOp::EqZero, i) => {
+                matches!(ts[i].single(), Some(t) if t.d >= Decoration::Dac)
+            }
+            _ => false,
+        };
+        if !x.is_common_interval()
+            || !y.is_common_interval()
+            || !self.forms[..self.n_atom_forms]
+                .iter()
+                .any(|f| is_smooth_equation(&self.ts, f))
+        {
+            return (zero_mask, common_zero_forms);
+        }
+
+        self.eval_gradients();
+        let (cx, cy) = (x.mid(), y.mid());
+        self.eval_terms_at_points(&[(cx, cy)], n_theta, 0);
+        let cx = interval!(cx, cx).unwrap();
+        let cy = interval!(cy, cy).unwrap();
+
+        let ts = &self.ts;
+        let ds = &self.ds;
+        let bs = &self.batch_ts;
+        // The value at the center and the gradient of the term of a smooth equation.
+        let term = |f: &StaticForm| match f.kind {
+            StaticFormKind::Atomic(_, i) if is_smooth_equation(ts, f) => {
+                bs[i].single(0).map(|t| (t.x, ds[i]))
+            }
+            _ => None,
+        };
+        for (k, f) in self.forms[..self.n_atom_forms].iter().enumerate() {
+            if let Some((fc, d)) = term(f) {
+                zero_mask.0[k] =
+                    Self::newton_test(x, cx, fc, d.dx) || Self::newton_test(y, cy, fc, d.dy);
+            }
+        }
+        for (k, f) in self.forms.iter().enumerate().skip(self.n_atom_forms) {
+            if let StaticFormKind::And(f1, f2) = f.kind {
+                if let (Some(f1), Some(f2)) = (
+                    term(&self.forms[f1 as usize]),
+                    term(&self.forms[f2 as usize]),
+                ) {
+                    if Self::krawczyk_test(x, y, cx, cy, f1, f2) {
+                        common_zero_forms.push(k as FormIndex);
+                    }
+                }
+            }
+        }
+        (zero_mask, common_zero_forms)
+    }
+
+    /// Returns `true` if the Newton operator c − f(c)/f'(X) is contained in X,
+    /// which proves that f has a unique zero in X.
+    ///
+    /// `d` must be an enclosure of f' over X. If it contains zero, the operator
+    /// can be empty or unbounded and does not prove anything, so `false` is returned.
+    fn newton_test(x: Interval, c: Interval, fc: Interval, d: Interval) -> bool {
+        let n = c - fc / d;
+        !d.contains(0.0) && !n.is_empty() && n.subset(x)
+    }
+
+    /// Returns `true` if the Krawczyk operator for the system of equations f = g = 0
+    /// is contained in the interior of the box X × Y, which proves that the system
+    /// has a unique solution in the box.
+    ///
+    /// `f` and `g` are pairs of the value at the center (c_x, c_y) of the box
+    /// and the gradient over the box of each term.
+    fn krawczyk_test(
+        x: Interval,
+        y: Interval,
+        cx: Interval,
+        cy: Interval,
+        f: (Interval, Gradient),
+        g: (Interval, Gradient),
+    ) -> bool {
+        const ONE: Interval = const_interval!(1.0, 1.0);
+        let (fc, df) = f;
+        let (gc, dg) = g;
+        // C ≈ J(c)⁻¹, where J is the Jacobian matrix. Any C can be used for the test
+        // as long as the computation below is done in interval arithmetic.
+        let (a, b, c, d) = (df.dx.mid(), df.dy.mid(), dg.dx.mid(), dg.dy.mid());
+        let det = a * d - b * c;
+        let (c11, c12, c21, c22) = (d / det, -b / det, -c / det, a / det);
+        if ![c11, c12, c21, c22].iter().all(|c| c.is_finite()) {
+            return false;
+        }
+        let c11 = interval!(c11, c11).unwrap();
+        let c12 = interval!(c12, c12).unwrap();
+        let c21 = interval!(c21, c21).unwrap();
+        let c22 = interval!(c22, c22).unwrap();
+
+        // K = c − C F(c) + (I − C J(X × Y)) (X × Y − c).
+        let (dx, dy) = (x - cx, y - cy);
+        let kx = cx - (c11 * fc + c12 * gc) + (ONE - (c11 * df.dx + c12 * dg.dx)) * dx
+            - (c11 * df.dy + c12 * dg.dy) * dy;
+        let ky = cy - (c21 * fc + c22 * gc) - (c21 * df.dx + c22 * dg.dx) * dx
+            + (ONE - (c21 * df.dy + c22 * dg.dy)) * dy;
+        !kx.is_empty() && !ky.is_empty() && kx.interior(x) && ky.interior(y)
+    }
+
+    /// Computes the gradients of the terms from the values in `self.ts`
+    /// and stores them in `self.ds`.
+    fn eval_gradients(&mut self) {
+        let ts = &self.ts;
+        let ds = &mut self.ds;
+        for t in &self.terms {
+            t.put_eval_gradient(ts, ds);
+        }
+    }
+
+    /// Evaluates the terms at each of the given points with `self.programs[program]`
+    /// and stores the values in `self.batch_ts`.
+    ///
+    /// The values of the terms that are skipped by the program must have been stored
+    /// in `self.batch_ts` for all the points.
+    fn eval_terms_at_points(&mut self, points: &[(f64, f64)], n_theta: Interval, program: usize) {
+        self.eval_count += points.len();
+        let n = points.len();
+        for t in &self.terms {
+            let xs = &mut self.batch_ts[t.store_index];
+            if t.vars == VarSet::EMPTY && xs.len() != n {
+                *xs = PointValues::repeat(&self.ts[t.store_index], n);
+            }
+        }
+
+        self.programs[program].eval_points(
+            &self.terms,
+            &mut self.batch_ts,
+            &mut self.batch_scratch_ts,
+            points,
+            n_theta,
+        );
+    }
+
+    /// Evaluates the relation with the given arguments in multiple precision.
+    ///
+    /// The precision of the evaluation is that of `x`.
+    /// Terms that have no multiple-precision implementation are evaluated with [`f64`] intervals.
+    /// Irrational constants, such as π, are also only accurate to the precision of [`f64`].
+    pub fn eval_mp(&mut self, x: &MpInterval, y: &MpInterval, n_theta: Interval) -> EvalResult {
+        self.eval_count += 1;
+        let prec = x.prec();
+        let n_terms = self.terms.len();
+        let init = !matches!(&self.mp, Some(mp) if mp.prec == prec);
+        if init {
+            self.mp = Some(MpValueStore {
+                prec,
+                ms: ValueStore::new(MpInterval::empty(prec), n_terms),
+                ts: self.ts.clone(),
+            });
+        }
+
+        let mp = self.mp.as_mut().unwrap();
+        let ms = &mut mp.ms;
+        let ts = &mut mp.ts;
+        for t in &self.terms {
+            match t.kind {
+                StaticTermKind::X => t.put(ms, x.clone()),
+                StaticTermKind::Y => t.put(ms, y.clone()),
+                StaticTermKind::NTheta => t.put(
+                    ms,
+                    MpInterval::from_dec_interval(DecInterval::new(n_theta), prec),
+                ),
+                StaticTermKind::Constant(_) if init => {
+                    match self
+                        .rational_constants
+                        .iter()
+                        .find(|(i, _)| *i == t.store_index)
+                    {
+                        Some((_, xr)) => t.put(ms, MpInterval::from_rational(xr, prec)),
+                        _ => t.put_eval_mp(ms, ts, prec),
+                    }
+                }
+                _ if t.vars == VarSet::EMPTY && !init => {
+                    // Constant subexpression.
+                }
+                _ => t.put_eval_mp(ms, ts, prec),
+            }
+        }
+
+        EvalResult(
+            self.forms[..self.n_atom_forms]
+                .iter()
+                .map(|f| {
+                    if let StaticFormKind::Atomic(_, i) = f.kind {
+                        ts[i] = ms[i].to_tupper_interval_set();
+                    }
+                    f.eval(ts)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the number of evaluations that have been made thus far,
+    /// where each point evaluated in a batch is counted as one.
     pub fn eval_count(&self) -> usize {
         self.eval_count
     }
@@ -188,7 +879,6 @@ impl Relation {
             return r.clone();
         }
 
-        let terms = &self.terms;
         let ts = &mut self.ts;
         let mx_ts = cache.get_x(x);
         let my_ts = cache.get_y(y);
@@ -203,20 +893,8 @@ impl Relation {
             }
         }
 
-        for t in terms {
-            match t.kind {
-                StaticTermKind::X => t.put(ts, DecInterval::new(x).into()),
-                StaticTermKind::Y => t.put(ts, DecInterval::new(y).into()),
-                StaticTermKind::NTheta => t.put(ts, DecInterval::new(n_theta).into()),
-                _ if t.vars == VarSet::EMPTY
-                    || t.vars == VarSet::X && mx_ts.is_some()
-                    || t.vars == VarSet::Y && my_ts.is_some() =>
-                {
-                    // Constant or cached subexpression.
-                }
-                _ => t.put_eval(ts),
-            }
-        }
+        let program = &self.programs[mx_ts.is_some() as usize | (my_ts.is_some() as usize) << 1];
+        program.eval(&self.terms, ts, x, y, n_theta);
 
         let r = EvalResult(
             self.forms[..self.n_atom_forms]
@@ -232,20 +910,24 @@ impl Relation {
         r
     }
 
+    /// Returns the results at the first `n` points of the values in `self.batch_ts`.
+    fn results_at_points(&self, n: usize) -> Vec<EvalResult> {
+        let bs = &self.batch_ts;
+        (0..n)
+            .map(|i| {
+                EvalResult(
+                    self.forms[..self.n_atom_forms]
+                        .iter()
+                        .map(|f| f.eval_point(bs, i))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
     fn eval_without_cache(&mut self, x: Interval, y: Interval, n_theta: Interval) -> EvalResult {
         let ts = &mut self.ts;
-        let terms = &self.terms;
-        for t in terms {
-            match t.kind {
-                StaticTermKind::X => t.put(ts, DecInterval::new(x).into()),
-                StaticTermKind::Y => t.put(ts, DecInterval::new(y).into()),
-                StaticTermKind::NTheta => t.put(ts, DecInterval::new(n_theta).into()),
-                _ if t.vars == VarSet::EMPTY => {
-                    // Constant subexpression.
-                }
-                _ => t.put_eval(ts),
-            }
-        }
+        self.programs[0].eval(&self.terms, ts, x, y, n_theta);
 
         EvalResult(
             self.forms[..self.n_atom_forms]
@@ -271,6 +953,14 @@ impl FromStr for Relation {
 
     fn from_str(s: &str) -> Result<Self, String> {
         let mut e = parse_expr(s, Context::builtin_context())?;
+        #[cfg(not(feature = "arb"))]
+        {
+            let mut v = FindUnavailableFunction::default();
+            v.visit_expr(&e);
+            if let Some(error) = v.error {
+                return Err(error);
+            }
+        }
         // TODO: Check types and return a pretty error message.
         loop {
             let mut v = EliminateNot::default();
@@ -309,6 +999,7 @@ impl FromStr for Relation {
         let collector = CollectStatic::new(v);
         let terms = collector.terms.clone();
         let forms = collector.forms.clone();
+        let rational_constants = collector.rational_constants.clone();
         let n_terms = terms.len();
         let n_atom_forms = forms
             .iter()
@@ -319,11 +1010,38 @@ impl FromStr for Relation {
         v.visit_expr(&e);
         let (mx, my) = v.mx_my();
 
+        let programs = (0..4)
+            .map(|i| {
+                let x_cached = i & 1 != 0;
+                let y_cached = i & 2 != 0;
+                Program::new(&terms, |t| match t.kind {
+                    StaticTermKind::X | StaticTermKind::Y | StaticTermKind::NTheta => true,
+                    _ if t.vars == VarSet::EMPTY
+                        || t.vars == VarSet::X && x_cached
+                        || t.vars == VarSet::Y && y_cached =>
+                    {
+                        // Constant or cached subexpression.
+                        false
+                    }
+                    _ => true,
+                })
+            })
+            .collect();
+
         let mut slf = Self {
             terms,
+            programs,
             forms,
             n_atom_forms,
             ts: ValueStore::new(TupperIntervalSet::new(), n_terms),
+            batch_ts: ValueStore::new(PointValues::default(), n_terms),
+            batch_scratch_ts: ValueStore::new(TupperIntervalSet::new(), n_terms),
+            ds: ValueStore::new(Gradient::ZERO, n_terms),
+            fs: ValueStore::new(AffineForm::constant(Interval::EMPTY), n_terms),
+            ms: ValueStore::new(TaylorModel::default(), n_terms),
+            cs: ValueStore::new(None, n_terms),
+            rational_constants,
+            mp: None,
             eval_count: 0,
             mx,
             my,
@@ -335,7 +1053,7 @@ impl FromStr for Relation {
     }
 }
 
-fn simplify(e: &mut Expr) {
+pub(crate) fn simplify(e: &mut Expr) {
     loop {
         let mut fl = Flatten::default();
         fl.visit_expr_mut(e);
@@ -359,19 +1077,23 @@ fn simplify(e: &mut Expr) {
 fn expand_polar_coords(e: &mut Expr) {
     use {BinaryOp::*, NaryOp::*};
 
-    // e1 = e /. {r → sqrt(x^2 + y^2), θ → atan2(y, x) + 2π n_θ}.
-    let mut e1 = e.clone();
+    // The branch r ≤ 0 is obtained from the branch r ≥ 0 by (r, θ) → (-r, θ + π).
+    // Most of the trigonometric functions of θ are left unchanged by `FlipPolarCoords`,
+    // so the two branches share them, as well as hypot(x, y) and atan2(y, x) + 2π n_θ,
+    // and only differ by the sign of r, the offset of the other occurrences of θ
+    // and the arithmetic on top of them.
+    // θ can alternatively be replaced by atan2(-y, -x) + 2π n_θ in the branch r ≤ 0,
+    // which will be a little more precise for some n_θ,
+    // but much slower since it breaks the sharing of `atan2`.
+    let mut e2 = e.clone();
+    FlipPolarCoords.visit_expr_mut(&mut e2);
+
+    // e /. {r → hypot(x, y), θ → atan2(y, x) + 2π n_θ}.
     let mut v = ReplaceAll::new(|e| match e {
         var!(x) if x == "r" => Some(Expr::binary(
-            Pow,
-            box Expr::nary(
-                Plus,
-                vec![
-                    Expr::binary(Pow, box Expr::var("x"), box Expr::two()),
-                    Expr::binary(Pow, box Expr::var("y"), box Expr::two()),
-                ],
-            ),
-            box Expr::one_half(),
+            Hypot,
+            box Expr::var("x"),
+            box Expr::var("y"),
         )),
         var!(x) if x == "theta" || x == "θ" => Some(Expr::nary(
             Plus,
@@ -388,50 +1110,14 @@ fn expand_polar_coords(e: &mut Expr) {
         )),
         _ => None,
     });
-    v.visit_expr_mut(&mut e1);
+    v.visit_expr_mut(e);
     if !v.modified {
         // `e` does not contain r nor θ.
         return;
     }
-
-    // e2 = e /. {r → -sqrt(x^2 + y^2), θ → atan2(y, x) + 2π (1/2 + n_θ)}.
-    // θ can alternatively be replaced by atan2(-y, -x) + 2π n_θ,
-    // which will be a little more precise for some n_θ,
-    // but much slower since we have to evaluate `atan2` separately for `e1` and `e2`.
-    let mut e2 = e.clone();
-    let mut v = ReplaceAll::new(|e| match e {
-        var!(x) if x == "r" => Some(Expr::unary(
-            UnaryOp::Neg,
-            box Expr::binary(
-                Pow,
-                box Expr::nary(
-                    Plus,
-                    vec![
-                        Expr::binary(Pow, box Expr::var("x"), box Expr::two()),
-                        Expr::binary(Pow, box Expr::var("y"), box Expr::two()),
-                    ],
-                ),
-                box Expr::one_half(),
-            ),
-        )),
-        var!(x) if x == "theta" || x == "θ" => Some(Expr::nary(
-            Plus,
-            vec![
-                Expr::binary(Atan2, box Expr::var("y"), box Expr::var("x")),
-                Expr::nary(
-                    Times,
-                    vec![
-                        Expr::constant(DecInterval::TAU.into(), None),
-                        Expr::nary(Plus, vec![Expr::one_half(), Expr::var("<n-theta>")]),
-                    ],
-                ),
-            ],
-        )),
-        _ => None,
-    });
     v.visit_expr_mut(&mut e2);
 
-    *e = Expr::binary(BinaryOp::Or, box e1, box e2);
+    *e = Expr::binary(BinaryOp::Or, box take(e), box e2);
 }
 
 /// Returns the period of a function of θ in multiples of 2π, i.e., any integer p that satisfies
@@ -597,6 +1283,257 @@ pub fn relation_type(e: &Expr) -> RelationType {
 mod tests {
     use super::*;
 
+    #[test]
+    fn contract() {
+        fn f(rel: &str, x: Interval, y: Interval) -> (Interval, Interval) {
+            let mut r = rel.parse::<Relation>().unwrap();
+            r.contract(x, y, const_interval!(0.0, 0.0))
+        }
+
+        let x = const_interval!(-2.0, 2.0);
+        let y = const_interval!(-2.0, 2.0);
+        assert_eq!(
+            f("x^2 + y^2 = 1", x, y),
+            (const_interval!(-1.0, 1.0), const_interval!(-1.0, 1.0))
+        );
+        assert_eq!(
+            f("x^2 + y^2 <= 1", x, y),
+            (const_interval!(-1.0, 1.0), const_interval!(-1.0, 1.0))
+        );
+        assert_eq!(f("y = 1 || y = -1", x, y), (x, const_interval!(-1.0, 1.0)));
+        assert_eq!(
+            f("y = 1 && y = -1", x, y),
+            (Interval::EMPTY, Interval::EMPTY)
+        );
+        assert_eq!(
+            f("exp(x) = y + 1", x, const_interval!(-0.5, 0.0)),
+            (const_interval!(0.5, 1.0).ln(), const_interval!(-0.5, 0.0))
+        );
+        assert_eq!(f("sqrt(x) < 1", x, y), (const_interval!(0.0, 1.0), y));
+        assert_eq!(f("sqrt(x) > 1", x, y), (const_interval!(1.0, 2.0), y));
+        // Negated formulas hold wherever the terms are undefined.
+        assert_eq!(f("!(x^2 + y^2 = 1)", x, y), (x, y));
+        assert_eq!(f("!(sqrt(x) < 1)", x, y), (x, y));
+    }
+
+    #[test]
+    fn tightening() {
+        let all = SignSet::NEG | SignSet::ZERO | SignSet::POS;
+        let neg = SignSet::NEG;
+        let pos = SignSet::POS;
+        // The sign sets of the first atomic formula from plain evaluation and the ones
+        // tightened by `eval_tightened`, `eval_affine` and `eval_taylor`, respectively.
+        let cases = [
+            // Monotone in x.
+            (
+                "x^2 - 2x + 1.01 = 0",
+                const_interval!(1.1, 1.3),
+                const_interval!(0.0, 1.0),
+                [all, pos, pos, pos],
+            ),
+            // The mean-value form.
+            (
+                "sin(x) - sin(x + 0.01) = 0",
+                const_interval!(0.0, 0.2),
+                const_interval!(0.0, 1.0),
+                [all, neg, neg, neg],
+            ),
+            (
+                "x^2 - 2x y + y^2 = 1",
+                const_interval!(10.0, 10.125),
+                const_interval!(8.5, 8.625),
+                [all, pos, pos, pos],
+            ),
+            (
+                "exp(x) - x y = 1.3",
+                const_interval!(1.0, 1.25),
+                const_interval!(1.0, 1.25),
+                [all, pos, pos, pos],
+            ),
+            // sin(x) - x + x^3/6 ≈ x^5/120 ≤ 8.2 × 10^-6 over X = [1/8, 1/4].
+            (
+                "sin(x) - x + x^3/6 = 0.0001",
+                const_interval!(0.125, 0.25),
+                const_interval!(0.0, 1.0),
+                [all, all, all, neg],
+            ),
+            (
+                "sqrt(x) / y + ln(x y) = 2",
+                const_interval!(1.0, 1.125),
+                const_interval!(1.0, 1.125),
+                [neg, neg, neg, neg],
+            ),
+            // Not continuous.
+            (
+                "y = floor(x)",
+                const_interval!(-0.5, 0.5),
+                const_interval!(-0.25, 0.25),
+                [all, all, all, all],
+            ),
+            (
+                "y = floor(x) + x - x",
+                const_interval!(-0.5, 0.5),
+                const_interval!(-0.25, 0.25),
+                [all, all, all, all],
+            ),
+        ];
+        for (rel, x, y, expected) in cases.iter() {
+            let mut r = rel.parse::<Relation>().unwrap();
+            let n_theta = const_interval!(0.0, 0.0);
+            let r0 = r.eval(*x, *y, n_theta, None);
+            let actual = [
+                r0.0[0].0,
+                r.eval_tightened(*x, *y, n_theta, r0.clone()).0[0].0,
+                r.eval_affine(*x, *y, n_theta, r0.clone()).0[0].0,
+                r.eval_taylor(*x, *y, n_theta, r0).0[0].0,
+            ];
+            assert_eq!(actual, *expected, "{}", rel);
+        }
+    }
+
+    #[test]
+    fn eval_on_box() {
+        fn f(rel: &str, x: Interval, y: Interval) -> TruthValue {
+            rel.parse::<Relation>().unwrap().eval_on_box(x, y)
+        }
+
+        let x = const_interval!(-0.5, 0.5);
+        let y = const_interval!(-0.5, 0.5);
+        assert_eq!(f("x^2 + y^2 < 1", x, y), TruthValue::True);
+        assert_eq!(f("x^2 + y^2 > 1", x, y), TruthValue::False);
+        assert_eq!(f("x^2 + y^2 = 1", x, y), TruthValue::False);
+        assert_eq!(f("y = x", x, y), TruthValue::Unknown);
+        assert_eq!(f("sqrt(x) >= 0", x, y), TruthValue::Unknown);
+        assert_eq!(
+            f("sqrt(x) >= 0", const_interval!(1.0, 2.0), y),
+            TruthValue::True
+        );
+        assert_eq!(f("r < 1", x, y), TruthValue::True);
+        assert_eq!(f("r = 2θ", x, y), TruthValue::Unknown);
+        assert_eq!(
+            f(
+                "r = sin(θ)",
+                const_interval!(2.0, 3.0),
+                const_interval!(2.0, 3.0)
+            ),
+            TruthValue::False
+        );
+    }
+
+    #[test]
+    fn eval_points() {
+        fn to_vec(r: &EvalResult) -> Vec<(SignSet, Decoration)> {
+            r.0.iter().map(|&DecSignSet(ss, d)| (ss, d)).collect()
+        }
+
+        let points = [
+            (0.0, 0.0),
+            (1.5, -2.0),
+            (-3.0, 0.5),
+            (2.0, 2.0),
+            (-0.25, 7.0),
+        ];
+        for rel in &[
+            "y = sin(x)",
+            "x^2 + y^2 < 2 && y > 2x - 1",
+            "y = sqrt(x) || y = ln(x) + 1/x",
+            "y = max(x, 1) * x + floor(x)",
+            "r = theta",
+        ] {
+            let mut r = rel.parse::<Relation>().unwrap();
+            let n_theta = const_interval!(1.0, 1.0);
+            let rs = r.eval_points(&points, n_theta, None);
+            assert_eq!(rs.len(), points.len());
+            for (&(x, y), r_batch) in points.iter().zip(&rs) {
+                let r_single = r.eval(
+                    interval!(x, x).unwrap(),
+                    interval!(y, y).unwrap(),
+                    n_theta,
+                    None,
+                );
+                assert_eq!(to_vec(r_batch), to_vec(&r_single));
+            }
+
+            // The second pass restores the values from the caches.
+            for level in &[EvalCacheLevel::PerAxis, EvalCacheLevel::Full] {
+                let mut cache = EvalCache::new(*level);
+                for _ in 0..2 {
+                    let rs_cached = r.eval_points(&points, n_theta, Some(&mut cache));
+                    assert_eq!(
+                        rs_cached.iter().map(to_vec).collect::<Vec<_>>(),
+                        rs.iter().map(to_vec).collect::<Vec<_>>()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn prove_solutions() {
+        fn f(rel: &str) -> (Vec<bool>, Vec<FormIndex>) {
+            let mut r = rel.parse::<Relation>().unwrap();
+            let x = const_interval!(0.875, 1.125);
+            let y = const_interval!(0.9375, 1.0625);
+            let n_theta = const_interval!(0.0, 0.0);
+            r.eval(x, y, n_theta, None);
+            let (zero_mask, common_zero_forms) = r.prove_solutions(x, y, n_theta);
+            (zero_mask.0.to_vec(), common_zero_forms)
+        }
+
+        assert_eq!(f("y = x^2"), (vec![true], vec![]));
+        assert_eq!(f("y = x^2 + 1"), (vec![false], vec![]));
+        // The partial derivative with respect to x is zero and that with respect to y
+        // contains zero, neither of which proves anything.
+        assert_eq!(f("y^2 - 2 y + 1 = 0.01"), (vec![false], vec![]));
+        assert_eq!(f("y < x^2"), (vec![false], vec![]));
+        assert_eq!(f("y = x^2 && x^2 + y^2 = 2"), (vec![true, true], vec![2]));
+        assert_eq!(f("y = x^2 && y = x^2 + 0.001"), (vec![true, true], vec![]));
+        assert_eq!(f("y = x^2 && x + y = 2.5"), (vec![true, false], vec![]));
+    }
+
+    #[test]
+    fn expand_polar_coords() {
+        use crate::ops::ScalarBinaryOp;
+
+        fn f(rel: &str) -> (usize, usize) {
+            let r = rel.parse::<Relation>().unwrap();
+            let count = |op| {
+                r.terms
+                    .iter()
+                    .filter(|t| matches!(t.kind, StaticTermKind::Binary(o, _, _) if o == op))
+                    .count()
+            };
+            (count(ScalarBinaryOp::Hypot), count(ScalarBinaryOp::Atan2))
+        }
+
+        // The branches r ≥ 0 and r ≤ 0 share the terms for r and θ.
+        assert_eq!(f("r = θ"), (1, 1));
+        assert_eq!(f("r^2 = θ"), (1, 1));
+        assert_eq!(f("r sin(θ) = 1"), (1, 1));
+        assert_eq!(f("r < θ && r > θ/2"), (1, 1));
+        assert_eq!(f("ln(r) = tan(θ)"), (1, 1));
+        assert_eq!(f("θ = 1"), (0, 1));
+
+        fn g(rel: &str) -> (usize, usize) {
+            use crate::ops::ScalarUnaryOp::*;
+            let r = rel.parse::<Relation>().unwrap();
+            let n_trig = r
+                .terms
+                .iter()
+                .filter(|t| matches!(t.kind, StaticTermKind::Unary(Cos | Sin | Tan, _)))
+                .count();
+            (n_trig, r.n_atom_forms)
+        }
+
+        // They also share the trigonometric functions of θ with integer coefficients.
+        assert_eq!(g("r = 1 + 2 sin(5θ)"), (1, 2));
+        assert_eq!(g("r = cos(2θ + 1) + tan(3θ)"), (2, 2));
+        assert_eq!(g("r = sin(θ/2)"), (2, 2));
+        // The branches coincide.
+        assert_eq!(g("r sin(θ) = 1"), (1, 1));
+        assert_eq!(g("r^2 = cos(2θ)"), (1, 1));
+    }
+
     #[test]
     fn n_theta_range() {
         fn f(rel: &str) -> Interval {
@@ -658,4 +1595,28 @@ mod tests {
         assert_eq!(f("x = sin(θ) && r = cos(θ)"), Polar);
         assert_eq!(f("x = sin(θ) || r = cos(θ)"), Polar);
     }
+
+    #[cfg(not(feature = "arb"))]
+    #[test]
+    fn unavailable_functions() {
+        fn f(rel: &str) -> Result<Relation, String> {
+            rel.parse::<Relation>()
+        }
+
+        assert!(f("y = Ai(x) + Bi'(x)").is_ok());
+        assert!(f("y = J(2, x) + Y(-1 + 2, x)").is_ok());
+        assert!(f("y = Si(x) + Ci(x)").is_ok());
+        assert_eq!(
+            f("y = Ei(1)").err().unwrap(),
+            "function `Ei` is only available when the feature `arb` is enabled"
+        );
+        assert_eq!(
+            f("y = J(1/2, x)").err().unwrap(),
+            "`J(n, x)` only permits integers for `n` unless the feature `arb` is enabled"
+        );
+        assert!(f("y = J(0.5, x)").is_err());
+        assert!(f("y = J(10^400, x)").is_err());
+        assert!(f("y = Y(x, 1)").is_err());
+        assert!(f("y = normquantile(x)").is_err());
+    }
 }
@@ -0,0 +1,648 @@
+use crate::interval_set_ops::sinc;
+use gmp_mpfr_sys::mpfr;
+use inari::{const_interval, interval, Interval};
+use rug::Float;
+
+const ONE: Interval = const_interval!(1.0, 1.0);
+const ZERO: Interval = const_interval!(0.0, 0.0);
+
+macro_rules! mpfr_fn {
+    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident) => {
+        fn $f_rd(x: f64) -> f64 {
+            mpfr_fn!($mpfr_f(x, RNDD))
+        }
+
+        fn $f_ru(x: f64) -> f64 {
+            mpfr_fn!($mpfr_f(x, RNDU))
+        }
+    };
+
+    ($mpfr_f:ident($x:ident, $rnd:ident)) => {{
+        let mut x = Float::with_val(f64::MANTISSA_DIGITS, $x);
+        let rnd = mpfr::rnd_t::$rnd;
+        unsafe {
+            mpfr::$mpfr_f(x.as_raw_mut(), x.as_raw(), rnd);
+            mpfr::get_d(x.as_raw(), rnd)
+        }
+    }};
+}
+
+macro_rules! mpfr_fn_si {
+    ($mpfr_f:ident, $f_rd:ident, $f_ru:ident) => {
+        fn $f_rd(n: i32, x: f64) -> f64 {
+            mpfr_fn_si!($mpfr_f(n, x, RNDD))
+        }
+
+        fn $f_ru(n: i32, x: f64) -> f64 {
+            mpfr_fn_si!($mpfr_f(n, x, RNDU))
+        }
+    };
+
+    ($mpfr_f:ident($n:ident, $x:ident, $rnd:ident)) => {{
+        let mut x = Float::with_val(f64::MANTISSA_DIGITS, $x);
+        let rnd = mpfr::rnd_t::$rnd;
+        unsafe {
+            mpfr::$mpfr_f(x.as_raw_mut(), $n.into(), x.as_raw(), rnd);
+            mpfr::get_d(x.as_raw(), rnd)
+        }
+    }};
+}
+
+mpfr_fn!(ai, ai_rd, ai_ru);
+mpfr_fn_si!(jn, jn_rd, jn_ru);
+mpfr_fn_si!(yn, yn_rd, yn_ru);
+
+fn i(x: f64) -> Interval {
+    interval!(x, x).unwrap()
+}
+
+/// The maximum number of terms summed by [`sum_series`].
+const MAX_SERIES_TERMS: u32 = 1024;
+
+/// Returns an enclosure of t_0 + t_1 + …, where t_k = r(k) t_{k-1} for k ≥ 1.
+///
+/// |r(k)| must be nonincreasing in k.
+/// [`Interval::ENTIRE`] is returned if the series does not converge within [`MAX_SERIES_TERMS`] terms.
+fn sum_series<F>(t0: Interval, r: F) -> Interval
+where
+    F: Fn(f64) -> Interval,
+{
+    let mut s = t0;
+    let mut t = t0;
+    for k in 1..MAX_SERIES_TERMS {
+        let k = k as f64;
+        t = r(k) * t;
+        s += t;
+        // If |r(j)| ≤ 1/2 for all j > k, the remainder is bounded by |t_k|.
+        let tail = t.mag();
+        if r(k + 1.0).mag() <= 0.5 && tail <= f64::EPSILON * s.mag() {
+            return s + interval!(-tail, tail).unwrap();
+        }
+    }
+    Interval::ENTIRE
+}
+
+/// Returns enclosures of P(ν, z) and Q(ν, z) in the Hankel expansion:
+///
+///   J_ν(z) = √(2 / (π z)) (P(ν, z) cos χ - Q(ν, z) sin χ), χ = z - (ν/2 + 1/4) π,
+///
+/// where `mu` = 4ν^2 and 0 ≤ ν ≤ 1.
+///
+/// Each series is truncated after ℓ ≥ 1 terms, so that the remainder is bounded by
+/// the first neglected term and has the same sign (DLMF 10.17.iii).
+fn hankel_pq(mu: Interval, z: Interval) -> (Interval, Interval) {
+    // The maximum number of terms of each series.
+    const MAX_TERMS: f64 = 64.0;
+    let z_inv = ONE / z;
+    // a_k(ν) / z^k, where a_k(ν) = (μ - 1^2) (μ - 3^2) … (μ - (2k - 1)^2) / (k! 8^k).
+    let next = |t: Interval, k: f64| t * (mu - i((2.0 * k - 1.0).powi(2))) / i(8.0 * k) * z_inv;
+    let mut p = ONE;
+    let mut q = ZERO;
+    let mut t = ONE;
+    let mut l = 1.0;
+    loop {
+        // The sign of the terms of order 2ℓ - 2 and 2ℓ - 1.
+        let sign = if l % 2.0 == 1.0 { ONE } else { -ONE };
+        let t1 = next(t, 2.0 * l - 1.0);
+        q += sign * t1;
+        let t2 = next(t1, 2.0 * l);
+        // The terms decrease roughly while their order is less than 2z.
+        if t2.mag() <= f64::EPSILON || l >= z.inf().min(MAX_TERMS) {
+            let t3 = next(t2, 2.0 * l + 1.0);
+            p += (-sign * t2).convex_hull(ZERO);
+            q += (-sign * t3).convex_hull(ZERO);
+            return (p, q);
+        }
+        p += -sign * t2;
+        t = t2;
+        l += 1.0;
+    }
+}
+
+// Ai(0).
+const AIRY_AI_ZERO: Interval = const_interval!(0.3550280538878172, 0.3550280538878173);
+// -Ai'(0).
+const AIRY_NEG_AI_PRIME_ZERO: Interval = const_interval!(0.25881940379280677, 0.2588194037928068);
+const SQRT_3: Interval = const_interval!(1.7320508075688772, 1.7320508075688774);
+
+/// Ai and Bi on [-∞, -[`AIRY_ASYMPTOTIC_MIN`]] are evaluated with the asymptotic expansions.
+const AIRY_ASYMPTOTIC_MIN: f64 = 7.0;
+
+/// Bi(x) and Bi'(x) exceed 10^288 for x ≥ [`AIRY_POSITIVE_MAX`], so they are not evaluated there.
+const AIRY_POSITIVE_MAX: f64 = 100.0;
+
+/// Returns enclosures of the ranges of Ai, Ai', Bi and Bi' over `x`, in this order.
+///
+/// `x` must be nonempty.
+pub fn airy(x: Interval) -> [Interval; 4] {
+    let ys0 = {
+        let x = x.intersection(interval!(f64::NEG_INFINITY, -AIRY_ASYMPTOTIC_MIN).unwrap());
+        if x.is_empty() {
+            [Interval::EMPTY; 4]
+        } else {
+            airy_asymptotic(-x)
+        }
+    };
+    let ys1 = {
+        let x = x.intersection(interval!(-AIRY_ASYMPTOTIC_MIN, 0.0).unwrap());
+        if x.is_empty() {
+            [Interval::EMPTY; 4]
+        } else {
+            airy_taylor(x)
+        }
+    };
+    let ys2 = {
+        let x = x.intersection(const_interval!(0.0, f64::INFINITY));
+        if x.is_empty() {
+            [Interval::EMPTY; 4]
+        } else {
+            airy_monotone(x)
+        }
+    };
+    let mut ys = ys0;
+    for ((y, y1), y2) in ys.iter_mut().zip(ys1).zip(ys2) {
+        *y = y.convex_hull(y1).convex_hull(y2);
+    }
+    ys
+}
+
+/// Returns enclosures of the ranges of Ai(-z), Ai'(-z), Bi(-z) and Bi'(-z) over `z`
+/// using the Hankel expansions of J_{±1/3} and J_{±2/3} (DLMF 9.6.6–9.6.9).
+///
+/// `z` must be a subset of [[`AIRY_ASYMPTOTIC_MIN`], +∞].
+fn airy_asymptotic(z: Interval) -> [Interval; 4] {
+    let zeta = const_interval!(2.0, 2.0) / const_interval!(3.0, 3.0) * z * z.sqrt();
+    let theta = zeta - Interval::FRAC_PI_4;
+    let c = theta.cos();
+    let s = theta.sin();
+    let (p1, q1) = hankel_pq(const_interval!(4.0, 4.0) / const_interval!(9.0, 9.0), zeta);
+    let (p2, q2) = hankel_pq(
+        const_interval!(16.0, 16.0) / const_interval!(9.0, 9.0),
+        zeta,
+    );
+    let frac_1_sqrt_pi = Interval::FRAC_2_SQRT_PI / const_interval!(2.0, 2.0);
+    let z_4 = z.sqrt().sqrt();
+    // z^(-1/4) / √π and z^(1/4) / √π.
+    let u = frac_1_sqrt_pi / z_4;
+    let v = frac_1_sqrt_pi * z_4;
+    [
+        u * (p1 * c - q1 * s),
+        v * (p2 * s + q2 * c),
+        -u * (p1 * s + q1 * c),
+        v * (p2 * c - q2 * s),
+    ]
+}
+
+/// Returns enclosures of Ai(x), Ai'(x), Bi(x) and Bi'(x) using the Maclaurin series.
+///
+/// `x` must be bounded.
+fn airy_series(x: Interval) -> [Interval; 4] {
+    let x3 = x.pown(3);
+    // Ai(x) = Ai(0) f(x) + Ai'(0) g(x), Bi(x) = √3 (Ai(0) f(x) - Ai'(0) g(x)), where
+    //   f(x) = 1 + x^3/(2⋅3) + x^6/(2⋅3⋅5⋅6) + …,
+    //   g(x) = x + x^4/(3⋅4) + x^7/(3⋅4⋅6⋅7) + ….
+    let f = sum_series(ONE, |k| x3 / i((3.0 * k - 1.0) * (3.0 * k)));
+    let g = sum_series(x, |k| x3 / i(3.0 * k * (3.0 * k + 1.0)));
+    let df = sum_series(x.sqr() / const_interval!(2.0, 2.0), |k| {
+        x3 / i(3.0 * k * (3.0 * k + 2.0))
+    });
+    let dg = sum_series(ONE, |k| x3 / i((3.0 * k - 2.0) * (3.0 * k)));
+    let c1 = AIRY_AI_ZERO;
+    let c2 = AIRY_NEG_AI_PRIME_ZERO;
+    [
+        c1 * f - c2 * g,
+        c1 * df - c2 * dg,
+        SQRT_3 * (c1 * f + c2 * g),
+        SQRT_3 * (c1 * df + c2 * dg),
+    ]
+}
+
+/// Returns enclosures of Ai(x), Ai'(x), Bi(x) and Bi'(x).
+///
+/// `x` must be in [-[`AIRY_ASYMPTOTIC_MIN`], [`AIRY_POSITIVE_MAX`]].
+fn airy_point(x: f64) -> [Interval; 4] {
+    let ys = airy_series(i(x));
+    if x < 0.0 {
+        ys
+    } else {
+        // The series of Ai and Ai' suffer from cancellation for x > 0.
+        let ai = interval!(ai_rd(x), ai_ru(x)).unwrap();
+        // Ai(x) Bi'(x) - Ai'(x) Bi(x) = 1/π.
+        let ai_prime = (ai * ys[3] - Interval::FRAC_1_PI) / ys[2];
+        [ai, ai_prime, ys[2], ys[3]]
+    }
+}
+
+/// Returns enclosures of the ranges of Ai, Ai', Bi and Bi' over `x`.
+///
+/// `x` must be a nonempty subset of [-[`AIRY_ASYMPTOTIC_MIN`], 0].
+fn airy_taylor(x: Interval) -> [Interval; 4] {
+    let a = x.inf();
+    let m = x.mid();
+    let r = (x - i(m)).mag();
+    let dx = interval!(-r, r).unwrap();
+    let dx2 = dx.sqr() / const_interval!(2.0, 2.0);
+    let ya = airy_point(a);
+    let ym = airy_point(m);
+    let mut ys = [Interval::EMPTY; 4];
+    for k in [0, 2] {
+        // Both Ai and Bi satisfy y'' = x y. Since F(x) = y'(x)^2 - x y(x)^2 is nonincreasing,
+        // |y'(x)| ≤ √F(a) and |x y(x)| ≤ √(-a F(a)) for x ∈ [a, 0].
+        let f = ya[k + 1].sqr() - i(a) * ya[k].sqr();
+        let s = f.sqrt().sup();
+        let d1 = interval!(-s, s).unwrap();
+        let s2 = (i(-a) * f).sqrt().sup();
+        let d2 = interval!(-s2, s2).unwrap();
+        // |y'''(x)| = |y(x) + x y'(x)| ≤ |y(m)| + √F(a) r - a √F(a).
+        let s3 = (ym[k].abs() + i(s) * i(r) - i(a) * i(s)).sup();
+        let d3 = interval!(-s3, s3).unwrap();
+        ys[k] = (ym[k] + ym[k + 1] * dx + d2 * dx2).intersection(ym[k] + d1 * dx);
+        ys[k + 1] = (ym[k + 1] + i(m) * ym[k] * dx + d3 * dx2).intersection(d1);
+    }
+    ys
+}
+
+/// Returns enclosures of the ranges of Ai, Ai', Bi and Bi' over `x`.
+///
+/// `x` must be a nonempty subset of [0, +∞].
+fn airy_monotone(x: Interval) -> [Interval; 4] {
+    // Ai is decreasing and Ai', Bi and Bi' are increasing on [0, +∞].
+    let a = x.inf().min(AIRY_POSITIVE_MAX);
+    let b = x.sup();
+    let ya = airy_point(a);
+    let yb = airy_point(b.min(AIRY_POSITIVE_MAX));
+    let beyond_max = b > AIRY_POSITIVE_MAX;
+    [
+        interval!(if beyond_max { 0.0 } else { yb[0].inf() }, ya[0].sup()).unwrap(),
+        interval!(ya[1].inf(), if beyond_max { 0.0 } else { yb[1].sup() }).unwrap(),
+        interval!(
+            ya[2].inf(),
+            if beyond_max {
+                f64::INFINITY
+            } else {
+                yb[2].sup()
+            }
+        )
+        .unwrap(),
+        interval!(
+            ya[3].inf(),
+            if beyond_max {
+                f64::INFINITY
+            } else {
+                yb[3].sup()
+            }
+        )
+        .unwrap(),
+    ]
+}
+
+/// Returns an enclosure of the range of J_n over `x`.
+///
+/// `n` must be an integer and `x` must be nonempty.
+pub fn bessel_j(n: f64, x: Interval) -> Interval {
+    bessel_jy(n, x, false).intersection(const_interval!(-1.0, 1.0))
+}
+
+/// Returns an enclosure of the range of Y_n over `x` ∩ (0, +∞].
+///
+/// `n` must be an integer and `x` must be nonempty.
+pub fn bessel_y(n: f64, x: Interval) -> Interval {
+    // Y_ν is increasing on (0, Y_ZERO_RD] for ν ≥ 0, since Y_ν' > 0 on (0, y_{ν,1}]
+    // and y_{ν,1} ≥ y_{0,1} = 0.8935769…, where y_{ν,1} is the first positive zero of Y_ν
+    // (DLMF 10.21.3).
+    const Y_ZERO_RD: f64 = 0.89;
+    let x = x.intersection(const_interval!(0.0, f64::INFINITY));
+    if x.is_empty() || x.sup() == 0.0 {
+        return Interval::EMPTY;
+    }
+    let a = x.inf();
+    let b = x.sup();
+    if a >= Y_ZERO_RD {
+        return bessel_jy(n, x, true);
+    }
+    let y0 = if n.abs() > MAX_BESSEL_ORDER {
+        Interval::ENTIRE
+    } else {
+        let m = n.abs() as i32;
+        let inf = if a == 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            yn_rd(m, a)
+        };
+        let y = interval!(inf, yn_ru(m, b.min(Y_ZERO_RD))).unwrap();
+        // Y_{-n} = (-1)^n Y_n.
+        if n < 0.0 && m % 2 == 1 {
+            -y
+        } else {
+            y
+        }
+    };
+    let y1 = if b > Y_ZERO_RD {
+        bessel_jy(n, interval!(Y_ZERO_RD, b).unwrap(), true)
+    } else {
+        Interval::EMPTY
+    };
+    y0.convex_hull(y1)
+}
+
+/// The maximum order of the Bessel functions for which enclosures are computed
+/// other than by their global bounds.
+const MAX_BESSEL_ORDER: f64 = 1048576.0;
+
+/// Returns an enclosure of the range of J_n (if `second_kind` is `false`) or Y_n over `x`.
+///
+/// Let f_n be either J_n or Y_n, m be the midpoint and r be the radius of `x`. Then,
+///
+///   f_n(x) ∈ f_n(m) + f_n'(m) [-r, r] + [-M, M] [0, r^2/2],
+///
+/// where f_n' = (f_{n-1} - f_{n+1}) / 2 and M bounds |f_n''| = |f_{n-2} - 2 f_n + f_{n+2}| / 4.
+/// |f_ν(x)| is bounded by M_ν(a) = √(J_ν(a)^2 + Y_ν(a)^2) for |x| ≥ a > 0 and ν ≥ 0,
+/// since M_ν is decreasing (DLMF 10.18.17).
+fn bessel_jy(n: f64, x: Interval, second_kind: bool) -> Interval {
+    if n.abs() > MAX_BESSEL_ORDER {
+        return Interval::ENTIRE;
+    }
+    let n = n as i32;
+    let f = |n: i32, x: f64| {
+        if second_kind {
+            interval!(yn_rd(n, x), yn_ru(n, x)).unwrap()
+        } else {
+            interval!(jn_rd(n, x), jn_ru(n, x)).unwrap()
+        }
+    };
+    let a = x.abs().inf();
+    // [0, M_|n|(a)], where the upper bound is rounded up.
+    let env = |n: i32| {
+        let sup = if a == 0.0 {
+            f64::INFINITY
+        } else {
+            let n = n.abs();
+            let j = interval!(jn_rd(n, a), jn_ru(n, a)).unwrap();
+            let y = interval!(yn_rd(n, a), yn_ru(n, a)).unwrap();
+            (j.sqr() + y.sqr()).sqrt().sup()
+        };
+        interval!(0.0, sup).unwrap()
+    };
+    let env_n = env(n).sup();
+    let y = if x.is_common_interval() {
+        let m = x.mid();
+        let r = (x - i(m)).mag();
+        let dx = interval!(-r, r).unwrap();
+        let dx2 = dx.sqr() / const_interval!(2.0, 2.0);
+        let mut m2 = ((env(n - 2) + const_interval!(2.0, 2.0) * env(n) + env(n + 2))
+            / const_interval!(4.0, 4.0))
+        .sup();
+        if !second_kind {
+            // |J_n(x)| ≤ 1.
+            m2 = m2.min(1.0);
+        }
+        let d2 = interval!(-m2, m2).unwrap();
+        f(n, m) + (f(n - 1, m) - f(n + 1, m)) / const_interval!(2.0, 2.0) * dx + d2 * dx2
+    } else {
+        Interval::ENTIRE
+    };
+    y.intersection(interval!(-env_n, env_n).unwrap())
+}
+
+// Euler's constant γ.
+const EULER_GAMMA: Interval = const_interval!(0.5772156649015328, 0.5772156649015329);
+
+/// Si and Ci on [[`SI_CI_ASYMPTOTIC_MIN`], +∞] are evaluated with the asymptotic expansions.
+const SI_CI_ASYMPTOTIC_MIN: f64 = 18.0;
+
+/// Returns enclosures of the auxiliary functions f(x) and g(x) for the sine and cosine integrals
+/// using the asymptotic expansions, whose remainders are bounded by the first neglected terms
+/// and have the same signs (DLMF 6.12.3, 6.12.4).
+///
+/// `x` must be ≥ [`SI_CI_ASYMPTOTIC_MIN`].
+fn si_ci_auxiliary(x: f64) -> (Interval, Interval) {
+    let x = i(x);
+    let x_inv2 = (ONE / x).sqr();
+    // The terms decrease while 2k < x.
+    let n = (x.inf() / 2.0).floor();
+    let mut f = ZERO;
+    let mut g = ZERO;
+    // (2k)! / x^2k and (2k + 1)! / x^2k.
+    let mut s = ONE;
+    let mut t = ONE;
+    let mut k = 0.0;
+    loop {
+        let sign = if k % 2.0 == 0.0 { ONE } else { -ONE };
+        if k == n {
+            f += (sign * s).convex_hull(ZERO);
+            g += (sign * t).convex_hull(ZERO);
+            break;
+        }
+        f += sign * s;
+        g += sign * t;
+        k += 1.0;
+        s = s * i((2.0 * k - 1.0) * (2.0 * k)) * x_inv2;
+        t = t * i(2.0 * k * (2.0 * k + 1.0)) * x_inv2;
+    }
+    (f / x, g / x.sqr())
+}
+
+/// Returns an enclosure of Si(x).
+fn si_point(x: f64) -> Interval {
+    if x < 0.0 {
+        -si_point(-x)
+    } else if x < SI_CI_ASYMPTOTIC_MIN {
+        let x = i(x);
+        let x2 = x.sqr();
+        // Si(x) = Σ_{k≥0} (-1)^k x^(2k+1) / ((2k + 1) (2k + 1)!).
+        sum_series(x, |k| {
+            -x2 * i(2.0 * k - 1.0) / i(2.0 * k * (2.0 * k + 1.0) * (2.0 * k + 1.0))
+        })
+    } else {
+        let (f, g) = si_ci_auxiliary(x);
+        let x = i(x);
+        Interval::FRAC_PI_2 - f * x.cos() - g * x.sin()
+    }
+}
+
+/// Returns an enclosure of Ci(x).
+///
+/// `x` must be positive.
+fn ci_point(x: f64) -> Interval {
+    if x < SI_CI_ASYMPTOTIC_MIN {
+        let x = i(x);
+        let x2 = x.sqr();
+        // Ci(x) = γ + ln(x) + Σ_{k≥1} (-1)^k x^2k / (2k (2k)!).
+        EULER_GAMMA
+            + x.ln()
+            + sum_series(-x2 / const_interval!(4.0, 4.0), |k| {
+                -x2 * i(2.0 * k) / i((2.0 * k + 2.0) * (2.0 * k + 2.0) * (2.0 * k + 1.0))
+            })
+    } else {
+        let (f, g) = si_ci_auxiliary(x);
+        let x = i(x);
+        f * x.sin() - g * x.cos()
+    }
+}
+
+/// Returns an enclosure of [-M(a), M(a)], where M(a) = √(Ci(a)^2 + (Si(a) - π/2)^2)
+/// bounds both |Ci(x)| and |Si(x) - π/2| for x ≥ a.
+///
+/// `a` must be positive.
+fn si_ci_envelope(a: f64) -> Interval {
+    let env = (ci_point(a).sqr() + (si_point(a) - Interval::FRAC_PI_2).sqr())
+        .sqrt()
+        .sup();
+    interval!(-env, env).unwrap()
+}
+
+/// Returns an enclosure of the range of Si over `x`.
+///
+/// `x` must be nonempty.
+pub fn si(x: Interval) -> Interval {
+    // max_x |Si(x)| = Si(π), rounded up.
+    const MAX: f64 = 1.8519370519824663;
+    let a = x.inf();
+    let b = x.sup();
+    let y = if x.is_common_interval() {
+        // Si' = sinc.
+        let m = x.mid();
+        si_point(m) + sinc(x) * (x - i(m))
+    } else if a > 0.0 {
+        Interval::FRAC_PI_2 + si_ci_envelope(a)
+    } else if b < 0.0 {
+        -Interval::FRAC_PI_2 + si_ci_envelope(-b)
+    } else {
+        Interval::ENTIRE
+    };
+    y.intersection(interval!(-MAX, MAX).unwrap())
+}
+
+/// Returns an enclosure of the range of Ci over `x` ∩ (0, +∞].
+///
+/// `x` must be nonempty.
+pub fn ci(x: Interval) -> Interval {
+    // max_x Ci(x) = Ci(π/2), rounded up.
+    const MAX: f64 = 0.47200065143956865;
+    let x = x.intersection(const_interval!(0.0, f64::INFINITY));
+    if x.is_empty() || x.sup() == 0.0 {
+        return Interval::EMPTY;
+    }
+    let a = x.inf();
+    let b = x.sup();
+    let y = if a == 0.0 {
+        // Ci is increasing on (0, π/2].
+        let sup = if b <= Interval::FRAC_PI_2.inf() {
+            ci_point(b).sup()
+        } else {
+            MAX
+        };
+        interval!(f64::NEG_INFINITY, sup).unwrap()
+    } else if b == f64::INFINITY {
+        si_ci_envelope(a)
+    } else {
+        // Ci'(x) = cos(x) / x.
+        let m = x.mid();
+        ci_point(m) + x.cos() / x * (x - i(m))
+    };
+    y.intersection(interval!(f64::NEG_INFINITY, MAX).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(y: Interval, expected: f64) {
+        assert!(
+            y.contains(expected),
+            "{:?} does not contain {}",
+            y,
+            expected
+        );
+        assert!(
+            y.wid() < 1e-8 * expected.abs().max(1.0),
+            "{:?} is too wide",
+            y
+        );
+    }
+
+    #[test]
+    fn airy() {
+        let ys = super::airy(const_interval!(-10.0, -10.0));
+        check(ys[0], 0.04024123848644319);
+        check(ys[1], 0.99626504413279);
+        check(ys[2], -0.3146798296438386);
+        check(ys[3], 0.11941411339990924);
+        check(
+            super::airy(const_interval!(-3.0, -3.0))[0],
+            -0.37881429367765807,
+        );
+        check(
+            super::airy(const_interval!(2.0, 2.0))[1],
+            -0.05309038443365363,
+        );
+        check(
+            super::airy(const_interval!(2.0, 2.0))[2],
+            3.2980949999782147,
+        );
+        check(
+            super::airy(const_interval!(5.0, 5.0))[3],
+            1435.8190802179825,
+        );
+
+        let ys = super::airy(Interval::ENTIRE);
+        assert!(ys[0].sup() >= 0.5357);
+        assert!(ys[1].is_entire());
+        assert_eq!(ys[2].sup(), f64::INFINITY);
+        assert!(ys[3].is_entire());
+        let ys = super::airy(const_interval!(0.0, f64::INFINITY));
+        assert!(ys[0].inf() >= 0.0 && ys[0].contains(0.3550280538878172));
+        assert!(ys[1].sup() <= 0.0);
+        let ys = super::airy(const_interval!(1e300, 1e300));
+        assert!(ys[0].inf() >= 0.0 && ys[0].sup() <= 1e-290);
+        assert!(ys[1].sup() <= 0.0);
+    }
+
+    #[test]
+    fn bessel() {
+        check(
+            bessel_j(3.0, const_interval!(-2.5, -2.5)),
+            -0.21660039103911352,
+        );
+        check(
+            bessel_j(0.0, const_interval!(50.0, 50.0)),
+            0.055812327669251815,
+        );
+        check(bessel_y(2.0, const_interval!(0.5, 0.5)), -5.441370837174266);
+        check(
+            bessel_y(-1.0, const_interval!(30.0, 30.0)),
+            -0.08442557066174723,
+        );
+
+        let y = bessel_j(1.0, Interval::ENTIRE);
+        assert_eq!(y, const_interval!(-1.0, 1.0));
+        let y = bessel_y(0.0, const_interval!(0.0, 0.5));
+        assert_eq!(y.inf(), f64::NEG_INFINITY);
+        assert!(y.sup() >= -0.44451873350670656 && y.sup() < -0.4445187335);
+        let y = bessel_y(-1.0, const_interval!(0.0, 0.5));
+        assert!(y.inf() <= 1.471472392670243 && y.inf() > 1.4714723926);
+        assert_eq!(y.sup(), f64::INFINITY);
+        assert!(bessel_y(0.0, const_interval!(-1.0, 0.0)).is_empty());
+        assert!(bessel_y(0.0, const_interval!(1.0, f64::INFINITY)).sup() < 1.0);
+    }
+
+    #[test]
+    fn si_ci() {
+        check(si(const_interval!(-5.0, -5.0)), -1.549931244944674);
+        check(si(const_interval!(40.0, 40.0)), 1.5869851193547845);
+        check(ci(const_interval!(0.25, 0.25)), -0.8246630625809457);
+        check(ci(const_interval!(30.0, 30.0)), -0.033032417282071144);
+
+        assert_eq!(
+            si(Interval::ENTIRE),
+            const_interval!(-1.8519370519824663, 1.8519370519824663)
+        );
+        assert_eq!(
+            ci(Interval::ENTIRE),
+            const_interval!(f64::NEG_INFINITY, 0.47200065143956865)
+        );
+        assert!(ci(const_interval!(-1.0, 0.0)).is_empty());
+        assert!(ci(const_interval!(0.0, 1.0)).inf() == f64::NEG_INFINITY);
+        assert!(si(const_interval!(30.0, f64::INFINITY)).contains(std::f64::consts::FRAC_PI_2));
+    }
+}
@@ -0,0 +1,241 @@
+use inari::{const_interval, interval, Interval};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// The maximum total degree of the polynomial part of a Taylor model.
+const ORDER: usize = 3;
+
+/// The number of the monomials uⁱvʲ with i + j ≤ `ORDER`.
+const N_COEFFS: usize = (ORDER + 1) * (ORDER + 2) / 2;
+
+const ZERO: Interval = const_interval!(0.0, 0.0);
+const ONE: Interval = const_interval!(1.0, 1.0);
+
+/// A Taylor model p(u, v) + R, where p is a polynomial in u = x - x₀ and v = y - y₀
+/// of total degree ≤ 3 with interval coefficients, (x₀, y₀) is the center of the box
+/// over which the model is constructed, and R is an interval that encloses the remainder.
+///
+/// The polynomial part represents the dependency of a term on x and y symbolically
+/// up to the order, so Taylor models give much tighter enclosures than intervals over
+/// small boxes, where the remainder is of a higher order of the size of the box.
+#[derive(Clone, Copy, Debug)]
+pub struct TaylorModel {
+    /// The coefficients of the monomials, ordered by total degree and then by the degree in v:
+    /// 1, u, v, u², uv, v², u³, ….
+    p: [Interval; N_COEFFS],
+    r: Interval,
+    /// The ranges of uⁱ for 0 ≤ i ≤ 2 `ORDER`.
+    u: [Interval; 2 * ORDER + 1],
+    /// The ranges of vʲ for 0 ≤ j ≤ 2 `ORDER`.
+    v: [Interval; 2 * ORDER + 1],
+}
+
+impl TaylorModel {
+    /// Returns a Taylor model over the same box as `self` that only represents the interval.
+    pub fn with_constant(&self, x: Interval) -> Self {
+        Self::constant(x, self.u, self.v)
+    }
+
+    fn constant(x: Interval, u: [Interval; 2 * ORDER + 1], v: [Interval; 2 * ORDER + 1]) -> Self {
+        let mut p = [ZERO; N_COEFFS];
+        p[0] = x;
+        Self { p, r: ZERO, u, v }
+    }
+
+    /// Creates the Taylor models of x and y over the box X × Y.
+    ///
+    /// Returns [`None`] if either of the intervals is not bounded or empty.
+    pub fn variables(x: Interval, y: Interval) -> Option<(Self, Self)> {
+        if !x.is_common_interval() || !y.is_common_interval() {
+            return None;
+        }
+        let x0 = interval!(x.mid(), x.mid()).unwrap();
+        let y0 = interval!(y.mid(), y.mid()).unwrap();
+        let powers = |x: Interval| {
+            let mut xs = [ONE; 2 * ORDER + 1];
+            for (i, x_i) in xs.iter_mut().enumerate() {
+                *x_i = x.pown(i as i32);
+            }
+            xs
+        };
+        let (u, v) = (powers(x - x0), powers(y - y0));
+        let mut tx = Self::constant(x0, u, v);
+        tx.p[Self::index(1, 0)] = ONE;
+        let mut ty = Self::constant(y0, u, v);
+        ty.p[Self::index(0, 1)] = ONE;
+        Some((tx, ty))
+    }
+
+    /// Returns an enclosure of the range of the Taylor model.
+    pub fn range(&self) -> Interval {
+        self.bound_polynomial() + self.r
+    }
+
+    /// Returns a Taylor model that encloses f(self).
+    ///
+    /// `coeffs(x, n)` must return the Taylor coefficients [f(x), f'(x), f''(x)/2!, …, f⁽ⁿ⁾(x)/n!]
+    /// evaluated over the interval `x`, or [`None`] if f is not n times continuously
+    /// differentiable over `x`.
+    pub fn compose<F>(self, coeffs: F) -> Option<Self>
+    where
+        F: Fn(Interval, usize) -> Option<Vec<Interval>>,
+    {
+        let c = self.p[0].mid();
+        let c = interval!(c, c).ok()?;
+        let a = coeffs(c, ORDER)?;
+        let b = coeffs(self.range().convex_hull(c), ORDER + 1)?[ORDER + 1];
+        if !b.is_common_interval() {
+            return None;
+        }
+
+        // f(c + t) = Σ_{k=0}^{n} a_k tᵏ + f⁽ⁿ⁺¹⁾(ξ)/(n+1)! tⁿ⁺¹, where n = ORDER
+        // and ξ lies between c and c + t.
+        let mut t = self;
+        t.p[0] -= c;
+        let mut y = Self::constant(a[ORDER], self.u, self.v);
+        for &a in a[..ORDER].iter().rev() {
+            y = y * t;
+            y.p[0] += a;
+        }
+        y.r += b * t.range().pown(ORDER as i32 + 1);
+        Some(y)
+    }
+
+    /// Returns the square of the Taylor model.
+    pub fn sqr(self) -> Self {
+        self * self
+    }
+
+    fn bound_polynomial(&self) -> Interval {
+        let mut y = ZERO;
+        for d in 0..=ORDER {
+            for j in 0..=d {
+                let i = d - j;
+                y += self.p[Self::index(i, j)] * self.bound_monomial(i, j);
+            }
+        }
+        y
+    }
+
+    fn bound_monomial(&self, i: usize, j: usize) -> Interval {
+        self.u[i] * self.v[j]
+    }
+
+    fn index(i: usize, j: usize) -> usize {
+        let d = i + j;
+        d * (d + 1) / 2 + j
+    }
+}
+
+impl Default for TaylorModel {
+    fn default() -> Self {
+        Self::constant(ZERO, [ZERO; 2 * ORDER + 1], [ZERO; 2 * ORDER + 1])
+    }
+}
+
+impl Add for TaylorModel {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let mut p = self.p;
+        for (p, q) in p.iter_mut().zip(rhs.p.iter()) {
+            *p += *q;
+        }
+        Self {
+            p,
+            r: self.r + rhs.r,
+            ..self
+        }
+    }
+}
+
+impl Sub for TaylorModel {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Neg for TaylorModel {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            p: self.p.map(|p| -p),
+            r: -self.r,
+            ..self
+        }
+    }
+}
+
+impl Mul for TaylorModel {
+    type Output = Self;
+
+    // The terms of the product of the polynomials of degrees higher than `ORDER`
+    // are bounded and put into the remainder.
+    fn mul(self, rhs: Self) -> Self {
+        let mut p = [ZERO; N_COEFFS];
+        let mut r = ZERO;
+        if self.r != ZERO || rhs.r != ZERO {
+            r = self.r * rhs.r + self.r * rhs.bound_polynomial() + rhs.r * self.bound_polynomial();
+        }
+        for d1 in 0..=ORDER {
+            for j1 in 0..=d1 {
+                let a = self.p[Self::index(d1 - j1, j1)];
+                if a == ZERO {
+                    continue;
+                }
+                for d2 in 0..=ORDER {
+                    for j2 in 0..=d2 {
+                        let b = rhs.p[Self::index(d2 - j2, j2)];
+                        if b == ZERO {
+                            continue;
+                        }
+                        let (i, j) = (d1 - j1 + d2 - j2, j1 + j2);
+                        if i + j <= ORDER {
+                            p[Self::index(i, j)] += a * b;
+                        } else {
+                            r += a * b * self.bound_monomial(i, j);
+                        }
+                    }
+                }
+            }
+        }
+        Self { p, r, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn taylor_model() {
+        let (x, y) =
+            TaylorModel::variables(const_interval!(1.0, 1.25), const_interval!(-0.5, 0.5)).unwrap();
+        assert_eq!(x.range(), const_interval!(1.0, 1.25));
+        assert_eq!(y.range(), const_interval!(-0.5, 0.5));
+        assert_eq!((x - x).range(), ZERO);
+        assert_eq!(x.sqr().range(), const_interval!(0.984375, 1.5625));
+        // Products of degree ≤ 3 are exact.
+        assert_eq!((x * x * y - x.sqr() * y).range(), ZERO);
+
+        // exp(x) - 1 - x - x²/2 - x³/6 over X = [-1/16, 1/16] is enclosed
+        // much more tightly than by interval arithmetic.
+        let (x, _) = TaylorModel::variables(const_interval!(-0.0625, 0.0625), ZERO).unwrap();
+        let ex = x
+            .compose(|x, n| {
+                let mut a = vec![];
+                let mut t = x.exp();
+                for k in 0..=n {
+                    a.push(t);
+                    t /= interval!((k + 1) as f64, (k + 1) as f64).unwrap();
+                }
+                Some(a)
+            })
+            .unwrap();
+        let c = |a: f64| x.with_constant(interval!(a, a).unwrap());
+        let t = ex - c(1.0) - x - x.sqr() * c(0.5) - x * x * x * c(1.0 / 6.0);
+        assert!(t.range().mag() < 1e-6);
+    }
+}
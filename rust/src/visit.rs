@@ -1,14 +1,18 @@
 use crate::{
-    ast::{BinaryOp, Expr, ExprId, NaryOp, TernaryOp, UnaryOp, ValueType, VarSet, UNINIT_EXPR_ID},
+    ast::{
+        BinaryOp, Expr, ExprId, NaryOp, QuaternaryOp, TernaryOp, UnaryOp, ValueType, VarSet,
+        UNINIT_EXPR_ID,
+    },
     binary, constant,
     interval_set::Site,
     nary,
     ops::{
-        FormIndex, RankedMinMaxOp, RelOp, ScalarBinaryOp, ScalarTernaryOp, ScalarUnaryOp,
-        StaticForm, StaticFormKind, StaticTerm, StaticTermKind, StoreIndex,
+        FormIndex, RankedMinMaxOp, RelOp, ScalarBinaryOp, ScalarQuaternaryOp, ScalarTernaryOp,
+        ScalarUnaryOp, StaticForm, StaticFormKind, StaticTerm, StaticTermKind, StoreIndex,
     },
-    pown, rootn, ternary, unary, uninit, var,
+    pown, quaternary, rootn, ternary, unary, uninit, var,
 };
+use inari::{const_dec_interval, DecInterval};
 use rug::Rational;
 use std::{
     cmp::Ordering,
@@ -42,6 +46,12 @@ fn traverse_expr<'a, V: Visit<'a>>(v: &mut V, e: &'a Expr) {
             v.visit_expr(y);
             v.visit_expr(z);
         }
+        quaternary!(_, x, y, z, w) => {
+            v.visit_expr(x);
+            v.visit_expr(y);
+            v.visit_expr(z);
+            v.visit_expr(w);
+        }
         nary!(_, xs) => {
             for x in xs {
                 v.visit_expr(x);
@@ -76,6 +86,12 @@ fn traverse_expr_mut<V: VisitMut>(v: &mut V, e: &mut Expr) {
             v.visit_expr_mut(y);
             v.visit_expr_mut(z);
         }
+        quaternary!(_, x, y, z, w) => {
+            v.visit_expr_mut(x);
+            v.visit_expr_mut(y);
+            v.visit_expr_mut(z);
+            v.visit_expr_mut(w);
+        }
         nary!(_, xs) => {
             for x in xs {
                 v.visit_expr_mut(x);
@@ -211,6 +227,70 @@ where
     }
 }
 
+/// Replaces r with -r and θ with θ + π, which gives another pair of polar coordinates
+/// of the same point.
+///
+/// r^a, sin(a θ + b) and cos(a θ + b), where a is an integer and b is a constant,
+/// are replaced with (-1)^a times themselves, and tan(a θ + b) is left as is,
+/// so that they remain the same as in the original expression.
+///
+/// Precondition: the expression has been pre-transformed and simplified.
+pub struct FlipPolarCoords;
+
+impl FlipPolarCoords {
+    /// Returns a if the expression is of the form a θ + b,
+    /// where a is a rational number and b is a constant.
+    fn theta_coefficient(e: &Expr) -> Option<Rational> {
+        use NaryOp::*;
+        match e {
+            var!(name) if name == "theta" || name == "θ" => Some(1.into()),
+            nary!(Plus, xs) => match &xs[..] {
+                [constant!(_), x] => Self::theta_coefficient(x),
+                _ => None,
+            },
+            nary!(Times, xs) => match &xs[..] {
+                [constant!(a), var!(name)] if name == "theta" || name == "θ" => a.1.clone(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl VisitMut for FlipPolarCoords {
+    fn visit_expr_mut(&mut self, e: &mut Expr) {
+        use {BinaryOp::*, NaryOp::*, UnaryOp::*};
+        match e {
+            binary!(Pow, var!(name), constant!(a)) if name == "r" => match &a.1 {
+                Some(a) if *a.denom() == 1 => {
+                    if a.numer().is_odd() {
+                        *e = Expr::nary(Times, vec![Expr::minus_one(), take(e)]);
+                    }
+                }
+                _ => traverse_expr_mut(self, e),
+            },
+            var!(name) if name == "r" => {
+                *e = Expr::nary(Times, vec![Expr::minus_one(), take(e)]);
+            }
+            var!(name) if name == "theta" || name == "θ" => {
+                *e = Expr::nary(
+                    Plus,
+                    vec![take(e), Expr::constant(DecInterval::PI.into(), None)],
+                );
+            }
+            unary!(op @ (Cos | Sin | Tan), x) => match Self::theta_coefficient(x) {
+                Some(a) if *a.denom() == 1 => {
+                    if *op != Tan && a.numer().is_odd() {
+                        *e = Expr::nary(Times, vec![Expr::minus_one(), take(e)]);
+                    }
+                }
+                _ => traverse_expr_mut(self, e),
+            },
+            _ => traverse_expr_mut(self, e),
+        }
+    }
+}
+
 /// Replaces expressions that contain [`UnaryOp::Not`] with their equivalents without the operation.
 #[derive(Default)]
 pub struct EliminateNot {
@@ -518,10 +598,93 @@ pub struct Transform {
 
 impl VisitMut for Transform {
     fn visit_expr_mut(&mut self, e: &mut Expr) {
-        use {BinaryOp::*, NaryOp::*};
+        use {BinaryOp::*, NaryOp::*, UnaryOp::*};
         traverse_expr_mut(self, e);
 
+        // The closed forms of j₁ and i₁ are not used since they suffer from cancellation
+        // near x = 0, e.g., j₁(x) = (sin(x)/x - cos(x))/x.
         match e {
+            binary!(SphericalBesselI, constant!(n), x) if n.0.to_f64() == Some(0.0) => {
+                // (SphericalBesselI 0 x) → (Sinhc x)
+                *e = Expr::unary(Sinhc, box take(x));
+                self.modified = true;
+            }
+            binary!(SphericalBesselJ, constant!(n), x) if n.0.to_f64() == Some(0.0) => {
+                // (SphericalBesselJ 0 x) → (Sinc x)
+                *e = Expr::unary(Sinc, box take(x));
+                self.modified = true;
+            }
+            binary!(SphericalBesselK, constant!(n), x) if n.0.to_f64() == Some(0.0) => {
+                // (SphericalBesselK 0 x) → (Times π/2 (Exp (Times -1 x)) (Pow (Pow x 1/2) -2))
+                // The square root restricts the domain to x > 0.
+                let x = take(x);
+                *e = Expr::nary(
+                    Times,
+                    vec![
+                        Expr::constant(DecInterval::FRAC_PI_2.into(), None),
+                        Expr::unary(Exp, box Expr::nary(Times, vec![Expr::minus_one(), x.clone()])),
+                        Expr::binary(
+                            Pow,
+                            box Expr::binary(Pow, box x, box Expr::one_half()),
+                            box Expr::constant(
+                                const_dec_interval!(-2.0, -2.0).into(),
+                                Some((-2).into()),
+                            ),
+                        ),
+                    ],
+                );
+                self.modified = true;
+            }
+            binary!(SphericalBesselY, constant!(n), x) if n.0.to_f64() == Some(0.0) => {
+                // (SphericalBesselY 0 x) → (Times -1 (Cos x) (Pow x -1))
+                let x = take(x);
+                *e = Expr::nary(
+                    Times,
+                    vec![
+                        Expr::minus_one(),
+                        Expr::unary(Cos, box x.clone()),
+                        Expr::binary(Pow, box x, box Expr::minus_one()),
+                    ],
+                );
+                self.modified = true;
+            }
+            binary!(SphericalBesselY, constant!(n), x) if n.0.to_f64() == Some(1.0) => {
+                // (SphericalBesselY 1 x) →
+                //   (Times -1 (Plus (Times (Cos x) (Pow x -1)) (Sin x)) (Pow x -1))
+                let x = take(x);
+                let recip_x = Expr::binary(Pow, box x.clone(), box Expr::minus_one());
+                *e = Expr::nary(
+                    Times,
+                    vec![
+                        Expr::minus_one(),
+                        Expr::nary(
+                            Plus,
+                            vec![
+                                Expr::nary(
+                                    Times,
+                                    vec![Expr::unary(Cos, box x.clone()), recip_x.clone()],
+                                ),
+                                Expr::unary(Sin, box x),
+                            ],
+                        ),
+                        recip_x,
+                    ],
+                );
+                self.modified = true;
+            }
+            binary!(Pow, nary!(Plus, xs), constant!((_, Some(a)))) if *a == (1, 2) => {
+                if let [
+                    binary!(Pow, x, constant!((_, Some(m)))),
+                    binary!(Pow, y, constant!((_, Some(n)))),
+                ] = &mut xs[..]
+                {
+                    if *m == 2 && *n == 2 {
+                        // (Pow (Plus (Pow x 2) (Pow y 2)) 1/2) → (Hypot x y)
+                        *e = Expr::binary(Hypot, box take(x), box take(y));
+                        self.modified = true;
+                    }
+                }
+            }
             binary!(Pow, x, constant!(a)) => {
                 match a.0.to_f64() {
                     Some(a) if a == 1.0 => {
@@ -532,9 +695,33 @@ impl VisitMut for Transform {
                     _ => (),
                 }
             }
+            unary!(Ln, nary!(Plus, xs)) => {
+                if let Some(i) = xs
+                    .iter()
+                    .position(|x| matches!(x, constant!((_, Some(a))) if *a == 1))
+                {
+                    // (Ln (Plus 1 x…)) → (Log1p (Plus x…))
+                    xs.remove(i);
+                    *e = Expr::unary(Log1p, box Expr::nary(Plus, take(xs)));
+                    self.modified = true;
+                }
+            }
             nary!(Plus, xs) => {
                 let len = xs.len();
 
+                if let (Some(i), Some(j)) = (
+                    xs.iter()
+                        .position(|x| matches!(x, constant!((_, Some(a))) if *a == -1)),
+                    xs.iter().position(|x| matches!(x, unary!(Exp, _))),
+                ) {
+                    // (Plus -1 (Exp x) y…) → (Plus (Expm1 x) y…)
+                    if let unary!(Exp, x) = &mut xs[j] {
+                        let x = take(x);
+                        xs[j] = Expr::unary(Expm1, box x);
+                    }
+                    xs.remove(i);
+                }
+
                 // Drop zeros.
                 xs.retain(|x| !matches!(x, constant!(a) if a.0.to_f64() == Some(0.0)));
 
@@ -782,6 +969,7 @@ impl VisitMut for PostTransform {
                         let root = match d {
                             1 => take(x),
                             2 => Expr::unary(Sqrt, box take(x)),
+                            3 => Expr::unary(Cbrt, box take(x)),
                             _ => Expr::rootn(box take(x), d),
                         };
                         *e = match n {
@@ -835,6 +1023,82 @@ impl VisitMut for FuseMulAdd {
     }
 }
 
+/// Finds a function that is not available because the feature `arb` is disabled.
+///
+/// This must be applied before constant folding, which would evaluate such functions.
+#[cfg(not(feature = "arb"))]
+#[derive(Default)]
+pub struct FindUnavailableFunction {
+    /// The error message that describes the first function found.
+    pub error: Option<String>,
+}
+
+#[cfg(not(feature = "arb"))]
+impl<'a> Visit<'a> for FindUnavailableFunction {
+    fn visit_expr(&mut self, e: &'a Expr) {
+        use {BinaryOp::*, QuaternaryOp::*, TernaryOp::*, UnaryOp::*};
+        traverse_expr(self, e);
+        if self.error.is_some() {
+            return;
+        }
+        let name = match e {
+            unary!(Chi, _) => "Chi",
+            unary!(Ei, _) => "Ei",
+            unary!(EllipticE, _) => "E",
+            unary!(EllipticK, _) => "K",
+            unary!(Erfcinv, _) => "erfcinv",
+            unary!(Erfi, _) => "erfi",
+            unary!(Erfinv, _) => "erfinv",
+            unary!(FresnelC, _) => "C",
+            unary!(FresnelS, _) => "S",
+            unary!(Li, _) => "li",
+            unary!(Shi, _) => "Shi",
+            binary!(BesselI, _, _) => "I",
+            binary!(BesselK, _, _) => "K",
+            binary!(EllipticEInc, _, _) => "EllipticE",
+            binary!(EllipticF, _, _) => "F",
+            binary!(ExpIntegralE, _, _) => "E",
+            binary!(GammaInc, _, _) => "Gamma",
+            binary!(GammaIncLower, _, _) => "gammaLower",
+            binary!(GammaRegularized, _, _) => "Q",
+            binary!(GammaRegularizedLower, _, _) => "gammaP",
+            binary!(Hyp0F1, _, _) => "hyp0f1",
+            binary!(JacobiAm, _, _) => "am",
+            binary!(JacobiCn, _, _) => "cn",
+            binary!(JacobiDn, _, _) => "dn",
+            binary!(JacobiSn, _, _) => "sn",
+            binary!(SphericalBesselI, _, _) => "i",
+            binary!(SphericalBesselJ, _, _) => "j",
+            binary!(SphericalBesselK, _, _) => "k",
+            binary!(SphericalBesselY, _, _) => "y",
+            binary!(StruveH, _, _) => "StruveH",
+            binary!(StruveL, _, _) => "StruveL",
+            ternary!(BetaRegularized, _, _, _) => "I",
+            ternary!(EllipticPi, _, _, _) => "Pi",
+            ternary!(Hyp1F1, _, _, _) => "hyp1f1",
+            quaternary!(Hyp2F1, _, _, _, _) => "hyp2f1",
+            binary!(op @ (BesselJ | BesselY), n, _) => {
+                // Only integer orders that are exactly representable as `f64` are supported.
+                let n = n.eval().and_then(|(n, _)| n.to_f64());
+                if !matches!(n, Some(n) if n == n.trunc()) {
+                    let name = if *op == BesselJ { "J" } else { "Y" };
+                    self.error = Some(format!(
+                        "`{}(n, x)` only permits integers for `n` \
+                         unless the feature `arb` is enabled",
+                        name
+                    ));
+                }
+                return;
+            }
+            _ => return,
+        };
+        self.error = Some(format!(
+            "function `{}` is only available when the feature `arb` is enabled",
+            name
+        ));
+    }
+}
+
 /// Updates metadata of terms and formulas.
 pub struct UpdateMetadata;
 
@@ -872,9 +1136,44 @@ impl AssignId {
     fn term_can_perform_cut(e: &Expr) -> bool {
         use {BinaryOp::*, UnaryOp::*};
         match e {
-            unary!(Ceil | Digamma | Floor | Gamma | Recip | Tan, _)
+            unary!(
+                Ceil | Digamma
+                    | Factorial
+                    | Floor
+                    | Gamma
+                    | Heaviside
+                    | IsPrime
+                    | LnGamma
+                    | Mobius
+                    | NumDivisors
+                    | Popcount
+                    | PrimePi
+                    | Recip
+                    | Rect
+                    | SawtoothWave
+                    | Sign
+                    | SquareWave
+                    | Tan
+                    | Totient,
+                _
+            )
             | binary!(
-                Atan2 | Div | Gcd | Lcm | Log | Mod | Pow | RankedMax | RankedMin,
+                Atan2 | Beta
+                    | Binom
+                    | BitAnd
+                    | BitOr
+                    | BitXor
+                    | Div
+                    | DivisorSigma
+                    | Gcd
+                    | Lcm
+                    | Log
+                    | Mod
+                    | Poch
+                    | Polygamma
+                    | Pow
+                    | RankedMax
+                    | RankedMin,
                 _,
                 _
             ) => true,
@@ -917,6 +1216,8 @@ impl VisitMut for AssignId {
 pub struct CollectStatic {
     pub terms: Vec<StaticTerm>,
     pub forms: Vec<StaticForm>,
+    /// The exact values of the constant terms that are rational numbers.
+    pub rational_constants: Vec<(StoreIndex, Rational)>,
     site_map: SiteMap,
     exprs: Vec<UnsafeExprRef>,
     term_index: HashMap<ExprId, usize>,
@@ -929,6 +1230,7 @@ impl CollectStatic {
         let mut slf = Self {
             terms: vec![],
             forms: vec![],
+            rational_constants: vec![],
             site_map: v.site_map,
             exprs: v.exprs,
             term_index: HashMap::new(),
@@ -942,7 +1244,7 @@ impl CollectStatic {
     }
 
     fn collect_terms(&mut self) {
-        use {BinaryOp::*, NaryOp::*, TernaryOp::*, UnaryOp::*};
+        use {BinaryOp::*, NaryOp::*, QuaternaryOp::*, TernaryOp::*, UnaryOp::*};
         for t in self.exprs.iter().copied() {
             let k = match &*t {
                 constant!(x) => Some(StaticTermKind::Constant(box x.0.clone())),
@@ -961,6 +1263,7 @@ impl CollectStatic {
                     Asinh => Some(ScalarUnaryOp::Asinh),
                     Atan => Some(ScalarUnaryOp::Atan),
                     Atanh => Some(ScalarUnaryOp::Atanh),
+                    Cbrt => Some(ScalarUnaryOp::Cbrt),
                     Ceil => Some(ScalarUnaryOp::Ceil),
                     Chi => Some(ScalarUnaryOp::Chi),
                     Ci => Some(ScalarUnaryOp::Ci),
@@ -972,35 +1275,60 @@ impl CollectStatic {
                     EllipticK => Some(ScalarUnaryOp::EllipticK),
                     Erf => Some(ScalarUnaryOp::Erf),
                     Erfc => Some(ScalarUnaryOp::Erfc),
+                    Erfcinv => Some(ScalarUnaryOp::Erfcinv),
                     Erfi => Some(ScalarUnaryOp::Erfi),
+                    Erfinv => Some(ScalarUnaryOp::Erfinv),
                     Exp => Some(ScalarUnaryOp::Exp),
                     Exp10 => Some(ScalarUnaryOp::Exp10),
                     Exp2 => Some(ScalarUnaryOp::Exp2),
+                    Expm1 => Some(ScalarUnaryOp::Expm1),
+                    Factorial => Some(ScalarUnaryOp::Factorial),
                     Floor => Some(ScalarUnaryOp::Floor),
                     FresnelC => Some(ScalarUnaryOp::FresnelC),
                     FresnelS => Some(ScalarUnaryOp::FresnelS),
                     Gamma => Some(ScalarUnaryOp::Gamma),
+                    Heaviside => Some(ScalarUnaryOp::Heaviside),
+                    IsPrime => Some(ScalarUnaryOp::IsPrime),
                     Li => Some(ScalarUnaryOp::Li),
                     Ln => Some(ScalarUnaryOp::Ln),
+                    LnGamma => Some(ScalarUnaryOp::LnGamma),
                     Log10 => Some(ScalarUnaryOp::Log10),
+                    Log1p => Some(ScalarUnaryOp::Log1p),
+                    Mobius => Some(ScalarUnaryOp::Mobius),
                     Neg => Some(ScalarUnaryOp::Neg),
+                    NumDivisors => Some(ScalarUnaryOp::NumDivisors),
                     One => Some(ScalarUnaryOp::One),
+                    Popcount => Some(ScalarUnaryOp::Popcount),
+                    PrimePi => Some(ScalarUnaryOp::PrimePi),
                     Recip => Some(ScalarUnaryOp::Recip),
+                    Rect => Some(ScalarUnaryOp::Rect),
+                    SawtoothWave => Some(ScalarUnaryOp::SawtoothWave),
                     Shi => Some(ScalarUnaryOp::Shi),
                     Si => Some(ScalarUnaryOp::Si),
+                    Sign => Some(ScalarUnaryOp::Sign),
                     Sin => Some(ScalarUnaryOp::Sin),
                     Sinc => Some(ScalarUnaryOp::Sinc),
                     Sinh => Some(ScalarUnaryOp::Sinh),
+                    Sinhc => Some(ScalarUnaryOp::Sinhc),
                     Sqr => Some(ScalarUnaryOp::Sqr),
                     Sqrt => Some(ScalarUnaryOp::Sqrt),
+                    SquareWave => Some(ScalarUnaryOp::SquareWave),
                     Tan => Some(ScalarUnaryOp::Tan),
                     Tanh => Some(ScalarUnaryOp::Tanh),
+                    Totient => Some(ScalarUnaryOp::Totient),
+                    Tri => Some(ScalarUnaryOp::Tri),
                     UndefAt0 => Some(ScalarUnaryOp::UndefAt0),
                     _ => None,
                 }
                 .map(|op| StaticTermKind::Unary(op, self.store_index(x))),
-                binary!(op @ (Add | Atan2 | BesselI | BesselJ | BesselK | BesselY | Div | GammaInc
-                    | Gcd | Lcm | Log | Max | Min | Mod | Mul | Pow | Sub), x, y) => {
+                binary!(op @ (Add | Atan2 | BesselI | BesselJ | BesselK | BesselY | Beta | Binom
+                    | BitAnd | BitOr | BitXor | ChebyshevT | ChebyshevU | Div | DivisorSigma
+                    | EllipticEInc | EllipticF | ExpIntegralE | GammaInc | GammaIncLower
+                    | GammaRegularized | GammaRegularizedLower | Gcd | HermiteH | Hyp0F1 | Hypot
+                    | JacobiAm | JacobiCn | JacobiDn | JacobiSn | LaguerreL | Lcm | LegendreP | Log
+                    | Max | Min | Mod | Mul | Poch | Polygamma | Pow | SphericalBesselI
+                    | SphericalBesselJ | SphericalBesselK | SphericalBesselY | StruveH | StruveL
+                    | Sub), x, y) => {
                     let op = match op {
                         Add => ScalarBinaryOp::Add,
                         Atan2 => ScalarBinaryOp::Atan2,
@@ -1008,16 +1336,47 @@ impl CollectStatic {
                         BesselJ => ScalarBinaryOp::BesselJ,
                         BesselK => ScalarBinaryOp::BesselK,
                         BesselY => ScalarBinaryOp::BesselY,
+                        Beta => ScalarBinaryOp::Beta,
+                        Binom => ScalarBinaryOp::Binom,
+                        BitAnd => ScalarBinaryOp::BitAnd,
+                        BitOr => ScalarBinaryOp::BitOr,
+                        BitXor => ScalarBinaryOp::BitXor,
+                        ChebyshevT => ScalarBinaryOp::ChebyshevT,
+                        ChebyshevU => ScalarBinaryOp::ChebyshevU,
                         Div => ScalarBinaryOp::Div,
+                        DivisorSigma => ScalarBinaryOp::DivisorSigma,
+                        EllipticEInc => ScalarBinaryOp::EllipticEInc,
+                        EllipticF => ScalarBinaryOp::EllipticF,
+                        ExpIntegralE => ScalarBinaryOp::ExpIntegralE,
                         GammaInc => ScalarBinaryOp::GammaInc,
+                        GammaIncLower => ScalarBinaryOp::GammaIncLower,
+                        GammaRegularized => ScalarBinaryOp::GammaRegularized,
+                        GammaRegularizedLower => ScalarBinaryOp::GammaRegularizedLower,
                         Gcd => ScalarBinaryOp::Gcd,
+                        HermiteH => ScalarBinaryOp::HermiteH,
+                        Hyp0F1 => ScalarBinaryOp::Hyp0F1,
+                        Hypot => ScalarBinaryOp::Hypot,
+                        JacobiAm => ScalarBinaryOp::JacobiAm,
+                        JacobiCn => ScalarBinaryOp::JacobiCn,
+                        JacobiDn => ScalarBinaryOp::JacobiDn,
+                        JacobiSn => ScalarBinaryOp::JacobiSn,
+                        LaguerreL => ScalarBinaryOp::LaguerreL,
                         Lcm => ScalarBinaryOp::Lcm,
+                        LegendreP => ScalarBinaryOp::LegendreP,
                         Log => ScalarBinaryOp::Log,
                         Max => ScalarBinaryOp::Max,
                         Min => ScalarBinaryOp::Min,
                         Mod => ScalarBinaryOp::Mod,
                         Mul => ScalarBinaryOp::Mul,
+                        Poch => ScalarBinaryOp::Poch,
+                        Polygamma => ScalarBinaryOp::Polygamma,
                         Pow => ScalarBinaryOp::Pow,
+                        SphericalBesselI => ScalarBinaryOp::SphericalBesselI,
+                        SphericalBesselJ => ScalarBinaryOp::SphericalBesselJ,
+                        SphericalBesselK => ScalarBinaryOp::SphericalBesselK,
+                        SphericalBesselY => ScalarBinaryOp::SphericalBesselY,
+                        StruveH => ScalarBinaryOp::StruveH,
+                        StruveL => ScalarBinaryOp::StruveL,
                         Sub => ScalarBinaryOp::Sub,
                         _ => unreachable!(),
                     };
@@ -1041,6 +1400,11 @@ impl CollectStatic {
                 }
                 binary!(_, _, _) => None,
                 ternary!(op, x, y, z) => match op {
+                    AssocLegendreP => Some(ScalarTernaryOp::AssocLegendreP),
+                    BetaRegularized => Some(ScalarTernaryOp::BetaRegularized),
+                    Clamp => Some(ScalarTernaryOp::Clamp),
+                    EllipticPi => Some(ScalarTernaryOp::EllipticPi),
+                    Hyp1F1 => Some(ScalarTernaryOp::Hyp1F1),
                     MulAdd => Some(ScalarTernaryOp::MulAdd),
                 }
                 .map(|op| {
@@ -1051,6 +1415,20 @@ impl CollectStatic {
                         self.store_index(z),
                     )
                 }),
+                quaternary!(op, x, y, z, w) => match op {
+                    Hyp2F1 => Some(ScalarQuaternaryOp::Hyp2F1),
+                }
+                .map(|op| {
+                    StaticTermKind::Quaternary(
+                        op,
+                        box [
+                            self.store_index(x),
+                            self.store_index(y),
+                            self.store_index(z),
+                            self.store_index(w),
+                        ],
+                    )
+                }),
                 nary!(_, _) => None,
                 pown!(x, n) => Some(StaticTermKind::Pown(self.store_index(x), *n)),
                 rootn!(x, n) => Some(StaticTermKind::Rootn(self.store_index(x), *n)),
@@ -1066,6 +1444,9 @@ impl CollectStatic {
                         StoreIndex::new(i)
                     }
                 };
+                if let constant!((_, Some(xr))) = &*t {
+                    self.rational_constants.push((store_index, xr.clone()));
+                }
                 self.terms.push(StaticTerm {
                     site: self.site_map.get(&t.id).copied(),
                     kind: k,
@@ -1124,7 +1505,8 @@ impl CollectStatic {
         self.form_index[&e.id]
     }
 
-    fn store_index(&self, e: &Expr) -> StoreIndex {
+    /// Returns the store index of the term that corresponds to the expression.
+    pub fn store_index(&self, e: &Expr) -> StoreIndex {
         self.terms[self.term_index[&e.id]].store_index
     }
 }
@@ -1330,6 +1712,30 @@ mod tests {
         test("x^-2 x^3", "(Times (Pow x -2) (Pow x 3))");
         test("x^2 x^2", "(Pow x 4)");
         test("sqrt(x) sqrt(x)", "(Pow (Pow x 0.5) 2)");
+
+        test("i(0, x)", "(Sinhc x)");
+        test("i(1, x)", "(SphericalBesselI 1 x)");
+        test("j(0, x)", "(Sinc x)");
+        test("j(1, x)", "(SphericalBesselJ 1 x)");
+        test(
+            "k(0, x)",
+            "(Times @ (Exp (Times -1 x)) (Pow (Pow x 0.5) -2))",
+        );
+        test("k(1, x)", "(SphericalBesselK 1 x)");
+        test("y(0, x)", "(Times -1 (Cos x) (Pow x -1))");
+        test(
+            "y(1, x)",
+            "(Times -1 (Plus (Times (Cos x) (Pow x -1)) (Sin x)) (Pow x -1))",
+        );
+        test("y(2, x)", "(SphericalBesselY 2 x)");
+
+        test("sqrt(x^2 + y^2)", "(Hypot x y)");
+        test("sqrt(x^2 + 1)", "(Pow (Plus (Pow x 2) 1) 0.5)");
+        test("ln(1 + x)", "(Log1p x)");
+        test("ln(x + y + 1)", "(Log1p (Plus x y))");
+        test("exp(x) - 1", "(Expm1 x)");
+        test("exp(x) - 1 + y", "(Plus (Expm1 x) y)");
+        test("exp(x) - 2", "(Plus (Exp x) -2)");
     }
 
     #[test]
@@ -1353,10 +1759,11 @@ mod tests {
         test("x^3", "(Pown x 3)");
         test("x^(1/2)", "(Sqrt x)");
         test("x^(3/2)", "(Pown (Sqrt x) 3)");
-        test("x^(-2/3)", "(Pown (Rootn x 3) -2)");
-        test("x^(-1/3)", "(Recip (Rootn x 3))");
-        test("x^(1/3)", "(Rootn x 3)");
-        test("x^(2/3)", "(Sqr (Rootn x 3))");
+        test("x^(-2/3)", "(Pown (Cbrt x) -2)");
+        test("x^(-1/3)", "(Recip (Cbrt x))");
+        test("x^(1/3)", "(Cbrt x)");
+        test("x^(2/3)", "(Sqr (Cbrt x))");
+        test("x^(1/5)", "(Rootn x 5)");
         test("x + y", "(Add x y)");
         test("x + y + z", "(Add (Add x y) z)");
         test("x y", "(Mul x y)");